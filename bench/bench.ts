@@ -7,10 +7,12 @@ import { benchAsync } from './async'
 import { benchBuffer } from './buffer'
 import { benchCreateArray } from './create-array'
 import { benchGetArray } from './get-array-from-js'
+import { benchGetObject } from './get-object-from-js'
 import { benchGetSetProperty } from './get-set-property'
 import { benchNoop } from './noop'
 import { benchPlus } from './plus'
 import { benchQuery } from './query'
+import { benchRunScript } from './run-script'
 
 async function run() {
   const output = [
@@ -19,9 +21,11 @@ async function run() {
     await benchBuffer(),
     await benchCreateArray(),
     await benchGetArray(),
+    await benchGetObject(),
     await benchGetSetProperty(),
     await benchAsync(),
     await benchQuery(),
+    await benchRunScript(),
   ]
     .map(formatSummary)
     .join('\n')
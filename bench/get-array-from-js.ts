@@ -4,9 +4,16 @@ const {
   getArrayFromJson,
   getArrayFromJsArray,
   getArrayWithForLoop,
+  getNestedF64Array,
 } = require('./index.node')
 
 const FIXTURE = Array.from({ length: 1000 }).fill(42)
+const NESTED_FIXTURE = Array.from({ length: 100 }, () =>
+  Array.from({ length: 100 }).fill(42.5),
+)
+const NESTED_TYPED_ARRAY_FIXTURE = NESTED_FIXTURE.map(
+  (row) => new Float64Array(row as number[]),
+)
 
 export const benchGetArray = () =>
   b.suite(
@@ -22,6 +29,14 @@ export const benchGetArray = () =>
       getArrayWithForLoop(FIXTURE)
     }),
 
+    b.add('get nested f64 array', () => {
+      getNestedF64Array(NESTED_FIXTURE)
+    }),
+
+    b.add('get nested f64 array from Float64Array rows', () => {
+      getNestedF64Array(NESTED_TYPED_ARRAY_FIXTURE)
+    }),
+
     b.cycle(),
     b.complete(),
   )
@@ -0,0 +1,38 @@
+import b from 'benny'
+
+const {
+  getObjectFromJson,
+  getObjectFromSerde,
+  getJsonMapFromJsObject,
+} = require('./index.node')
+
+const FIXTURE = Object.fromEntries(
+  Array.from({ length: 100 }, (_, i) => [
+    `key${i}`,
+    {
+      id: i,
+      name: `item${i}`,
+      enabled: i % 2 === 0,
+      tags: ['a', 'b', 'c'],
+      parent: null,
+    },
+  ]),
+)
+
+export const benchGetObject = () =>
+  b.suite(
+    'getObjectFromJs',
+    b.add('get object from json string', () => {
+      getObjectFromJson(JSON.stringify(FIXTURE))
+    }),
+    b.add('get object from serde', () => {
+      getObjectFromSerde(FIXTURE)
+    }),
+
+    b.add('get object as serde_json::Map', () => {
+      getJsonMapFromJsObject(FIXTURE)
+    }),
+
+    b.cycle(),
+    b.complete(),
+  )
@@ -0,0 +1,19 @@
+import b from 'benny'
+
+const { runScript, runCompiledScript } = require('./index.node')
+
+const TIMES = 100
+
+export const benchRunScript = () =>
+  b.suite(
+    'Run script',
+    b.add('run_script * 100', () => {
+      runScript('1 + 1', TIMES)
+    }),
+    b.add('compile_script + run * 100', () => {
+      runCompiledScript('return 1 + 1', TIMES)
+    }),
+
+    b.cycle(),
+    b.complete(),
+  )
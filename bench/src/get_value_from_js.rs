@@ -1,10 +1,14 @@
 use napi::{CallContext, JsObject, JsString, JsUndefined, JsUnknown, Result};
-use serde_json::from_str;
+use serde_json::{from_str, Map, Value};
 
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("getArrayFromJson", get_array_from_json)?;
   exports.create_named_method("getArrayFromJsArray", get_array_from_js_array)?;
   exports.create_named_method("getArrayWithForLoop", get_array_with_for_loop)?;
+  exports.create_named_method("getNestedF64Array", get_nested_f64_array)?;
+  exports.create_named_method("getObjectFromJson", get_object_from_json)?;
+  exports.create_named_method("getObjectFromSerde", get_object_from_serde)?;
+  exports.create_named_method("getJsonMapFromJsObject", get_json_map_from_js_object)?;
   Ok(())
 }
 
@@ -32,3 +36,29 @@ fn get_array_with_for_loop(ctx: CallContext) -> Result<JsUndefined> {
   }
   ctx.env.get_undefined()
 }
+
+#[js_function(1)]
+fn get_nested_f64_array(ctx: CallContext) -> Result<JsUndefined> {
+  let _: Vec<Vec<f64>> = ctx.get(0)?;
+  ctx.env.get_undefined()
+}
+
+#[js_function(1)]
+fn get_object_from_json(ctx: CallContext) -> Result<JsUndefined> {
+  let input = ctx.get::<JsString>(0)?.into_utf8()?;
+  let _: Map<String, Value> = from_str(input.as_str()?)?;
+  ctx.env.get_undefined()
+}
+
+#[js_function(1)]
+fn get_object_from_serde(ctx: CallContext) -> Result<JsUndefined> {
+  let input = ctx.get::<JsObject>(0)?;
+  let _: Map<String, Value> = ctx.env.from_js_value(input)?;
+  ctx.env.get_undefined()
+}
+
+#[js_function(1)]
+fn get_json_map_from_js_object(ctx: CallContext) -> Result<JsUndefined> {
+  let _: Map<String, Value> = ctx.get(0)?;
+  ctx.env.get_undefined()
+}
@@ -21,6 +21,7 @@ mod get_value_from_js;
 mod noop;
 mod plus;
 mod query;
+mod run_script;
 
 #[module_exports]
 fn init(mut exports: JsObject, env: Env) -> Result<()> {
@@ -33,6 +34,7 @@ fn init(mut exports: JsObject, env: Env) -> Result<()> {
   create_array::register_js(&mut exports)?;
   get_value_from_js::register_js(&mut exports)?;
   query::register_js(&mut exports)?;
+  run_script::register_js(&mut exports)?;
 
   Ok(())
 }
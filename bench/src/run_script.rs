@@ -0,0 +1,28 @@
+use napi::{CallContext, JsObject, JsString, JsUndefined, JsUnknown, Result};
+
+#[js_function(2)]
+fn run_script(ctx: CallContext) -> Result<JsUndefined> {
+  let code = ctx.get::<JsString>(0)?.into_utf8()?;
+  let times: u32 = ctx.get(1)?;
+  for _ in 0..times {
+    ctx.env.run_script::<_, JsUnknown>(code.as_str()?)?;
+  }
+  ctx.env.get_undefined()
+}
+
+#[js_function(2)]
+fn run_compiled_script(ctx: CallContext) -> Result<JsUndefined> {
+  let code = ctx.get::<JsString>(0)?.into_utf8()?;
+  let times: u32 = ctx.get(1)?;
+  let script = ctx.env.compile_script(code.as_str()?)?;
+  for _ in 0..times {
+    script.run::<JsUnknown>(ctx.env)?;
+  }
+  ctx.env.get_undefined()
+}
+
+pub fn register_js(exports: &mut JsObject) -> Result<()> {
+  exports.create_named_method("runScript", run_script)?;
+  exports.create_named_method("runCompiledScript", run_compiled_script)?;
+  Ok(())
+}
@@ -646,6 +646,7 @@ interface TypeDef {
   kind: 'fn' | 'struct' | 'impl' | 'enum' | 'interface'
   name: string
   original_name?: string
+  extends?: string
   def: string
   js_mod?: string
   js_doc: string
@@ -677,7 +678,7 @@ async function processIntermediateTypeFile(
   function convertDefs(defs: TypeDef[], nested = false): string {
     const classes = new Map<
       string,
-      { def: string; js_doc: string; original_name?: string }
+      { def: string; js_doc: string; original_name?: string; extends?: string }
     >()
     const impls = new Map<string, string>()
     let dts = ''
@@ -691,6 +692,7 @@ async function processIntermediateTypeFile(
           }
           classes.set(def.name, {
             original_name: def.original_name,
+            extends: def.extends,
             def: def.def,
             js_doc: def.js_doc,
           })
@@ -727,14 +729,18 @@ async function processIntermediateTypeFile(
       }
     })
 
-    for (const [name, { js_doc, def, original_name }] of classes.entries()) {
+    for (const [
+      name,
+      { js_doc, def, original_name, extends: parent },
+    ] of classes.entries()) {
       const implDef = impls.get(name)
 
       if (original_name && name !== original_name) {
         dts += indentLines(`export type ${original_name} = ${name}\n`, nest)
       }
 
-      dts += indentLines(`${js_doc}export class ${name} {`, nest)
+      const heritage = parent ? ` extends ${parent}` : ''
+      dts += indentLines(`${js_doc}export class ${name}${heritage} {`, nest)
 
       if (def) {
         dts += '\n' + indentLines(def, nest + 2)
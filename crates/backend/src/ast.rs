@@ -14,12 +14,16 @@ pub struct NapiFn {
   pub kind: FnKind,
   pub vis: syn::Visibility,
   pub parent: Option<Ident>,
+  /// The registered JavaScript name of the `parent` class
+  pub parent_js_name: Option<String>,
   pub strict: bool,
   pub return_if_invalid: bool,
+  pub return_result_object: bool,
   pub js_mod: Option<String>,
   pub ts_generic_types: Option<String>,
-  pub ts_args_type: Option<String>,
+  pub ts_args_type: Vec<String>,
   pub ts_return_type: Option<String>,
+  pub ts_decl: Option<String>,
   pub skip_typescript: bool,
   pub comments: Vec<String>,
   pub parent_is_generator: bool,
@@ -30,6 +34,27 @@ pub struct NapiFn {
   pub unsafe_: bool,
 }
 
+impl NapiFn {
+  /// `async fn`s returning `impl Stream` return the async iterator directly instead of a `Promise` of it
+  pub fn is_async_stream(&self) -> bool {
+    if !self.is_async {
+      return false;
+    }
+    if let Some(syn::Type::ImplTrait(impl_trait)) = &self.ret {
+      impl_trait.bounds.iter().any(|bound| match bound {
+        syn::TypeParamBound::Trait(t) => t
+          .path
+          .segments
+          .last()
+          .map_or(false, |segment| segment.ident == "Stream"),
+        _ => false,
+      })
+    } else {
+      false
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct CallbackArg {
   pub pat: Box<syn::Pat>,
@@ -86,6 +111,17 @@ pub struct NapiStruct {
   pub comments: Vec<String>,
   pub implement_iterator: bool,
   pub use_custom_finalize: bool,
+  pub use_cleanup: bool,
+  pub implement_debug: bool,
+  pub extends: Option<NapiStructExtends>,
+}
+
+/// Superclass of a `#[napi(extends = "Parent")]` class, which is embedded as the first field
+#[derive(Debug, Clone)]
+pub struct NapiStructExtends {
+  pub name: Ident,
+  pub js_name: String,
+  pub field: syn::Member,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -108,6 +144,7 @@ pub struct NapiStructField {
   pub comments: Vec<String>,
   pub skip_typescript: bool,
   pub ts_type: Option<String>,
+  pub base64: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -140,6 +177,36 @@ pub struct NapiEnumVariant {
   pub comments: Vec<String>,
 }
 
+/// Enum with data, represented as `{ [discriminant]: "Variant", ...fields }` object in JavaScript
+#[derive(Debug, Clone)]
+pub struct NapiStructuredEnum {
+  pub name: Ident,
+  pub js_name: String,
+  pub discriminant: String,
+  pub variants: Vec<NapiStructuredEnumVariant>,
+  pub js_mod: Option<String>,
+  pub comments: Vec<String>,
+  pub skip_typescript: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct NapiStructuredEnumVariant {
+  pub name: Ident,
+  pub fields: Vec<NapiStructField>,
+  pub is_tuple: bool,
+  pub comments: Vec<String>,
+}
+
+/// Struct or enum marked with `#[napi(from_serde)]`, converted from JavaScript values by its `Deserialize` impl
+#[derive(Debug, Clone)]
+pub struct NapiFromSerde {
+  pub name: Ident,
+  pub js_name: String,
+  pub ts_type: Option<String>,
+  pub js_mod: Option<String>,
+  pub comments: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct NapiConst {
   pub name: Ident,
@@ -5,6 +5,7 @@ use crate::BindgenResult;
 mod r#const;
 mod r#enum;
 mod r#fn;
+mod serde;
 mod r#struct;
 
 pub const PROPERTY_ATTRIBUTE_DEFAULT: i32 = 0;
@@ -41,3 +42,40 @@ fn js_mod_to_token_stream(js_mod: Option<&String>) -> TokenStream {
     })
     .unwrap_or_else(|| quote! { None })
 }
+
+/// `Global<T>` arguments are injected instead of read from the JavaScript arguments.
+///
+/// Only `Global<T>`, `bindgen_prelude::Global<T>` and `napi::bindgen_prelude::Global<T>` match,
+/// other types named `Global` are ordinary arguments if they take no single type argument
+/// or are written through another path, e.g. `crate::Global<T>`.
+pub(crate) fn is_injected_global(ty: &syn::TypePath) -> bool {
+  const PRELUDE_PATH: [&str; 2] = ["napi", "bindgen_prelude"];
+
+  if ty.qself.is_some() {
+    return false;
+  }
+  let segments = &ty.path.segments;
+  let takes_single_type = match segments.last() {
+    Some(syn::PathSegment {
+      ident,
+      arguments: syn::PathArguments::AngleBracketed(arguments),
+    }) if ident == "Global" => {
+      arguments.args.len() == 1
+        && matches!(arguments.args.first(), Some(syn::GenericArgument::Type(_)))
+    }
+    _ => false,
+  };
+  if !takes_single_type {
+    return false;
+  }
+  let prefix_len = segments.len() - 1;
+  if prefix_len > PRELUDE_PATH.len()
+    || (ty.path.leading_colon.is_some() && prefix_len != PRELUDE_PATH.len())
+  {
+    return false;
+  }
+  PRELUDE_PATH[PRELUDE_PATH.len() - prefix_len..]
+    .iter()
+    .zip(segments)
+    .all(|(expected, segment)| segment.ident == expected)
+}
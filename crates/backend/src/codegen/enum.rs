@@ -3,7 +3,7 @@ use quote::ToTokens;
 
 use crate::{
   codegen::{get_register_ident, js_mod_to_token_stream},
-  BindgenResult, NapiEnum, TryToTokens,
+  BindgenResult, NapiEnum, NapiStructuredEnum, TryToTokens,
 };
 
 impl TryToTokens for NapiEnum {
@@ -158,3 +158,149 @@ impl NapiEnum {
     }
   }
 }
+
+impl TryToTokens for NapiStructuredEnum {
+  fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
+    let napi_value_conversion = self.gen_napi_value_map_impl();
+
+    (quote! {
+      #napi_value_conversion
+    })
+    .to_tokens(tokens);
+
+    Ok(())
+  }
+}
+
+impl NapiStructuredEnum {
+  fn gen_napi_value_map_impl(&self) -> TokenStream {
+    let name = &self.name;
+    let name_str = self.name.to_string();
+    let discriminant = &self.discriminant;
+    let mut from_napi_branches = vec![];
+    let mut to_napi_branches = vec![];
+
+    self.variants.iter().for_each(|v| {
+      let v_name = &v.name;
+      let v_name_str = v.name.to_string();
+      let mut field_destructions = vec![];
+      let mut obj_field_setters = vec![];
+      let mut obj_field_getters = vec![];
+
+      for (i, field) in v.fields.iter().enumerate() {
+        let field_js_name = &field.js_name;
+        let ty = &field.ty;
+        let alias_ident = format_ident!("arg{}", i);
+        match &field.name {
+          syn::Member::Named(ident) => field_destructions.push(quote! { #ident: #alias_ident }),
+          syn::Member::Unnamed(_) => field_destructions.push(quote! { #alias_ident }),
+        }
+        if is_optional_type(ty) {
+          obj_field_setters.push(quote! {
+            if #alias_ident.is_some() {
+              obj.set(#field_js_name, #alias_ident)?;
+            }
+          });
+          obj_field_getters.push(quote! { let #alias_ident: #ty = obj.get(#field_js_name)?; });
+        } else {
+          obj_field_setters.push(quote! { obj.set(#field_js_name, #alias_ident)?; });
+          obj_field_getters.push(quote! {
+            let #alias_ident: #ty = obj.get(#field_js_name)?.ok_or_else(|| napi::bindgen_prelude::Error::new(
+              napi::bindgen_prelude::Status::InvalidArg,
+              format!("Missing field `{}`", #field_js_name),
+            ))?;
+          });
+        }
+      }
+
+      let destructed_variant = if v.fields.is_empty() {
+        quote! { #name::#v_name }
+      } else if v.is_tuple {
+        quote! { #name::#v_name(#(#field_destructions),*) }
+      } else {
+        quote! { #name::#v_name { #(#field_destructions),* } }
+      };
+
+      to_napi_branches.push(quote! {
+        #destructed_variant => {
+          obj.set(#discriminant, #v_name_str)?;
+          #(#obj_field_setters)*
+        }
+      });
+      from_napi_branches.push(quote! {
+        #v_name_str => {
+          #(#obj_field_getters)*
+          Ok(#destructed_variant)
+        }
+      });
+    });
+
+    quote! {
+      impl napi::bindgen_prelude::TypeName for #name {
+        fn type_name() -> &'static str {
+          #name_str
+        }
+
+        fn value_type() -> napi::ValueType {
+          napi::ValueType::Object
+        }
+      }
+
+      impl napi::bindgen_prelude::ValidateNapiValue for #name {}
+
+      impl napi::bindgen_prelude::FromNapiValue for #name {
+        unsafe fn from_napi_value(
+          env: napi::bindgen_prelude::sys::napi_env,
+          napi_val: napi::bindgen_prelude::sys::napi_value
+        ) -> napi::bindgen_prelude::Result<Self> {
+          let obj = napi::bindgen_prelude::Object::from_napi_value(env, napi_val)?;
+          let discriminant: String = obj.get(#discriminant)?.ok_or_else(|| napi::bindgen_prelude::Error::new(
+            napi::bindgen_prelude::Status::InvalidArg,
+            format!("Missing discriminant field `{}` of enum `{}`", #discriminant, #name_str),
+          ))?;
+
+          match discriminant.as_str() {
+            #(#from_napi_branches,)*
+            _ => {
+              Err(napi::bindgen_prelude::error!(
+                napi::bindgen_prelude::Status::InvalidArg,
+                "value `{}` does not match any variant of enum `{}`",
+                discriminant,
+                #name_str
+              ))
+            }
+          }
+        }
+      }
+
+      impl napi::bindgen_prelude::ToNapiValue for #name {
+        unsafe fn to_napi_value(
+          env: napi::bindgen_prelude::sys::napi_env,
+          val: Self
+        ) -> napi::bindgen_prelude::Result<napi::bindgen_prelude::sys::napi_value> {
+          let env_wrapper = napi::bindgen_prelude::Env::from(env);
+          let mut obj = env_wrapper.create_object()?;
+
+          match val {
+            #(#to_napi_branches,)*
+          }
+
+          napi::bindgen_prelude::Object::to_napi_value(env, obj)
+        }
+      }
+    }
+  }
+}
+
+fn is_optional_type(ty: &syn::Type) -> bool {
+  if let syn::Type::Path(syn::TypePath {
+    path: syn::Path { segments, .. },
+    ..
+  }) = ty
+  {
+    if let Some(last_path) = segments.last() {
+      return last_path.ident == "Option";
+    }
+  }
+  false
+}
@@ -62,7 +62,7 @@ impl TryToTokens for NapiFn {
           let _make_ref = |a: ::std::ptr::NonNull<napi::bindgen_prelude::sys::napi_value__>| {
             let mut node_ref = ::std::mem::MaybeUninit::uninit();
             napi::bindgen_prelude::check_status!(unsafe {
-                napi::bindgen_prelude::sys::napi_create_reference(env, a.as_ptr(), 0, node_ref.as_mut_ptr())
+                napi::bindgen_prelude::sys::napi_create_reference(env, a.as_ptr(), 1, node_ref.as_mut_ptr())
               },
               "failed to create napi ref"
             )?;
@@ -99,11 +99,27 @@ impl TryToTokens for NapiFn {
       } else {
         quote! { Ok(#receiver(#(#arg_names),*).await) }
       };
-      quote! {
-        napi::bindgen_prelude::execute_tokio_future(env, async move { #call }, move |env, #receiver_ret_name| {
-          _args_ref.drop(env);
-          #ret
-        })
+      if self.is_async_stream() {
+        quote! {
+          // the stream borrows the arguments until it's dropped, not only while the future runs
+          napi::bindgen_prelude::ToNapiValue::to_napi_value(
+            env,
+            napi::bindgen_prelude::AsyncIterable::from_future(async move { #call })
+              .with_release(move |env| _args_ref.drop(env)),
+          )
+        }
+      } else {
+        quote! {
+          // resolve with the `Err` as well, the references must be deleted on the JavaScript thread either way
+          napi::bindgen_prelude::execute_tokio_future(env, async move {
+            let _ret: napi::bindgen_prelude::Result<_> = #call;
+            Ok(_ret)
+          }, move |env, _ret| {
+            _args_ref.drop(env);
+            let #receiver_ret_name = _ret?;
+            #ret
+          })
+        }
       }
     };
 
@@ -161,6 +177,16 @@ impl TryToTokens for NapiFn {
       }
     };
 
+    // `async fn` must always return a `Promise`, errors raised before its future is spawned reject it
+    let handle_error = if self.is_async {
+      quote! { napi::bindgen_prelude::reject_promise(env, e) }
+    } else {
+      quote! {
+        napi::bindgen_prelude::JsError::from(e).throw_into(env);
+        std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
+      }
+    };
+
     (quote! {
       #(#attrs)*
       #[doc(hidden)]
@@ -172,8 +198,8 @@ impl TryToTokens for NapiFn {
       ) -> napi::bindgen_prelude::sys::napi_value {
         unsafe {
           #function_call.unwrap_or_else(|e| {
-            napi::bindgen_prelude::JsError::from(e).throw_into(env);
-            std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
+            let e = e.with_last_error_info(env);
+            #handle_error
           })
         }
       }
@@ -213,13 +239,15 @@ impl NapiFn {
           });
         }
         Some(FnSelf::MutRef) => {
+          // Exclusivity isn't checked, reentrant JavaScript calls on the same instance alias `this`
           refs.push(make_ref(quote! { cb.this }));
           arg_conversions.push(quote! {
             let this_ptr = unsafe { cb.unwrap_raw::<#parent>()? };
             let this: &mut #parent = Box::leak(Box::from_raw(this_ptr));
           });
         }
-        _ => {}
+        // moved out after the arguments are converted, see below
+        Some(FnSelf::Value) | None => {}
       };
     }
 
@@ -260,6 +288,10 @@ impl NapiFn {
                       }
                     }
                   }
+                } else if super::is_injected_global(path) {
+                  args.push(quote! { napi::bindgen_prelude::Global::from_env(env)? });
+                  skipped_arg_count += 1;
+                  continue;
                 } else if p.ident == "This" {
                   if let syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments {
                     args: angle_bracketed_args,
@@ -284,10 +316,11 @@ impl NapiFn {
                               primitive_type
                             );
                           }
+                          let js_name = &self.js_name;
                           args.push(
                             quote! {
                               {
-                                <#ident as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, cb.this)?
+                                <#ident as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, cb.explicit_this(#js_name)?)?
                               }
                             },
                           );
@@ -346,6 +379,14 @@ impl NapiFn {
       }
     }
 
+    if let (Some(parent), Some(FnSelf::Value)) = (&self.parent, &self.fn_self) {
+      // The native value is moved out of the JavaScript object, so a failed argument conversion
+      // leaves the instance usable. Later calls on it fail to unwrap and throw.
+      arg_conversions.push(quote! {
+        let this: #parent = unsafe { cb.remove_wrap::<#parent>()? };
+      });
+    }
+
     Ok(ArgConversions {
       arg_conversions,
       args,
@@ -399,6 +440,16 @@ impl NapiFn {
         };
         (q, NapiArgType::MutRef)
       }
+      syn::Type::Reference(syn::TypeReference { elem, .. }) if is_js_value_type(elem) => {
+        // JavaScript value handles are converted into a local which is borrowed for the rest of the call
+        let q = quote! {
+          let #arg_name = &{
+            #type_check
+            <#elem as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, cb.get_arg(#index))?
+          };
+        };
+        (q, NapiArgType::Value)
+      }
       syn::Type::Reference(syn::TypeReference { elem, .. }) => {
         let q = quote! {
           let #arg_name = {
@@ -473,11 +524,7 @@ impl NapiFn {
     let name = &self.name;
 
     match self.fn_self {
-      Some(FnSelf::Value) => {
-        // impossible, panic! in parser
-        unimplemented!();
-      }
-      Some(FnSelf::Ref) | Some(FnSelf::MutRef) => quote! { this.#name },
+      Some(_) => quote! { this.#name },
       None => match &self.parent {
         Some(class) => quote! { #class::#name },
         None => quote! { #name },
@@ -504,22 +551,52 @@ impl NapiFn {
           quote! { cb.construct(#js_name, #ret) }
         }
       } else if self.kind == FnKind::Factory {
+        // inherited factories are called on the subclass, look up the class by name instead of using `this`
+        let class_js_name = format!(
+          "{}\0",
+          self.parent_js_name.as_deref().unwrap_or(js_name.as_str())
+        );
         if self.is_ret_result {
           if self.parent_is_generator {
-            quote! { cb.generator_factory(#js_name, #ret?) }
+            quote! { cb.generator_factory(#class_js_name, #ret?) }
           } else {
-            quote! { cb.factory(#js_name, #ret?) }
+            quote! { cb.factory(#class_js_name, #ret?) }
           }
         } else if self.parent_is_generator {
-          quote! { cb.generator_factory(#js_name, #ret) }
+          quote! { cb.generator_factory(#class_js_name, #ret) }
         } else {
-          quote! { cb.factory(#js_name, #ret) }
+          quote! { cb.factory(#class_js_name, #ret) }
+        }
+      } else if let Some(wrapper) = impl_trait_wrapper(ty) {
+        // the `Err` of async functions rejects the Promise before reaching here
+        if self.return_result_object {
+          quote! {
+            napi::bindgen_prelude::ToNapiValue::to_napi_value(env, napi::bindgen_prelude::ResultObject(#ret.map(#wrapper)))
+          }
+        } else if self.is_ret_result && !self.is_async {
+          quote! {
+            match #ret {
+              Ok(value) => napi::bindgen_prelude::ToNapiValue::to_napi_value(env, #wrapper(value)),
+              Err(err) => {
+                napi::bindgen_prelude::JsError::from(err.with_last_error_info(env)).throw_into(env);
+                Ok(std::ptr::null_mut())
+              },
+            }
+          }
+        } else {
+          quote! {
+            napi::bindgen_prelude::ToNapiValue::to_napi_value(env, #wrapper(#ret))
+          }
         }
       } else if self.is_ret_result {
         if self.is_async {
           quote! {
             <#ty as napi::bindgen_prelude::ToNapiValue>::to_napi_value(env, #ret)
           }
+        } else if self.return_result_object {
+          quote! {
+            napi::bindgen_prelude::ToNapiValue::to_napi_value(env, napi::bindgen_prelude::ResultObject(#ret))
+          }
         } else if is_return_self {
           quote! { #ret.map(|_| cb.this) }
         } else {
@@ -527,7 +604,7 @@ impl NapiFn {
             match #ret {
               Ok(value) => napi::bindgen_prelude::ToNapiValue::to_napi_value(env, value),
               Err(err) => {
-                napi::bindgen_prelude::JsError::from(err).throw_into(env);
+                napi::bindgen_prelude::JsError::from(err.with_last_error_info(env)).throw_into(env);
                 Ok(std::ptr::null_mut())
               },
             }
@@ -592,6 +669,33 @@ impl NapiFn {
   }
 }
 
+/// `impl Fn(A, B) -> Result<R>` and `impl Iterator<Item = Result<T>>` can't implement `ToNapiValue` directly,
+/// wrap them into `napi::bindgen_prelude::Closure` and `napi::bindgen_prelude::Iterable`.
+fn impl_trait_wrapper(ty: &syn::Type) -> Option<TokenStream> {
+  if let syn::Type::ImplTrait(impl_trait) = ty {
+    impl_trait.bounds.iter().find_map(|bound| match bound {
+      syn::TypeParamBound::Trait(t) => t.path.segments.last().and_then(|segment| {
+        match (&segment.arguments, segment.ident.to_string().as_str()) {
+          (syn::PathArguments::Parenthesized(arguments), "Fn") => {
+            let args = arguments.inputs.iter();
+            Some(quote! { napi::bindgen_prelude::Closure::<(#(#args,)*), _, _>::new })
+          }
+          (syn::PathArguments::AngleBracketed(_), "Iterator") => {
+            Some(quote! { napi::bindgen_prelude::Iterable::new })
+          }
+          (syn::PathArguments::AngleBracketed(_), "Stream") => {
+            Some(quote! { napi::bindgen_prelude::AsyncIterable::new })
+          }
+          _ => None,
+        }
+      }),
+      _ => None,
+    })
+  } else {
+    None
+  }
+}
+
 struct ArgConversions {
   pub args: Vec<TokenStream>,
   pub arg_conversions: Vec<TokenStream>,
@@ -600,6 +704,41 @@ struct ArgConversions {
   pub unsafe_: bool,
 }
 
+/// JavaScript value handles which can be borrowed as `&T` arguments
+const JS_VALUE_TYPES: &[&str] = &[
+  "JsUnknown",
+  "JsUndefined",
+  "JsNull",
+  "JsBoolean",
+  "JsNumber",
+  "JsString",
+  "JsObject",
+  "JsGlobal",
+  "JsFunction",
+  "JsExternal",
+  "JsSymbol",
+  "JsBuffer",
+  "JsArrayBuffer",
+  "JsTypedArray",
+  "JsDataView",
+  "JsBigInt",
+  "JsDate",
+  "Object",
+];
+
+fn is_js_value_type(ty: &syn::Type) -> bool {
+  match ty {
+    syn::Type::Path(syn::TypePath { qself: None, path }) => path
+      .segments
+      .last()
+      .map(|segment| {
+        segment.arguments.is_empty() && JS_VALUE_TYPES.iter().any(|name| segment.ident == name)
+      })
+      .unwrap_or(false),
+    _ => false,
+  }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 enum NapiArgType {
   Ref,
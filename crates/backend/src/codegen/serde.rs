@@ -0,0 +1,44 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::ToTokens;
+
+use crate::{BindgenResult, NapiFromSerde, TryToTokens};
+
+impl TryToTokens for NapiFromSerde {
+  fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
+    let name = &self.name;
+    let name_str = Literal::string(&self.name.to_string());
+    (quote! {
+      impl napi::bindgen_prelude::TypeName for #name {
+        fn type_name() -> &'static str {
+          #name_str
+        }
+
+        fn value_type() -> napi::ValueType {
+          napi::ValueType::Unknown
+        }
+      }
+
+      impl napi::bindgen_prelude::ValidateNapiValue for #name {}
+
+      impl napi::bindgen_prelude::FromNapiValue for #name {
+        unsafe fn from_napi_value(
+          env: napi::bindgen_prelude::sys::napi_env,
+          napi_val: napi::bindgen_prelude::sys::napi_value
+        ) -> napi::bindgen_prelude::Result<Self> {
+          let value = <napi::JsUnknown as napi::NapiValue>::from_raw_unchecked(env, napi_val);
+          napi::Env::from_raw(env).from_js_value(value).map_err(|e| {
+            napi::bindgen_prelude::error!(
+              e.status,
+              "Failed to deserialize napi value into `{}`. {}",
+              #name_str,
+              e.reason,
+            )
+          })
+        }
+      }
+    })
+    .to_tokens(tokens);
+
+    Ok(())
+  }
+}
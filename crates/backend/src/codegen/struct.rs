@@ -6,7 +6,7 @@ use quote::ToTokens;
 
 use crate::{
   codegen::{get_intermediate_ident, get_register_ident, js_mod_to_token_stream},
-  BindgenResult, FnKind, NapiImpl, NapiStruct, NapiStructKind, TryToTokens,
+  BindgenResult, FnKind, NapiImpl, NapiStruct, NapiStructField, NapiStructKind, TryToTokens,
 };
 
 static NAPI_IMPL_ID: AtomicU32 = AtomicU32::new(0);
@@ -476,16 +476,23 @@ impl NapiStruct {
   }
 
   fn gen_to_napi_value_obj_impl(&self) -> TokenStream {
+    if self.is_tuple {
+      return self.gen_to_napi_value_tuple_obj_impl();
+    }
+
     let name = &self.name;
     let name_str = self.name.to_string();
 
     let mut obj_field_setters = vec![];
     let mut obj_field_getters = vec![];
     let mut field_destructions = vec![];
+    let mut debug_fields = vec![];
 
     for field in self.fields.iter() {
       let field_js_name = &field.js_name;
       let ty = &field.ty;
+      let field_name = &field.name;
+      debug_fields.push(quote! { .field(#field_js_name, &self.#field_name) });
       let is_optional_field = if let syn::Type::Path(syn::TypePath {
         path: syn::Path { segments, .. },
         ..
@@ -499,63 +506,40 @@ impl NapiStruct {
       } else {
         false
       };
+      let get_field = if field.base64 {
+        quote! { obj.get::<_, napi::bindgen_prelude::Base64>(#field_js_name)?.map(|v| v.0) }
+      } else {
+        quote! { obj.get(#field_js_name)? }
+      };
       match &field.name {
         syn::Member::Named(ident) => {
           let alias_ident = format_ident!("{}_", ident);
           field_destructions.push(quote! { #ident: #alias_ident });
           if is_optional_field {
-            obj_field_setters.push(quote! {
-              if #alias_ident.is_some() {
-                obj.set(#field_js_name, #alias_ident)?;
-              }
-            });
+            obj_field_setters.push(gen_optional_field_setter(field, quote! { #alias_ident }));
           } else {
-            obj_field_setters.push(quote! { obj.set(#field_js_name, #alias_ident)?; });
+            let value = wrap_field_value(field, quote! { #alias_ident });
+            obj_field_setters.push(quote! { obj.set(#field_js_name, #value)?; });
           }
           if is_optional_field {
-            obj_field_getters.push(quote! { let #alias_ident: #ty = obj.get(#field_js_name)?; });
+            obj_field_getters.push(quote! { let #alias_ident: #ty = #get_field; });
           } else {
             obj_field_getters.push(quote! {
-              let #alias_ident: #ty = obj.get(#field_js_name)?.ok_or_else(|| napi::bindgen_prelude::Error::new(
+              let #alias_ident: #ty = #get_field.ok_or_else(|| napi::bindgen_prelude::Error::new(
                 napi::bindgen_prelude::Status::InvalidArg,
                 format!("Missing field `{}`", #field_js_name),
               ))?;
             });
           }
         }
-        syn::Member::Unnamed(i) => {
-          field_destructions.push(quote! { arg #i });
-          if is_optional_field {
-            obj_field_setters.push(quote! {
-              if arg #1.is_some() {
-                obj.set(#field_js_name, arg #i)?;
-              }
-            });
-          } else {
-            obj_field_setters.push(quote! { obj.set(#field_js_name, arg #1)?; });
-          }
-          if is_optional_field {
-            obj_field_getters.push(quote! { let arg #i: #ty = obj.get(#field_js_name)?; });
-          } else {
-            obj_field_getters.push(quote! {
-              let arg #i: #ty = obj.get(#field_js_name)?.ok_or_else(|| napi::bindgen_prelude::Error::new(
-                napi::bindgen_prelude::Status::InvalidArg,
-                format!("Missing field `{}`", #field_js_name),
-              ))?;
-            });
-          }
+        syn::Member::Unnamed(_) => {
+          unreachable!("tuple structs are converted by `gen_to_napi_value_tuple_obj_impl`")
         }
       }
     }
 
-    let destructed_fields = if self.is_tuple {
-      quote! {
-        Self (#(#field_destructions),*)
-      }
-    } else {
-      quote! {
-        Self {#(#field_destructions),*}
-      }
+    let destructed_fields = quote! {
+      Self {#(#field_destructions),*}
     };
 
     let to_napi_value = if self.object_to_js {
@@ -598,6 +582,133 @@ impl NapiStruct {
       quote! {}
     };
 
+    let debug = if self.implement_debug {
+      let js_name_str = &self.js_name;
+      quote! {
+        impl std::fmt::Debug for #name {
+          fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct(#js_name_str)
+              #(#debug_fields)*
+              .finish()
+          }
+        }
+      }
+    } else {
+      quote! {}
+    };
+
+    quote! {
+      impl napi::bindgen_prelude::TypeName for #name {
+        fn type_name() -> &'static str {
+          #name_str
+        }
+
+        fn value_type() -> napi::ValueType {
+          napi::ValueType::Object
+        }
+      }
+
+      #to_napi_value
+
+      #from_napi_value
+
+      #debug
+
+      impl napi::bindgen_prelude::ValidateNapiValue for #name {}
+    }
+  }
+
+  /// `#[napi(object)]` tuple structs are converted from and into fixed-length arrays
+  fn gen_to_napi_value_tuple_obj_impl(&self) -> TokenStream {
+    let name = &self.name;
+    let name_str = self.name.to_string();
+    let js_name_str = &self.js_name;
+    let len = self.fields.len() as u32;
+
+    let mut field_destructions = vec![];
+    let mut arr_element_setters = vec![];
+    let mut arr_element_getters = vec![];
+    let mut debug_fields = vec![];
+
+    for (index, field) in self.fields.iter().enumerate() {
+      let index = index as u32;
+      let ty = &field.ty;
+      let field_name = &field.name;
+      let alias_ident = format_ident!("field{}_", index);
+      debug_fields.push(quote! { .field(&self.#field_name) });
+      field_destructions.push(quote! { #alias_ident });
+      let value = wrap_field_value(field, quote! { #alias_ident });
+      arr_element_setters.push(quote! { arr.set(#index, #value)?; });
+      let get_element = if field.base64 {
+        quote! { arr.get::<napi::bindgen_prelude::Base64>(#index)?.map(|v| v.0) }
+      } else {
+        quote! { arr.get::<#ty>(#index)? }
+      };
+      arr_element_getters.push(quote! {
+        let #alias_ident: #ty = #get_element.ok_or_else(|| napi::bindgen_prelude::Error::new(
+          napi::bindgen_prelude::Status::InvalidArg,
+          format!("Missing element {} of `{}`", #index, #js_name_str),
+        ))?;
+      });
+    }
+
+    let to_napi_value = if self.object_to_js {
+      quote! {
+        impl napi::bindgen_prelude::ToNapiValue for #name {
+          unsafe fn to_napi_value(env: napi::bindgen_prelude::sys::napi_env, val: #name) -> napi::bindgen_prelude::Result<napi::bindgen_prelude::sys::napi_value> {
+            let env_wrapper = napi::bindgen_prelude::Env::from(env);
+            let mut arr = env_wrapper.create_array(#len)?;
+
+            let Self(#(#field_destructions),*) = val;
+            #(#arr_element_setters)*
+
+            napi::bindgen_prelude::Array::to_napi_value(env, arr)
+          }
+        }
+      }
+    } else {
+      quote! {}
+    };
+
+    let from_napi_value = if self.object_from_js {
+      quote! {
+        impl napi::bindgen_prelude::FromNapiValue for #name {
+          unsafe fn from_napi_value(
+            env: napi::bindgen_prelude::sys::napi_env,
+            napi_val: napi::bindgen_prelude::sys::napi_value
+          ) -> napi::bindgen_prelude::Result<Self> {
+            let arr = napi::bindgen_prelude::Array::from_napi_value(env, napi_val)?;
+            if arr.len() != #len {
+              return Err(napi::bindgen_prelude::Error::new(
+                napi::bindgen_prelude::Status::InvalidArg,
+                format!("Expected `{}` to be an array of length {}, got {}", #js_name_str, #len, arr.len()),
+              ));
+            }
+
+            #(#arr_element_getters)*
+
+            Ok(Self(#(#field_destructions),*))
+          }
+        }
+      }
+    } else {
+      quote! {}
+    };
+
+    let debug = if self.implement_debug {
+      quote! {
+        impl std::fmt::Debug for #name {
+          fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_tuple(#js_name_str)
+              #(#debug_fields)*
+              .finish()
+          }
+        }
+      }
+    } else {
+      quote! {}
+    };
+
     quote! {
       impl napi::bindgen_prelude::TypeName for #name {
         fn type_name() -> &'static str {
@@ -613,6 +724,8 @@ impl NapiStruct {
 
       #from_napi_value
 
+      #debug
+
       impl napi::bindgen_prelude::ValidateNapiValue for #name {}
     }
   }
@@ -761,6 +874,26 @@ impl NapiStruct {
       props.push(prop);
     }
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let register_extends = self.extends.as_ref().map(|extends| {
+      let name = &self.name;
+      let parent = &extends.name;
+      let field = &extends.field;
+      let parent_str = parent.to_string();
+      quote! {
+        // Instances are unwrapped as the superclass by inherited methods, which is only sound
+        // because the superclass is the first field of the `#[repr(C)]` struct
+        let _: fn(&#name) -> &#parent = |sub| &sub.#field;
+        napi::__private::register_class_extends(#name_str, #parent_str);
+      }
+    });
+    let register_cleanup = if self.use_cleanup {
+      let name = &self.name;
+      quote! {
+        napi::__private::register_class_cleanup(<#name as napi::bindgen_prelude::NapiCleanup>::on_env_exit);
+      }
+    } else {
+      quote! {}
+    };
     quote! {
       #[allow(non_snake_case)]
       #[allow(clippy::all)]
@@ -768,6 +901,8 @@ impl NapiStruct {
       #[napi::bindgen_prelude::ctor]
       fn #struct_register_name() {
         napi::__private::register_class(#name_str, #js_mod_ident, #js_name, vec![#(#props),*]);
+        #register_extends
+        #register_cleanup
       }
     }
   }
@@ -884,3 +1019,29 @@ impl NapiImpl {
     })
   }
 }
+
+/// `#[napi(base64)]` fields are converted through `Base64` instead of `Vec<u8>`
+fn wrap_field_value(field: &NapiStructField, value: TokenStream) -> TokenStream {
+  if field.base64 {
+    quote! { napi::bindgen_prelude::Base64(#value) }
+  } else {
+    value
+  }
+}
+
+fn gen_optional_field_setter(field: &NapiStructField, value: TokenStream) -> TokenStream {
+  let field_js_name = &field.js_name;
+  if field.base64 {
+    quote! {
+      if let Some(inner) = #value {
+        obj.set(#field_js_name, napi::bindgen_prelude::Base64(inner))?;
+      }
+    }
+  } else {
+    quote! {
+      if #value.is_some() {
+        obj.set(#field_js_name, #value)?;
+      }
+    }
+  }
+}
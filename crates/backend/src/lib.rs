@@ -53,6 +53,8 @@ napi_ast_impl! {
  (Struct, NapiStruct),
  (Impl, NapiImpl),
  (Enum, NapiEnum),
+ (StructuredEnum, NapiStructuredEnum),
+ (FromSerde, NapiFromSerde),
  (Const, NapiConst),
 }
 
@@ -66,6 +68,7 @@ pub(crate) static PRIMITIVE_TYPES: &[(&str, (&str, bool, bool))] = &[
   ("i32", ("number", false, false)),
   ("i64", ("number", false, false)),
   ("f32", ("number", false, false)),
+  ("CheckedF32", ("number", false, false)),
   ("f64", ("number", false, false)),
   ("u8", ("number", false, false)),
   ("u16", ("number", false, false)),
@@ -78,11 +81,14 @@ pub(crate) static PRIMITIVE_TYPES: &[(&str, (&str, bool, bool))] = &[
   ("isize", ("bigint", false, false)),
   ("JsBigInt", ("bigint", false, false)),
   ("BigInt", ("bigint", false, false)),
+  ("SmartInt", ("number | bigint", false, true)),
   ("JsBoolean", ("boolean", false, false)),
   ("bool", ("boolean", false, false)),
   ("JsString", ("string", false, false)),
   ("String", ("string", false, false)),
   ("str", ("string", false, false)),
+  ("CString", ("string", false, false)),
+  ("CStr", ("string", false, false)),
   ("Latin1String", ("string", false, false)),
   ("Utf16String", ("string", false, false)),
   ("char", ("string", false, false)),
@@ -1,6 +1,7 @@
 mod r#const;
 mod r#enum;
 mod r#fn;
+mod serde;
 pub(crate) mod r#struct;
 
 use std::{cell::RefCell, collections::HashMap};
@@ -13,6 +14,7 @@ pub struct TypeDef {
   pub kind: String,
   pub name: String,
   pub original_name: Option<String>,
+  pub extends: Option<String>,
   pub def: String,
   pub js_mod: Option<String>,
   pub js_doc: String,
@@ -102,13 +104,19 @@ impl ToString for TypeDef {
     } else {
       "".to_owned()
     };
+    let extends = if let Some(extends) = &self.extends {
+      format!(", \"extends\": \"{}\"", extends)
+    } else {
+      "".to_owned()
+    };
     format!(
-      r#"{{"kind": "{}", "name": "{}", "js_doc": "{}", "def": "{}"{}{}}}"#,
+      r#"{{"kind": "{}", "name": "{}", "js_doc": "{}", "def": "{}"{}{}{}}}"#,
       self.kind,
       self.name,
       escape_json(&self.js_doc),
       escape_json(&self.def),
       original_name,
+      extends,
       js_mod,
     )
   }
@@ -129,6 +137,8 @@ static KNOWN_TYPES: Lazy<HashMap<&'static str, (&'static str, bool, bool)>> = La
     ("Value", ("any", false, false)),
     ("Map", ("Record<string, any>", false, false)),
     ("HashMap", ("Record<{}, {}>", false, false)),
+    ("JsMap", ("Map<{}, {}>", false, false)),
+    ("PropertyMap", ("Record<{}, {}>", false, false)),
     ("ArrayBuffer", ("ArrayBuffer", false, false)),
     ("Int8Array", ("Int8Array", false, false)),
     ("Uint8Array", ("Uint8Array", false, false)),
@@ -148,7 +158,27 @@ static KNOWN_TYPES: Lazy<HashMap<&'static str, (&'static str, bool, bool)>> = La
     ("JsBuffer", ("Buffer", false, false)),
     ("Buffer", ("Buffer", false, false)),
     ("Vec", ("Array<{}>", false, false)),
+    ("VecDeque", ("Array<{}>", false, false)),
+    ("SmallVec", ("{}", false, false)),
+    ("Cow", ("{}", false, false)),
+    ("RefCell", ("{}", false, false)),
+    ("Rc", ("{}", false, false)),
+    ("Mutex", ("{}", false, false)),
+    ("RwLock", ("{}", false, false)),
+    ("Arc", ("{}", false, false)),
+    ("WeakHandle", ("{ isAlive(): boolean }", false, false)),
+    ("ControlFlow", ("{ done: true, value: {} } | { done: false, value: {} }", false, true)),
+    ("Ordering", ("-1 | 0 | 1", false, true)),
+    ("Elapsed", ("number", false, false)),
     ("Result", ("Error | {}", false, true)),
+    (
+      "SettledResults",
+      (
+        "Array<{ status: 'fulfilled', value: {} } | { status: 'rejected', reason: Error }>",
+        false,
+        false,
+      ),
+    ),
     ("Error", ("Error", false, false)),
     ("JsError", ("Error", false, false)),
     ("JsTypeError", ("TypeError", false, false)),
@@ -193,6 +223,44 @@ static KNOWN_TYPES: Lazy<HashMap<&'static str, (&'static str, bool, bool)>> = La
   map
 });
 
+/// Names in `KNOWN_TYPES` that other crates and user code commonly reuse, with the paths they are
+/// recognized under
+static PATH_DEPENDENT_TYPES: &[(&str, &[&str])] = &[
+  ("Rc", &["std::rc::Rc", "alloc::rc::Rc"]),
+  ("Arc", &["std::sync::Arc", "alloc::sync::Arc"]),
+  ("Mutex", &["std::sync::Mutex"]),
+  ("RwLock", &["std::sync::RwLock"]),
+  ("Ordering", &["std::cmp::Ordering", "core::cmp::Ordering"]),
+  ("Elapsed", &["napi::bindgen_prelude::Elapsed"]),
+  ("Url", &["url::Url"]),
+];
+
+/// Whether `path` refers to the type known as `rust_ty`.
+///
+/// A qualified path has to be (a suffix of) a recognized one, e.g. `cmp::Ordering`,
+/// an unqualified name is only known if no `#[napi]` type of the same name is registered.
+fn is_known_path(path: &syn::Path, rust_ty: &str) -> bool {
+  let recognized = match PATH_DEPENDENT_TYPES
+    .iter()
+    .find(|(name, _)| *name == rust_ty)
+  {
+    Some((_, recognized)) => recognized,
+    None => return true,
+  };
+  if path.segments.len() == 1 {
+    return !ALIAS.with(|aliases| aliases.borrow().contains_key(rust_ty));
+  }
+  let written = path
+    .segments
+    .iter()
+    .map(|segment| segment.ident.to_string())
+    .collect::<Vec<_>>()
+    .join("::");
+  recognized
+    .iter()
+    .any(|p| *p == written || p.ends_with(&format!("::{}", written)))
+}
+
 fn fill_ty(template: &str, args: Vec<String>) -> String {
   let matches = template.match_indices("{}").collect::<Vec<_>>();
   if args.len() != matches.len() {
@@ -218,6 +286,17 @@ fn is_ts_union_type(rust_ty: &str) -> bool {
     .unwrap_or(false)
 }
 
+fn is_u8_slice_arg(arguments: &syn::PathArguments) -> bool {
+  match arguments {
+    syn::PathArguments::AngleBracketed(arguments) => matches!(
+      arguments.args.first(),
+      Some(syn::GenericArgument::Type(Type::Slice(slice)))
+        if matches!(slice.elem.as_ref(), Type::Path(p) if p.path.is_ident("u8"))
+    ),
+    _ => false,
+  }
+}
+
 fn is_ts_function_type_notation(ty: &Type) -> bool {
   match ty {
     Type::Path(syn::TypePath { qself: None, path }) => {
@@ -261,7 +340,8 @@ pub fn ty_to_ts_type(ty: &Type, is_return_ty: bool, is_struct_field: bool) -> (S
 
       if let Some(syn::PathSegment { ident, arguments }) = path.segments.last() {
         let rust_ty = ident.to_string();
-        let is_ts_union_type = is_ts_union_type(&rust_ty);
+        let is_known_path = is_known_path(path, &rust_ty);
+        let is_ts_union_type = is_known_path && is_ts_union_type(&rust_ty);
         let args = if let syn::PathArguments::AngleBracketed(arguments) = arguments {
           arguments
             .args
@@ -321,12 +401,35 @@ pub fn ty_to_ts_type(ty: &Type, is_return_ty: bool, is_struct_field: bool) -> (S
               Some((rust_ty, false))
             }
           });
-        } else if let Some(&(known_ty, _, _)) = KNOWN_TYPES.get(rust_ty.as_str()) {
+        } else if rust_ty == "Arc" && is_known_path && is_u8_slice_arg(arguments) {
+          // `Arc<[u8]>` is converted from and into a `Buffer`, not an `Array`
+          ts_ty = Some(("Buffer".to_owned(), false));
+        } else if rust_ty == "Url" && is_known_path {
+          // `url::Url` is accepted from a `URL` object too, but always returned as a string
+          let ts = if is_return_ty {
+            "string"
+          } else {
+            "string | URL"
+          };
+          ts_ty = Some((ts.to_owned(), false));
+        } else if let Some(&(known_ty, _, _)) =
+          KNOWN_TYPES.get(rust_ty.as_str()).filter(|_| is_known_path)
+        {
           if known_ty.contains("{}") {
-            ts_ty = Some((
-              fill_ty(known_ty, args.into_iter().map(|(arg, _)| arg).collect()),
-              false,
-            ));
+            let mut args = args.into_iter().map(|(arg, _)| arg).collect::<Vec<_>>();
+            if rust_ty == "HashMap" || rust_ty == "JsMap" || rust_ty == "PropertyMap" {
+              // the `BuildHasher` parameter doesn't show up in TypeScript
+              args.truncate(2);
+            }
+            if rust_ty == "SettledResults" {
+              // the error type is always converted into an `Error`
+              args.truncate(1);
+            }
+            if rust_ty == "ControlFlow" && args.len() == 1 {
+              // `Continue` defaults to `()`
+              args.push("undefined".to_owned());
+            }
+            ts_ty = Some((fill_ty(known_ty, args), false));
           } else {
             ts_ty = Some((known_ty.to_owned(), false));
           }
@@ -363,10 +466,51 @@ pub fn ty_to_ts_type(ty: &Type, is_return_ty: bool, is_struct_field: bool) -> (S
       ts_ty.unwrap_or_else(|| ("any".to_owned(), false))
     }
     Type::Group(g) => ty_to_ts_type(&g.elem, is_return_ty, is_struct_field),
+    Type::ImplTrait(impl_trait) => impl_trait
+      .bounds
+      .iter()
+      .find_map(|bound| match bound {
+        syn::TypeParamBound::Trait(t) => t.path.segments.last().and_then(|segment| {
+          if let syn::PathArguments::Parenthesized(arguments) = &segment.arguments {
+            let ret = match &arguments.output {
+              syn::ReturnType::Type(_, ty) => Some(ty.as_ref()),
+              syn::ReturnType::Default => None,
+            };
+            Some((r#fn::gen_ts_fn_type(arguments.inputs.iter(), ret), false))
+          } else if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+            // `impl Iterator<Item = Result<T>>` => `IterableIterator<T>`
+            // `impl Stream<Item = Result<T>>` => `AsyncIterableIterator<T>`
+            let iterator = match segment.ident.to_string().as_str() {
+              "Iterator" => "IterableIterator",
+              "Stream" => "AsyncIterableIterator",
+              _ => return None,
+            };
+            arguments.args.iter().find_map(|arg| match arg {
+              syn::GenericArgument::Binding(binding) if binding.ident == "Item" => Some((
+                format!(
+                  "{}<{}>",
+                  iterator,
+                  ty_to_ts_type(&binding.ty, true, false).0
+                ),
+                false,
+              )),
+              _ => None,
+            })
+          } else {
+            None
+          }
+        }),
+        _ => None,
+      })
+      .unwrap_or_else(|| ("any".to_owned(), false)),
     Type::Array(a) => {
       let (element_type, is_optional) = ty_to_ts_type(&a.elem, is_return_ty, is_struct_field);
       (format!("{}[]", element_type), is_optional)
     }
+    Type::Slice(s) => (
+      format!("Array<{}>", ty_to_ts_type(&s.elem, false, false).0),
+      false,
+    ),
     _ => ("any".to_owned(), false),
   }
 }
@@ -14,6 +14,7 @@ impl ToTypeDef for NapiConst {
       kind: "const".to_owned(),
       name: self.js_name.to_owned(),
       original_name: Some(self.name.to_string()),
+      extends: None,
       def: format!(
         "export const {}: {}",
         &self.js_name,
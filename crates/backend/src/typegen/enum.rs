@@ -1,5 +1,5 @@
-use super::{add_alias, ToTypeDef, TypeDef};
-use crate::{js_doc_from_comments, NapiEnum};
+use super::{add_alias, ty_to_ts_type, ToTypeDef, TypeDef};
+use crate::{js_doc_from_comments, NapiEnum, NapiStructuredEnum};
 
 impl ToTypeDef for NapiEnum {
   fn to_type_def(&self) -> Option<TypeDef> {
@@ -13,6 +13,7 @@ impl ToTypeDef for NapiEnum {
       kind: "enum".to_owned(),
       name: self.js_name.to_owned(),
       original_name: Some(self.name.to_string()),
+      extends: None,
       def: self.gen_ts_variants(),
       js_doc: js_doc_from_comments(&self.comments),
       js_mod: self.js_mod.to_owned(),
@@ -37,3 +38,47 @@ impl NapiEnum {
       .join(",\n ")
   }
 }
+
+impl ToTypeDef for NapiStructuredEnum {
+  fn to_type_def(&self) -> Option<TypeDef> {
+    if self.skip_typescript {
+      return None;
+    }
+
+    add_alias(self.name.to_string(), self.js_name.to_string());
+
+    Some(TypeDef {
+      kind: "type".to_owned(),
+      name: self.js_name.to_owned(),
+      original_name: Some(self.name.to_string()),
+      extends: None,
+      def: format!("export type {} = {}", self.js_name, self.gen_ts_variants()),
+      js_doc: js_doc_from_comments(&self.comments),
+      js_mod: self.js_mod.to_owned(),
+    })
+  }
+}
+
+impl NapiStructuredEnum {
+  fn gen_ts_variants(&self) -> String {
+    self
+      .variants
+      .iter()
+      .map(|v| {
+        let mut fields = vec![format!("{}: '{}'", self.discriminant, v.name)];
+        fields.extend(v.fields.iter().filter(|f| !f.skip_typescript).map(|f| {
+          let (ts_type, is_optional) = ty_to_ts_type(&f.ty, false, true);
+          let ts_type = f.ts_type.clone().unwrap_or(ts_type);
+          format!(
+            "{}{}: {}",
+            f.js_name,
+            if is_optional { "?" } else { "" },
+            ts_type
+          )
+        }));
+        format!("{{ {} }}", fields.join(", "))
+      })
+      .collect::<Vec<_>>()
+      .join(" | ")
+  }
+}
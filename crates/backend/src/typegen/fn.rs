@@ -71,30 +71,54 @@ impl ToTypeDef for NapiFn {
       return None;
     }
 
-    let def = format!(
-      r#"{prefix} {name}{generic}({args}){ret}"#,
-      prefix = self.gen_ts_func_prefix(),
-      name = &self.js_name,
-      generic = &self
-        .ts_generic_types
-        .as_ref()
-        .map(|g| format!("<{}>", g))
-        .unwrap_or_else(|| "".to_string()),
-      args = self
-        .ts_args_type
-        .clone()
-        .unwrap_or_else(|| self.gen_ts_func_args()),
-      ret = self
-        .ts_return_type
-        .clone()
-        .map(|t| format!(": {}", t))
-        .unwrap_or_else(|| self.gen_ts_func_ret()),
-    );
+    if let Some(ts_decl) = &self.ts_decl {
+      return Some(TypeDef {
+        kind: "fn".to_owned(),
+        name: self.js_name.clone(),
+        original_name: None,
+        extends: None,
+        def: ts_decl.to_owned(),
+        js_mod: self.js_mod.to_owned(),
+        js_doc: js_doc_from_comments(&self.comments),
+      });
+    }
+
+    let generic = self
+      .ts_generic_types
+      .as_ref()
+      .map(|g| format!("<{}>", g))
+      .unwrap_or_else(|| "".to_string());
+    let ret = self
+      .ts_return_type
+      .clone()
+      .map(|t| format!(": {}", t))
+      .unwrap_or_else(|| self.gen_ts_func_ret());
+    let args_list = if self.ts_args_type.is_empty() {
+      vec![self.gen_ts_func_args()]
+    } else {
+      // multiple `ts_args_type` become overload signatures sharing the single implementation
+      self.ts_args_type.clone()
+    };
+    let def = args_list
+      .iter()
+      .map(|args| {
+        format!(
+          r#"{prefix} {name}{generic}({args}){ret}"#,
+          prefix = self.gen_ts_func_prefix(),
+          name = &self.js_name,
+          generic = generic,
+          args = args,
+          ret = ret,
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
 
     Some(TypeDef {
       kind: "fn".to_owned(),
       name: self.js_name.clone(),
       original_name: None,
+      extends: None,
       def,
       js_mod: self.js_mod.to_owned(),
       js_doc: js_doc_from_comments(&self.comments),
@@ -103,11 +127,16 @@ impl ToTypeDef for NapiFn {
 }
 
 fn gen_callback_type(callback: &CallbackArg) -> String {
+  gen_ts_fn_type(callback.args.iter(), callback.ret.as_ref())
+}
+
+pub(crate) fn gen_ts_fn_type<'a>(
+  args: impl Iterator<Item = &'a syn::Type>,
+  ret: Option<&syn::Type>,
+) -> String {
   format!(
     "({args}) => {ret}",
-    args = &callback
-      .args
-      .iter()
+    args = args
       .enumerate()
       .map(|(i, arg)| {
         let (ts_type, is_optional) = ty_to_ts_type(arg, false, false);
@@ -118,7 +147,7 @@ fn gen_callback_type(callback: &CallbackArg) -> String {
         }
       })
       .collect::<FnArgList>(),
-    ret = match &callback.ret {
+    ret = match ret {
       Some(ty) => ty_to_ts_type(ty, true, false).0,
       None => "void".to_owned(),
     }
@@ -140,7 +169,10 @@ impl NapiFn {
             }
             if let syn::Type::Path(path) = path.ty.as_ref() {
               if let Some(PathSegment { ident, arguments }) = path.path.segments.last() {
-                if ident == "Reference" || ident == "WeakReference" {
+                if ident == "Reference"
+                  || ident == "WeakReference"
+                  || crate::codegen::is_injected_global(path)
+                {
                   return None;
                 }
                 if ident == "This" || ident == "this" {
@@ -242,8 +274,13 @@ impl NapiFn {
           "void".to_owned()
         };
 
-        if self.is_async {
+        if self.is_async && !self.is_async_stream() {
           format!(": Promise<{}>", ret)
+        } else if self.return_result_object {
+          format!(
+            ": {{ ok: true, value: {} }} | {{ ok: false, error: Error }}",
+            if ret == "void" { "undefined" } else { &ret }
+          )
         } else {
           format!(": {}", ret)
         }
@@ -0,0 +1,23 @@
+use super::{ToTypeDef, TypeDef};
+
+use crate::{js_doc_from_comments, typegen::add_alias, NapiFromSerde};
+
+impl ToTypeDef for NapiFromSerde {
+  fn to_type_def(&self) -> Option<TypeDef> {
+    add_alias(self.name.to_string(), self.js_name.to_string());
+
+    Some(TypeDef {
+      kind: "type".to_owned(),
+      name: self.js_name.to_owned(),
+      original_name: Some(self.name.to_string()),
+      extends: None,
+      def: format!(
+        "export type {} = {}",
+        self.js_name,
+        self.ts_type.as_deref().unwrap_or("any")
+      ),
+      js_doc: js_doc_from_comments(&self.comments),
+      js_mod: self.js_mod.to_owned(),
+    })
+  }
+}
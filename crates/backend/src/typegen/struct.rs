@@ -17,6 +17,18 @@ impl ToTypeDef for NapiStruct {
     });
     add_alias(self.name.to_string(), self.js_name.to_string());
 
+    if self.kind == NapiStructKind::Object && self.is_tuple {
+      return Some(TypeDef {
+        kind: "type".to_owned(),
+        name: self.js_name.to_owned(),
+        original_name: None,
+        extends: None,
+        def: format!("export type {} = [{}]", self.js_name, self.gen_ts_tuple()),
+        js_mod: self.js_mod.to_owned(),
+        js_doc: js_doc_from_comments(&self.comments),
+      });
+    }
+
     Some(TypeDef {
       kind: String::from(if self.kind == NapiStructKind::Object {
         "interface"
@@ -25,6 +37,7 @@ impl ToTypeDef for NapiStruct {
       }),
       name: self.js_name.to_owned(),
       original_name: Some(self.name.to_string()),
+      extends: self.extends.as_ref().map(|extends| extends.js_name.clone()),
       def: self.gen_ts_class(),
       js_mod: self.js_mod.to_owned(),
       js_doc: js_doc_from_comments(&self.comments),
@@ -58,6 +71,7 @@ impl ToTypeDef for NapiImpl {
         kind: "impl".to_owned(),
         name: self.js_name.to_owned(),
         original_name: None,
+        extends: None,
         def: format!(
           "[Symbol.iterator](): Iterator<{}, {}, {}>",
           ty_to_ts_type(output_type, false, true).0,
@@ -72,6 +86,7 @@ impl ToTypeDef for NapiImpl {
         kind: "impl".to_owned(),
         name: self.js_name.to_owned(),
         original_name: None,
+        extends: None,
         def: self
           .items
           .iter()
@@ -96,7 +111,33 @@ impl ToTypeDef for NapiImpl {
   }
 }
 
+// `kebab-case` names from `js_name_all` aren't valid identifiers in TypeScript
+fn ts_property_name(js_name: &str) -> String {
+  let is_identifier = js_name
+    .chars()
+    .enumerate()
+    .all(|(i, c)| c == '_' || c == '$' || c.is_alphabetic() || (i > 0 && c.is_numeric()));
+  if is_identifier && !js_name.is_empty() {
+    js_name.to_owned()
+  } else {
+    format!("'{}'", js_name)
+  }
+}
+
 impl NapiStruct {
+  fn gen_ts_tuple(&self) -> String {
+    self
+      .fields
+      .iter()
+      .map(|f| {
+        f.ts_type
+          .clone()
+          .unwrap_or_else(|| ty_to_ts_type(&f.ty, false, false).0)
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+
   fn gen_ts_class(&self) -> String {
     let mut ctor_args = vec![];
     let def = self
@@ -122,7 +163,7 @@ impl NapiStruct {
         let arg = f.ts_type.as_ref().map(|ty| ty.to_string()).unwrap_or(arg);
 
         let sep = if is_optional { "?" } else { "" };
-        let arg = format!("{}{}: {}", &f.js_name, sep, arg);
+        let arg = format!("{}{}: {}", ts_property_name(&f.js_name), sep, arg);
         if self.kind == NapiStructKind::Constructor {
           ctor_args.push(arg.clone());
         }
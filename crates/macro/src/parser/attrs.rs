@@ -44,6 +44,7 @@ macro_rules! attrgen {
     $mac! {
       (catch_unwind, CatchUnwind(Span)),
       (js_name, JsName(Span, String, Span)),
+      (js_name_all, JsNameAll(Span, String, Span)),
       (constructor, Constructor(Span)),
       (factory, Factory(Span)),
       (getter, Getter(Span, Option<Ident>)),
@@ -55,16 +56,25 @@ macro_rules! attrgen {
       (skip, Skip(Span)),
       (strict, Strict(Span)),
       (return_if_invalid, ReturnIfInvalid(Span)),
+      (return_result_object, ReturnResultObject(Span)),
       (object, Object(Span)),
       (object_from_js, ObjectFromJs(Span, Option<bool>)),
       (object_to_js, ObjectToJs(Span, Option<bool>)),
+      (from_serde, FromSerde(Span)),
       (custom_finalize, CustomFinalize(Span)),
+      (cleanup, Cleanup(Span)),
+      (debug, Debug(Span)),
+      (extends, Extends(Span, String, Span)),
       (namespace, Namespace(Span, String, Span)),
       (iterator, Iterator(Span)),
+      (discriminant, Discriminant(Span, String, Span)),
       (ts_args_type, TsArgsType(Span, String, Span)),
       (ts_return_type, TsReturnType(Span, String, Span)),
       (ts_type, TsType(Span, String, Span)),
+      (ts_element_type, TsElementType(Span, String, Span)),
       (ts_generic_types, TsGenericTypes(Span, String, Span)),
+      (ts_decl, TsDecl(Span, String, Span)),
+      (base64, Base64(Span)),
 
       // impl later
       // (inspectable, Inspectable(Span)),
@@ -171,7 +181,7 @@ macro_rules! methods {
   };
 
   (@method $name:ident, $variant:ident($($other:tt)*)) => {
-    #[allow(unused)]
+    #[allow(unused, clippy::wrong_self_convention)]
     pub fn $name(&self) -> Option<&$($other)*> {
       self.attrs
         .iter()
@@ -225,6 +235,21 @@ impl BindgenAttrs {
   }
 
   attrgen!(methods);
+
+  /// All the `ts_args_type` overrides in declaration order, more than one emits overload signatures
+  pub fn ts_args_types(&self) -> Vec<&str> {
+    self
+      .attrs
+      .iter()
+      .filter_map(|a| match &a.1 {
+        BindgenAttr::TsArgsType(_, s, _) => {
+          a.0.set(true);
+          Some(&s[..])
+        }
+        _ => None,
+      })
+      .collect()
+  }
 }
 
 impl Default for BindgenAttrs {
@@ -269,6 +294,16 @@ pub fn record_struct(ident: &Ident, js_name: String, opts: &BindgenAttrs) {
   });
 }
 
+pub fn recorded_struct_js_name(struct_name: &str) -> Option<String> {
+  STRUCTS.with(|state| {
+    state
+      .parsed
+      .borrow()
+      .get(struct_name)
+      .map(|parsed| parsed.js_name.clone())
+  })
+}
+
 pub fn check_recorded_struct_for_impl(ident: &Ident, opts: &BindgenAttrs) -> BindgenResult<String> {
   STRUCTS.with(|state| {
     let struct_name = ident.to_string();
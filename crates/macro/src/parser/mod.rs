@@ -10,8 +10,9 @@ use attrs::{BindgenAttr, BindgenAttrs};
 use convert_case::{Case, Casing};
 use napi_derive_backend::{
   BindgenResult, CallbackArg, Diagnostic, FnKind, FnSelf, Napi, NapiConst, NapiEnum,
-  NapiEnumVariant, NapiFn, NapiFnArg, NapiFnArgKind, NapiImpl, NapiItem, NapiStruct,
-  NapiStructField, NapiStructKind,
+  NapiEnumVariant, NapiFn, NapiFnArg, NapiFnArgKind, NapiFromSerde, NapiImpl, NapiItem, NapiStruct,
+  NapiStructExtends, NapiStructField, NapiStructKind, NapiStructuredEnum,
+  NapiStructuredEnumVariant,
 };
 use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::ToTokens;
@@ -19,7 +20,9 @@ use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream, Result as SynResult};
 use syn::{Attribute, Meta, NestedMeta, PatType, PathSegment, Signature, Type, Visibility};
 
-use crate::parser::attrs::{check_recorded_struct_for_impl, record_struct};
+use crate::parser::attrs::{
+  check_recorded_struct_for_impl, record_struct, recorded_struct_js_name,
+};
 
 thread_local! {
   static GENERATOR_STRUCT: RefCell<HashMap<String, bool>> = Default::default();
@@ -334,6 +337,92 @@ fn extract_result_ty(ty: &syn::Type) -> BindgenResult<Option<syn::Type>> {
   }
 }
 
+/// `ts_type` of a struct field, `ts_element_type` only replaces the element type of `Vec<T>`,
+/// e.g. `Vec<String>` with `ts_element_type = "'a' | 'b'"` becomes `Array<'a' | 'b'>`
+fn field_ts_type(field: &syn::Field, opts: &BindgenAttrs) -> BindgenResult<Option<String>> {
+  fn array_of(ty: &syn::Type, element: &str) -> Option<String> {
+    let segment = match ty {
+      syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last()?,
+      _ => return None,
+    };
+    let inner = match &segment.arguments {
+      syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments { args, .. }) => {
+        match args.first() {
+          Some(syn::GenericArgument::Type(inner)) => inner,
+          _ => return None,
+        }
+      }
+      _ => return None,
+    };
+    if segment.ident == "Option" {
+      array_of(inner, element)
+    } else if segment.ident == "Vec" {
+      Some(format!(
+        "Array<{}>",
+        array_of(inner, element).unwrap_or_else(|| element.to_owned())
+      ))
+    } else {
+      None
+    }
+  }
+
+  match (opts.ts_type(), opts.ts_element_type()) {
+    (Some(_), Some((_, span))) => Err(Diagnostic::span_error(
+      span,
+      "#[napi(ts_element_type)] can't be used together with #[napi(ts_type)]",
+    )),
+    (Some((ts_type, _)), None) => Ok(Some(ts_type.to_owned())),
+    (None, Some((element, _))) => match array_of(&field.ty, element) {
+      Some(ts_type) => Ok(Some(ts_type)),
+      None => bail_span!(
+        field.ty,
+        "#[napi(ts_element_type)] can only be applied to `Vec<T>` or `Option<Vec<T>>` fields"
+      ),
+    },
+    (None, None) => Ok(None),
+  }
+}
+
+/// `#[napi(base64)]` converts a `Vec<u8>` or `Option<Vec<u8>>` field of a `#[napi(object)]` from and to a base64 string
+fn field_base64(field: &syn::Field, opts: &BindgenAttrs, is_object: bool) -> BindgenResult<bool> {
+  fn is_bytes(ty: &syn::Type) -> bool {
+    let segment = match ty {
+      syn::Type::Path(syn::TypePath { qself: None, path }) => match path.segments.last() {
+        Some(segment) => segment,
+        None => return false,
+      },
+      _ => return false,
+    };
+    let inner = match &segment.arguments {
+      syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments { args, .. }) => {
+        match args.first() {
+          Some(syn::GenericArgument::Type(inner)) => inner,
+          _ => return false,
+        }
+      }
+      _ => return false,
+    };
+    if segment.ident == "Option" {
+      is_bytes(inner)
+    } else {
+      segment.ident == "Vec" && inner.to_token_stream().to_string() == "u8"
+    }
+  }
+
+  match opts.base64() {
+    None => Ok(false),
+    Some(span) if !is_object => Err(Diagnostic::span_error(
+      *span,
+      "#[napi(base64)] is only supported on #[napi(object)] struct fields",
+    )),
+    Some(_) if !is_bytes(&field.ty) => bail_span!(
+      field.ty,
+      "#[napi(base64)] can only be applied to `Vec<u8>` or `Option<Vec<u8>>` fields"
+    ),
+    Some(_) => Ok(true),
+  }
+}
+
 fn get_expr(mut expr: &syn::Expr) -> &syn::Expr {
   while let syn::Expr::Group(g) = expr {
     expr = &g.expr;
@@ -590,10 +679,7 @@ fn napi_fn_from_decl(
         if parent.is_some() {
           assert!(fn_self.is_none());
           if r.reference.is_none() {
-            errors.push(err_span!(
-              r,
-              "The native methods can't move values from napi. Try `&self` or `&mut self` instead."
-            ));
+            fn_self = Some(FnSelf::Value);
           } else if r.mutability.is_some() {
             fn_self = Some(FnSelf::MutRef);
           } else {
@@ -607,6 +693,26 @@ fn napi_fn_from_decl(
     })
     .collect::<Vec<_>>();
 
+  if let Some((ts_decl, span)) = opts.ts_decl() {
+    if ts_decl.trim().is_empty() {
+      errors.push(Diagnostic::span_error(
+        span,
+        "#[napi(ts_decl)] requires a non-empty TypeScript declaration",
+      ));
+    }
+    if opts.ts_args_type().is_some()
+      || opts.ts_return_type().is_some()
+      || opts.ts_generic_types().is_some()
+      || opts.skip_typescript().is_some()
+      || args.iter().any(|arg| arg.ts_arg_type.is_some())
+    {
+      errors.push(Diagnostic::span_error(
+        span,
+        "#[napi(ts_decl)] replaces the whole generated declaration, it can't be used together with #[napi(ts_args_type)], #[napi(ts_arg_type)], #[napi(ts_return_type)], #[napi(ts_generic_types)] or #[napi(skip_typescript)]",
+      ));
+    }
+  }
+
   let (ret, is_ret_result) = match output {
     syn::ReturnType::Default => (None, false),
     syn::ReturnType::Type(_, ty) => {
@@ -619,6 +725,25 @@ fn napi_fn_from_decl(
     }
   };
 
+  if let Some(&span) = opts.return_result_object() {
+    if !is_ret_result {
+      errors.push(Diagnostic::span_error(
+        span,
+        "#[napi(return_result_object)] requires the function to return a `Result`",
+      ));
+    } else if asyncness.is_some() {
+      errors.push(Diagnostic::span_error(
+        span,
+        "#[napi(return_result_object)] can only be applied to sync functions, async functions already return a Promise",
+      ));
+    } else if opts.constructor().is_some() || opts.factory().is_some() {
+      errors.push(Diagnostic::span_error(
+        span,
+        "#[napi(return_result_object)] can't be applied to a constructor or factory",
+      ));
+    }
+  }
+
   Diagnostic::from_vec(errors).map(|_| {
     let js_name = if let Some(prop_name) = opts.getter() {
       opts.js_name().map_or_else(
@@ -682,14 +807,21 @@ fn napi_fn_from_decl(
       kind: fn_kind(opts),
       fn_self,
       parent: parent.cloned(),
+      parent_js_name: None,
       comments: extract_doc_comments(&attrs),
       attrs,
       strict: opts.strict().is_some(),
       return_if_invalid: opts.return_if_invalid().is_some(),
+      return_result_object: opts.return_result_object().is_some(),
       js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
       ts_generic_types: opts.ts_generic_types().map(|(m, _)| m.to_owned()),
-      ts_args_type: opts.ts_args_type().map(|(m, _)| m.to_owned()),
+      ts_args_type: opts
+        .ts_args_types()
+        .into_iter()
+        .map(ToOwned::to_owned)
+        .collect(),
       ts_return_type: opts.ts_return_type().map(|(m, _)| m.to_owned()),
+      ts_decl: opts.ts_decl().map(|(m, _)| m.to_owned()),
       skip_typescript: opts.skip_typescript().is_some(),
       parent_is_generator,
       writable: opts.writable(),
@@ -742,7 +874,7 @@ impl ParseNapi for syn::ItemStruct {
     if opts.ts_args_type().is_some()
       || opts.ts_return_type().is_some()
       || opts.skip_typescript().is_some()
-      || opts.ts_type().is_some()
+      || (opts.ts_type().is_some() && opts.from_serde().is_none())
     {
       bail_span!(
         self,
@@ -755,6 +887,18 @@ impl ParseNapi for syn::ItemStruct {
         "#[napi(return_if_invalid)] can only be applied to a function or method."
       );
     }
+    if opts.ts_decl().is_some() {
+      bail_span!(
+        self,
+        "#[napi(ts_decl)] can only be applied to a function or method."
+      );
+    }
+    if opts.return_result_object().is_some() {
+      bail_span!(
+        self,
+        "#[napi(return_result_object)] can only be applied to a function or method."
+      );
+    }
     if opts.catch_unwind().is_some() {
       bail_span!(
         self,
@@ -764,6 +908,9 @@ impl ParseNapi for syn::ItemStruct {
     if opts.object().is_some() && opts.custom_finalize().is_some() {
       bail_span!(self, "Custom finalize is not supported for #[napi(object)]");
     }
+    if opts.object().is_some() && opts.cleanup().is_some() {
+      bail_span!(self, "Cleanup is not supported for #[napi(object)]");
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
@@ -790,6 +937,18 @@ impl ParseNapi for syn::ItemImpl {
         "#[napi(return_if_invalid)] can only be applied to a function or method."
       );
     }
+    if opts.ts_decl().is_some() {
+      bail_span!(
+        self,
+        "#[napi(ts_decl)] can only be applied to a function or method."
+      );
+    }
+    if opts.return_result_object().is_some() {
+      bail_span!(
+        self,
+        "#[napi(return_result_object)] can only be applied to a function or method."
+      );
+    }
     if opts.catch_unwind().is_some() {
       bail_span!(
         self,
@@ -808,7 +967,7 @@ impl ParseNapi for syn::ItemEnum {
   fn parse_napi(&mut self, tokens: &mut TokenStream, opts: BindgenAttrs) -> BindgenResult<Napi> {
     if opts.ts_args_type().is_some()
       || opts.ts_return_type().is_some()
-      || opts.ts_type().is_some()
+      || (opts.ts_type().is_some() && opts.from_serde().is_none())
       || opts.custom_finalize().is_some()
     {
       bail_span!(
@@ -822,6 +981,18 @@ impl ParseNapi for syn::ItemEnum {
         "#[napi(return_if_invalid)] can only be applied to a function or method."
       );
     }
+    if opts.ts_decl().is_some() {
+      bail_span!(
+        self,
+        "#[napi(ts_decl)] can only be applied to a function or method."
+      );
+    }
+    if opts.return_result_object().is_some() {
+      bail_span!(
+        self,
+        "#[napi(return_result_object)] can only be applied to a function or method."
+      );
+    }
     if opts.catch_unwind().is_some() {
       bail_span!(
         self,
@@ -852,6 +1023,18 @@ impl ParseNapi for syn::ItemConst {
         "#[napi(return_if_invalid)] can only be applied to a function or method."
       );
     }
+    if opts.ts_decl().is_some() {
+      bail_span!(
+        self,
+        "#[napi(ts_decl)] can only be applied to a function or method."
+      );
+    }
+    if opts.return_result_object().is_some() {
+      bail_span!(
+        self,
+        "#[napi(return_result_object)] can only be applied to a function or method."
+      );
+    }
     if opts.catch_unwind().is_some() {
       bail_span!(
         self,
@@ -904,6 +1087,16 @@ impl ConvertToAST for syn::ItemFn {
 
 impl ConvertToAST for syn::ItemStruct {
   fn convert_to_ast(&mut self, opts: BindgenAttrs) -> BindgenResult<Napi> {
+    if opts.from_serde().is_some() {
+      if opts.constructor().is_some() || opts.object().is_some() {
+        bail_span!(
+          self,
+          "#[napi(from_serde)] can't be used with #[napi(constructor)] or #[napi(object)]"
+        );
+      }
+      return Ok(convert_from_serde_to_ast(&self.ident, &self.attrs, &opts));
+    }
+
     let mut errors = vec![];
 
     let vis = self.vis.clone();
@@ -922,6 +1115,60 @@ impl ConvertToAST for syn::ItemStruct {
       NapiStructKind::None
     };
 
+    if let Some(span) = opts.debug() {
+      if struct_kind != NapiStructKind::Object {
+        errors.push(Diagnostic::span_error(
+          *span,
+          "`debug` is only supported on #[napi(object)] struct",
+        ));
+      }
+    }
+
+    let readonly_all = opts.readonly().is_some();
+    if let Some(span) = opts.readonly() {
+      if struct_kind != NapiStructKind::Object {
+        errors.push(Diagnostic::span_error(
+          *span,
+          "`readonly` is only supported on #[napi(object)] struct",
+        ));
+      }
+    }
+
+    let extends =
+      opts.extends().and_then(
+        |(parent, span)| match struct_extends(self, parent, &struct_kind) {
+          Ok(extends) => Some(extends),
+          Err(msg) => {
+            errors.push(Diagnostic::span_error(span, msg));
+            None
+          }
+        },
+      );
+
+    let field_case = match opts.js_name_all() {
+      Some((case, span)) => {
+        if struct_kind != NapiStructKind::Object {
+          errors.push(Diagnostic::span_error(
+            span,
+            "`js_name_all` is only supported on #[napi(object)] struct",
+          ));
+        }
+        match case {
+          "camelCase" => Case::Camel,
+          "PascalCase" => Case::Pascal,
+          "kebab-case" => Case::Kebab,
+          _ => {
+            errors.push(Diagnostic::span_error(
+              span,
+              "`js_name_all` must be one of \"camelCase\", \"PascalCase\" or \"kebab-case\"",
+            ));
+            Case::Camel
+          }
+        }
+      }
+      None => Case::Camel,
+    };
+
     for (i, field) in self.fields.iter_mut().enumerate() {
       match field.vis {
         syn::Visibility::Public(..) => {}
@@ -941,7 +1188,7 @@ impl ConvertToAST for syn::ItemStruct {
       let (js_name, name) = match &field.ident {
         Some(ident) => (
           field_opts.js_name().map_or_else(
-            || ident.unraw().to_string().to_case(Case::Camel),
+            || ident.unraw().to_string().to_case(field_case),
             |(js_name, _)| js_name.to_owned(),
           ),
           syn::Member::Named(ident.clone()),
@@ -953,12 +1200,14 @@ impl ConvertToAST for syn::ItemStruct {
       };
 
       let ignored = field_opts.skip().is_some();
-      let readonly = field_opts.readonly().is_some();
+      let readonly = readonly_all || field_opts.readonly().is_some();
       let writable = field_opts.writable();
       let enumerable = field_opts.enumerable();
       let configurable = field_opts.configurable();
       let skip_typescript = field_opts.skip_typescript().is_some();
-      let ts_type = field_opts.ts_type().map(|e| e.0.to_string());
+      let base64 = field_base64(field, &field_opts, struct_kind == NapiStructKind::Object)?;
+      let ts_type =
+        field_ts_type(field, &field_opts)?.or_else(|| base64.then(|| "string".to_owned()));
 
       fields.push(NapiStructField {
         name,
@@ -972,6 +1221,7 @@ impl ConvertToAST for syn::ItemStruct {
         comments: extract_doc_comments(&field.attrs),
         skip_typescript,
         ts_type,
+        base64,
       })
     }
 
@@ -1001,11 +1251,67 @@ impl ConvertToAST for syn::ItemStruct {
         comments: extract_doc_comments(&self.attrs),
         implement_iterator,
         use_custom_finalize: opts.custom_finalize().is_some(),
+        use_cleanup: opts.cleanup().is_some(),
+        implement_debug: opts.debug().is_some(),
+        extends,
       }),
     })
   }
 }
 
+/// The superclass of `#[napi(extends)]` must be the first field of a `#[repr(C)]` struct,
+/// so the subclass can be used wherever the superclass is unwrapped from `this`
+fn struct_extends(
+  item: &syn::ItemStruct,
+  parent: &str,
+  struct_kind: &NapiStructKind,
+) -> Result<NapiStructExtends, String> {
+  if struct_kind == &NapiStructKind::Object {
+    return Err("`extends` is not supported on #[napi(object)] struct".to_owned());
+  }
+  let is_repr_c = item.attrs.iter().any(|attr| match attr.parse_meta() {
+    Ok(Meta::List(list)) => {
+      list.path.is_ident("repr")
+        && list
+          .nested
+          .iter()
+          .any(|meta| matches!(meta, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C")))
+    }
+    _ => false,
+  });
+  let first_field = item.fields.iter().next().filter(|field| match &field.ty {
+    Type::Path(syn::TypePath { path, qself: None }) => path
+      .segments
+      .last()
+      .map_or(false, |segment| segment.ident == parent),
+    _ => false,
+  });
+  let field = match first_field {
+    Some(field) if is_repr_c => field
+      .ident
+      .clone()
+      .map_or_else(|| syn::Member::Unnamed(0.into()), syn::Member::Named),
+    _ => {
+      return Err(format!(
+        "#[napi(extends = \"{0}\")] requires a #[repr(C)] struct with `{0}` as its first field",
+        parent
+      ))
+    }
+  };
+  let js_name = recorded_struct_js_name(parent).ok_or_else(|| {
+    format!(
+      "Did not find struct `{}` parsed before expand #[napi(extends)] for struct `{}`",
+      parent, item.ident
+    )
+  })?;
+
+  Ok(NapiStructExtends {
+    name: Ident::new(parent, Span::call_site()),
+    js_name,
+    field,
+  })
+}
+
 impl ConvertToAST for syn::ItemImpl {
   fn convert_to_ast(&mut self, impl_opts: BindgenAttrs) -> BindgenResult<Napi> {
     let struct_name = match get_ty(&self.self_ty) {
@@ -1087,6 +1393,10 @@ impl ConvertToAST for syn::ItemImpl {
       }
     }
 
+    for item in items.iter_mut() {
+      item.parent_js_name = Some(struct_js_name.clone());
+    }
+
     let namespace = impl_opts.namespace().map(|(m, _)| m.to_owned());
 
     Ok(Napi {
@@ -1112,6 +1422,20 @@ impl ConvertToAST for syn::ItemEnum {
       _ => bail_span!(self, "only public enum allowed"),
     }
 
+    if opts.from_serde().is_some() {
+      if opts.discriminant().is_some() || opts.skip_typescript().is_some() {
+        bail_span!(
+          self,
+          "#[napi(from_serde)] can't be used with #[napi(discriminant)] or #[napi(skip_typescript)]"
+        );
+      }
+      return Ok(convert_from_serde_to_ast(&self.ident, &self.attrs, &opts));
+    }
+
+    if let Some((discriminant, _)) = opts.discriminant() {
+      return convert_structured_enum_to_ast(self, discriminant.to_owned(), &opts);
+    }
+
     self.attrs.push(Attribute {
       pound_token: Default::default(),
       style: syn::AttrStyle::Outer,
@@ -1131,7 +1455,10 @@ impl ConvertToAST for syn::ItemEnum {
       .map(|v| {
         match v.fields {
           syn::Fields::Unit => {}
-          _ => bail_span!(v.fields, "Structured enum is not supported in #[napi]"),
+          _ => bail_span!(
+            v.fields,
+            "Structured enum is not supported in #[napi], try #[napi(discriminant = \"type\")]"
+          ),
         };
 
         let val = match &v.discriminant {
@@ -1195,6 +1522,97 @@ impl ConvertToAST for syn::ItemEnum {
   }
 }
 
+fn convert_structured_enum_to_ast(
+  item: &mut syn::ItemEnum,
+  discriminant: String,
+  opts: &BindgenAttrs,
+) -> BindgenResult<Napi> {
+  let js_name = opts
+    .js_name()
+    .map_or_else(|| item.ident.to_string(), |(s, _)| s.to_string());
+
+  let mut variants = vec![];
+  for v in item.variants.iter_mut() {
+    if v.discriminant.is_some() {
+      bail_span!(
+        v,
+        "explicit discriminant is not supported in enum with #[napi(discriminant)]"
+      );
+    }
+    let mut fields = vec![];
+    let mut is_tuple = false;
+    for (i, field) in v.fields.iter_mut().enumerate() {
+      let field_opts = BindgenAttrs::find(&mut field.attrs)?;
+      let (js_name, name) = match &field.ident {
+        Some(ident) => (
+          field_opts.js_name().map_or_else(
+            || ident.unraw().to_string().to_case(Case::Camel),
+            |(js_name, _)| js_name.to_owned(),
+          ),
+          syn::Member::Named(ident.clone()),
+        ),
+        None => {
+          is_tuple = true;
+          (format!("field{}", i), syn::Member::Unnamed(i.into()))
+        }
+      };
+      if js_name == discriminant {
+        bail_span!(
+          field,
+          "field name `{}` conflicts with the discriminant of enum",
+          js_name
+        );
+      }
+      fields.push(NapiStructField {
+        name,
+        js_name,
+        ty: field.ty.clone(),
+        getter: true,
+        setter: true,
+        writable: true,
+        enumerable: true,
+        configurable: true,
+        comments: extract_doc_comments(&field.attrs),
+        skip_typescript: field_opts.skip_typescript().is_some(),
+        ts_type: field_ts_type(field, &field_opts)?,
+        base64: field_base64(field, &field_opts, false)?,
+      });
+    }
+    variants.push(NapiStructuredEnumVariant {
+      name: v.ident.clone(),
+      fields,
+      is_tuple,
+      comments: extract_doc_comments(&v.attrs),
+    });
+  }
+
+  Ok(Napi {
+    item: NapiItem::StructuredEnum(NapiStructuredEnum {
+      name: item.ident.clone(),
+      js_name,
+      discriminant,
+      variants,
+      js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+      comments: extract_doc_comments(&item.attrs),
+      skip_typescript: opts.skip_typescript().is_some(),
+    }),
+  })
+}
+
+fn convert_from_serde_to_ast(ident: &Ident, attrs: &[Attribute], opts: &BindgenAttrs) -> Napi {
+  Napi {
+    item: NapiItem::FromSerde(NapiFromSerde {
+      name: ident.clone(),
+      js_name: opts
+        .js_name()
+        .map_or_else(|| ident.to_string(), |(s, _)| s.to_string()),
+      ts_type: opts.ts_type().map(|(ts_type, _)| ts_type.to_owned()),
+      js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+      comments: extract_doc_comments(attrs),
+    }),
+  }
+}
+
 impl ConvertToAST for syn::ItemConst {
   fn convert_to_ast(&mut self, opts: BindgenAttrs) -> BindgenResult<Napi> {
     match self.vis {
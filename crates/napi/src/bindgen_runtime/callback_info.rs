@@ -7,7 +7,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use once_cell::sync::Lazy;
 use thread_local::ThreadLocal;
 
-use crate::{bindgen_prelude::*, check_status, sys, Result};
+use crate::{bindgen_prelude::*, check_status, sys, type_of, Result};
 
 #[doc(hidden)]
 /// Determined is `constructor` called from Class `factory`
@@ -67,6 +67,27 @@ impl<const N: usize> CallbackInfo<N> {
     self.this
   }
 
+  /// `this` of a function expected to be called as a method, e.g. `fn.call(obj)`.
+  ///
+  /// Functions called without an object receiver get the global object as `this`, which is rejected.
+  pub fn explicit_this(&self, js_name: &str) -> Result<sys::napi_value> {
+    let mut global = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_global(self.env, &mut global) })?;
+    let mut is_global = false;
+    check_status!(unsafe { sys::napi_strict_equals(self.env, self.this, global, &mut is_global) })?;
+    let this_type = type_of!(self.env, self.this)?;
+    if is_global || (this_type != ValueType::Object && this_type != ValueType::Function) {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "`{}` must be called with an object as `this`, e.g. `{}.call(obj)`",
+          js_name, js_name
+        ),
+      ));
+    }
+    Ok(self.this)
+  }
+
   fn _construct<T: ObjectFinalize + 'static>(
     &self,
     js_name: &str,
@@ -119,9 +140,10 @@ impl<const N: usize> CallbackInfo<N> {
     Ok(instance)
   }
 
+  /// `js_name` is the nul terminated name the class of `T` is registered with
   pub fn factory<T: ObjectFinalize + 'static>(
     &self,
-    js_name: &str,
+    js_name: &'static str,
     obj: T,
   ) -> Result<sys::napi_value> {
     self._factory(js_name, obj).map(|(value, _)| value)
@@ -129,7 +151,7 @@ impl<const N: usize> CallbackInfo<N> {
 
   pub fn generator_factory<T: ObjectFinalize + Generator + 'static>(
     &self,
-    js_name: &str,
+    js_name: &'static str,
     obj: T,
   ) -> Result<sys::napi_value> {
     let (instance, generator_ptr) = self._factory(js_name, obj)?;
@@ -139,15 +161,28 @@ impl<const N: usize> CallbackInfo<N> {
 
   fn _factory<T: ObjectFinalize + 'static>(
     &self,
-    js_name: &str,
+    js_name: &'static str,
     obj: T,
   ) -> Result<(sys::napi_value, *mut T)> {
-    let this = self.this();
+    let class_name = js_name.trim_end_matches('\0');
+    // `this` is a subclass if the factory is inherited, which must not wrap a `T`
+    let ctor_ref = get_class_constructor(js_name).ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Failed to get constructor of class `{}`", class_name),
+      )
+    })?;
+    let mut ctor = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(self.env, ctor_ref, &mut ctor) },
+      "Failed to get constructor reference of class `{}`",
+      class_name,
+    )?;
     let mut instance = ptr::null_mut();
     let inner = ___CALL_FROM_FACTORY.get_or_default();
     inner.store(true, Ordering::Relaxed);
     let status =
-      unsafe { sys::napi_new_instance(self.env, this, 0, ptr::null_mut(), &mut instance) };
+      unsafe { sys::napi_new_instance(self.env, ctor, 0, ptr::null_mut(), &mut instance) };
     inner.store(false, Ordering::Relaxed);
     // Error thrown in `constructor`
     if status == sys::Status::napi_pending_exception {
@@ -173,7 +208,7 @@ impl<const N: usize> CallbackInfo<N> {
         )
       },
       "Failed to initialize class `{}`",
-      js_name,
+      class_name,
     )?;
 
     Reference::<T>::add_ref(
@@ -217,4 +252,32 @@ impl<const N: usize> CallbackInfo<N> {
       Ok(wrapped_val as *mut T)
     }
   }
+
+  /// Take the native value out of `this` for `#[napi]` methods consuming `self`.
+  ///
+  /// The wrap is removed from the JavaScript object, calling methods on it afterwards throws.
+  #[doc(hidden)]
+  pub unsafe fn remove_wrap<T>(&mut self) -> Result<T>
+  where
+    T: TypeName,
+  {
+    let wrapped_val = unsafe { self.unwrap_raw::<T>()? } as *mut c_void;
+    if super::js_values::is_referenced(wrapped_val) {
+      return Err(Error::new(
+        Status::GenericFailure,
+        format!(
+          "Can't consume `{}` while a `Reference` to it is alive",
+          T::type_name()
+        ),
+      ));
+    }
+    let mut removed_val: *mut c_void = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_remove_wrap(self.env, self.this, &mut removed_val) },
+      "Failed to remove wrap of `{}` type from napi value",
+      T::type_name(),
+    )?;
+    unsafe { super::js_values::remove_ref(self.env, removed_val) };
+    Ok(*unsafe { Box::from_raw(removed_val as *mut T) })
+  }
 }
@@ -12,6 +12,12 @@ impl Env {
   }
 
   /// Get [JsUndefined](./struct.JsUndefined.html) value
+  ///
+  /// Node-API always hands out the same `undefined` singleton, so nothing is allocated and the
+  /// result is `===` to the JavaScript `undefined`. Returning `()` from a `#[napi]` function
+  /// produces the same value.
+  ///
+  /// The handle belongs to the current handle scope, don't store it to reuse it on later calls.
   pub fn get_undefined(&self) -> Result<JsUndefined> {
     let mut raw_value = ptr::null_mut();
     check_status!(unsafe { sys::napi_get_undefined(self.0, &mut raw_value) })?;
@@ -19,6 +25,10 @@ impl Env {
     Ok(js_undefined)
   }
 
+  /// Get [JsNull](./struct.JsNull.html) value
+  ///
+  /// Like [`Env::get_undefined`], this returns the `null` singleton, the same value
+  /// [`Null`](./bindgen_prelude/struct.Null.html) is converted into.
   pub fn get_null(&self) -> Result<JsNull> {
     let mut raw_value = ptr::null_mut();
     check_status!(unsafe { sys::napi_get_null(self.0, &mut raw_value) })?;
@@ -4,49 +4,76 @@ use crate::{check_status, sys, Error, JsUnknown, NapiRaw, NapiValue, Result, Sta
 
 mod array;
 mod arraybuffer;
+mod base64;
 #[cfg(feature = "napi6")]
 mod bigint;
 mod boolean;
 mod buffer;
+mod cell;
 mod class;
+mod control_flow;
 #[cfg(all(feature = "chrono_date", feature = "napi5"))]
 mod date;
 mod either;
+mod elapsed;
 mod external;
 mod function;
+mod global;
+mod iterable;
 mod map;
 mod nil;
 mod number;
 mod object;
+mod ordering;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 mod promise;
+mod result;
 #[cfg(feature = "serde-json")]
 mod serde;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+mod state_methods;
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+mod stream;
 mod string;
 mod symbol;
+mod sync;
 mod task;
+#[cfg(feature = "url")]
+mod url;
 mod value_ref;
+mod weak;
 
 pub use crate::js_values::JsUnknown as Unknown;
 #[cfg(feature = "napi5")]
 pub use crate::JsDate as Date;
 pub use array::*;
 pub use arraybuffer::*;
+pub use base64::*;
 #[cfg(feature = "napi6")]
 pub use bigint::*;
 pub use buffer::*;
 pub use class::*;
 pub use either::*;
+pub use elapsed::*;
 pub use external::*;
 pub use function::*;
+pub use global::*;
+pub use iterable::*;
+pub use map::*;
 pub use nil::*;
+pub use number::*;
 pub use object::*;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 pub use promise::*;
+pub use result::*;
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+pub use stream::*;
 pub use string::*;
 pub use symbol::*;
 pub use task::*;
 pub use value_ref::*;
+pub use weak::*;
 
 #[cfg(feature = "latin1")]
 pub use string::latin1_string::*;
@@ -97,6 +124,17 @@ pub trait FromNapiValue: Sized {
   fn from_unknown(value: JsUnknown) -> Result<Self> {
     unsafe { Self::from_napi_value(value.0.env, value.0.value) }
   }
+
+  #[doc(hidden)]
+  /// Fast path of `FromNapiValue for Vec<Self>`, copying a `TypedArray` of `Self` in one go.
+  ///
+  /// Returns `Ok(None)` to fall back to converting element by element.
+  unsafe fn vec_from_typed_array(
+    _env: sys::napi_env,
+    _napi_val: sys::napi_value,
+  ) -> Result<Option<Vec<Self>>> {
+    Ok(None)
+  }
 }
 
 pub trait FromNapiRef {
@@ -186,6 +224,8 @@ impl<T: ValidateNapiValue> ValidateNapiValue for Option<T> {
   }
 }
 
+/// Only `null` and `undefined`, which is also what a missing argument or property reads as,
+/// become `None`. Any other value is converted as `Some`, so `[]` is `Some(Vec::new())` for `Option<Vec<T>>`
 impl<T> FromNapiValue for Option<T>
 where
   T: FromNapiValue,
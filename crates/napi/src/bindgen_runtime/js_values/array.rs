@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::ptr;
 
 use crate::{bindgen_prelude::*, check_status, sys, JsObject, Value, ValueType};
@@ -222,6 +224,40 @@ where
   }
 }
 
+impl<T> TypeName for Cow<'_, [T]>
+where
+  T: Clone,
+{
+  fn type_name() -> &'static str {
+    "Array<T>"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<T> ToNapiValue for Cow<'_, [T]>
+where
+  T: ToNapiValue + Clone,
+{
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    match val {
+      // elements are cloned one by one, the slice is never copied into a `Vec`
+      Cow::Borrowed(slice) => {
+        let mut arr = Array::new(env, slice.len() as u32)?;
+
+        for (i, v) in slice.iter().enumerate() {
+          arr.set(i as u32, v.clone())?;
+        }
+
+        unsafe { Array::to_napi_value(env, arr) }
+      }
+      Cow::Owned(vec) => unsafe { Vec::to_napi_value(env, vec) },
+    }
+  }
+}
+
 macro_rules! impl_for_primitive_type {
   ($primitive_type:ident) => {
     impl ToNapiValue for &Vec<$primitive_type> {
@@ -260,13 +296,63 @@ impl ToNapiValue for &Vec<String> {
   }
 }
 
+// Fast path for `Vec<f64>` from `Float64Array`, copied in one go instead of element by element.
+// Returns `Ok(None)` if the value isn't a `Float64Array`.
+pub(super) unsafe fn vec_from_float64_array(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+) -> Result<Option<Vec<f64>>> {
+  let mut is_typedarray = false;
+  check_status!(
+    unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typedarray) },
+    "Failed to check given napi value is TypedArray"
+  )?;
+  if !is_typedarray {
+    return Ok(None);
+  }
+  let mut typedarray_type = 0;
+  let mut len = 0;
+  let mut data = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_typedarray_info(
+        env,
+        napi_val,
+        &mut typedarray_type,
+        &mut len,
+        &mut data,
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    },
+    "Failed to get TypedArray info"
+  )?;
+  if typedarray_type != sys::TypedarrayType::float64_array {
+    return Ok(None);
+  }
+  let mut vec = Vec::<f64>::with_capacity(len);
+  if len > 0 {
+    unsafe {
+      ptr::copy_nonoverlapping(data as *const f64, vec.as_mut_ptr(), len);
+      vec.set_len(len);
+    }
+  }
+  Ok(Some(vec))
+}
+
+/// Nested arrays like `Vec<Vec<T>>` are converted recursively, so inner arrays can have different lengths.
+///
+/// Inner `Vec<f64>` can also be given as `Float64Array` for faster conversion.
 impl<T> FromNapiValue for Vec<T>
 where
   T: FromNapiValue,
 {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    if let Some(vec) = unsafe { T::vec_from_typed_array(env, napi_val)? } {
+      return Ok(vec);
+    }
     let arr = unsafe { Array::from_napi_value(env, napi_val)? };
-    let mut vec = vec![];
+    let mut vec = Vec::with_capacity(arr.len() as usize);
 
     for i in 0..arr.len() {
       if let Some(val) = arr.get::<T>(i)? {
@@ -303,6 +389,49 @@ where
   }
 }
 
+impl<T> TypeName for VecDeque<T> {
+  fn type_name() -> &'static str {
+    "Array<T>"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<T> ToNapiValue for VecDeque<T>
+where
+  T: ToNapiValue,
+{
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut arr = Array::new(env, val.len() as u32)?;
+
+    for (i, v) in val.into_iter().enumerate() {
+      arr.set(i as u32, v)?;
+    }
+
+    unsafe { Array::to_napi_value(env, arr) }
+  }
+}
+
+impl<T> FromNapiValue for VecDeque<T>
+where
+  T: FromNapiValue,
+{
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { Vec::<T>::from_napi_value(env, napi_val) }.map(VecDeque::from)
+  }
+}
+
+impl<T> ValidateNapiValue for VecDeque<T>
+where
+  T: FromNapiValue,
+{
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { Vec::<T>::validate(env, napi_val) }
+  }
+}
+
 macro_rules! arr_get {
   ($arr:expr, $n:expr) => {
     if let Some(e) = $arr.get($n)? {
@@ -599,3 +728,52 @@ where
 {
   tuple_from_napi_value!(16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
 }
+
+macro_rules! impl_tuple_to_napi_value {
+  ($total:expr, $($t:ident: $n:tt),+) => {
+    impl<$($t),+> ToNapiValue for ($($t,)+)
+    where
+      $($t: ToNapiValue),+
+    {
+      unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        let mut arr = Array::new(env, $total)?;
+        $(arr.set($n, val.$n)?;)+
+        unsafe { Array::to_napi_value(env, arr) }
+      }
+    }
+  };
+}
+
+impl_tuple_to_napi_value!(2, T0: 0, T1: 1);
+impl_tuple_to_napi_value!(3, T0: 0, T1: 1, T2: 2);
+impl_tuple_to_napi_value!(4, T0: 0, T1: 1, T2: 2, T3: 3);
+impl_tuple_to_napi_value!(5, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_tuple_to_napi_value!(6, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_tuple_to_napi_value!(7, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_tuple_to_napi_value!(8, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+impl_tuple_to_napi_value!(9, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8);
+impl_tuple_to_napi_value!(
+  10, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9
+);
+impl_tuple_to_napi_value!(
+  11, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10
+);
+impl_tuple_to_napi_value!(
+  12, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10, T11: 11
+);
+impl_tuple_to_napi_value!(
+  13, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10, T11: 11,
+  T12: 12
+);
+impl_tuple_to_napi_value!(
+  14, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10, T11: 11,
+  T12: 12, T13: 13
+);
+impl_tuple_to_napi_value!(
+  15, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10, T11: 11,
+  T12: 12, T13: 13, T14: 14
+);
+impl_tuple_to_napi_value!(
+  16, T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10, T11: 11,
+  T12: 12, T13: 13, T14: 14, T15: 15
+);
@@ -0,0 +1,101 @@
+use crate::{bindgen_prelude::*, sys, Error, Result, Status, ValueType};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+/// Bytes converted from and to a standard, padded base64 `string`
+///
+/// Used by `#[napi(base64)]` on `Vec<u8>` fields of a `#[napi(object)]`, decoding fails with
+/// `InvalidArg` if the string is not valid base64.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Base64(pub Vec<u8>);
+
+impl Base64 {
+  pub fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+      let n = (chunk[0] as u32) << 16
+        | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+        | *chunk.get(2).unwrap_or(&0) as u32;
+      for i in 0..4 {
+        if i <= chunk.len() {
+          encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        } else {
+          encoded.push(PAD as char);
+        }
+      }
+    }
+    encoded
+  }
+
+  pub fn decode(input: &str) -> Result<Vec<u8>> {
+    let invalid = |reason: &str| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Invalid base64 string `{}`, {}", input, reason),
+      )
+    };
+    let input = input.as_bytes();
+    if input.len() % 4 != 0 {
+      return Err(invalid("its length must be a multiple of 4"));
+    }
+    let mut decoded = Vec::with_capacity(input.len() / 4 * 3);
+    let chunk_count = input.len() / 4;
+    for (index, chunk) in input.chunks(4).enumerate() {
+      let padding = chunk.iter().rev().take_while(|&&c| c == PAD).count();
+      if padding > 2 || (padding > 0 && index + 1 != chunk_count) {
+        return Err(invalid("it has invalid padding"));
+      }
+      let mut n = 0u32;
+      for &c in &chunk[..4 - padding] {
+        let sextet = match c {
+          b'A'..=b'Z' => c - b'A',
+          b'a'..=b'z' => c - b'a' + 26,
+          b'0'..=b'9' => c - b'0' + 52,
+          b'+' => 62,
+          b'/' => 63,
+          _ => {
+            return Err(invalid(
+              "it contains a character out of the base64 alphabet",
+            ))
+          }
+        };
+        n = n << 6 | sextet as u32;
+      }
+      n <<= 6 * padding as u32;
+      decoded.extend_from_slice(&n.to_be_bytes()[1..4 - padding]);
+    }
+    Ok(decoded)
+  }
+}
+
+impl TypeName for Base64 {
+  fn type_name() -> &'static str {
+    "Base64"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::String
+  }
+}
+
+impl ValidateNapiValue for Base64 {}
+
+impl ToNapiValue for Base64 {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { String::to_napi_value(env, Base64::encode(&val.0)) }
+  }
+}
+
+impl FromNapiValue for Base64 {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let encoded = unsafe { String::from_napi_value(env, napi_val)? };
+    Base64::decode(&encoded).map(Base64)
+  }
+}
+
+impl From<Vec<u8>> for Base64 {
+  fn from(bytes: Vec<u8>) -> Self {
+    Base64(bytes)
+  }
+}
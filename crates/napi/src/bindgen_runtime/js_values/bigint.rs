@@ -10,7 +10,7 @@
 /// ```
 use std::ptr;
 
-use crate::{check_status, sys};
+use crate::{check_status, sys, type_of, Error, Status, ValueType};
 
 use super::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
 
@@ -19,6 +19,141 @@ use super::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
 #[allow(non_camel_case_types)]
 pub struct i64n(pub i64);
 
+/// `Number.MAX_SAFE_INTEGER`
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// `i64` or `u64` converted to a `Number` if it is within the safe integer range of JavaScript,
+/// `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER`, and to a `BigInt` otherwise,
+/// so no precision is lost in either direction
+///
+/// Both a `Number` and a `BigInt` are accepted from JavaScript, an error is returned if the value
+/// can not be represented by the inner type without loss.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SmartInt<T>(pub T);
+
+impl<T> TypeName for SmartInt<T> {
+  fn type_name() -> &'static str {
+    "SmartInt"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Unknown
+  }
+}
+
+impl<T> ValidateNapiValue for SmartInt<T>
+where
+  SmartInt<T>: FromNapiValue,
+{
+  unsafe fn validate(
+    env: sys::napi_env,
+    napi_val: sys::napi_value,
+  ) -> crate::Result<sys::napi_value> {
+    match type_of!(env, napi_val)? {
+      ValueType::Number | ValueType::BigInt => Ok(ptr::null_mut()),
+      received => Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Expect value to be Number or BigInt, but received {}",
+          received
+        ),
+      )),
+    }
+  }
+}
+
+macro_rules! impl_smart_int {
+  ($ty:ty, $create_bigint:ident, $in_safe_range:expr, $from_bigint:expr) => {
+    impl ToNapiValue for SmartInt<$ty> {
+      unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
+        let mut raw_value = ptr::null_mut();
+        if $in_safe_range(val.0) {
+          check_status!(unsafe { sys::napi_create_int64(env, val.0 as i64, &mut raw_value) })?;
+        } else {
+          check_status!(unsafe { sys::$create_bigint(env, val.0, &mut raw_value) })?;
+        }
+        Ok(raw_value)
+      }
+    }
+
+    impl FromNapiValue for SmartInt<$ty> {
+      unsafe fn from_napi_value(
+        env: sys::napi_env,
+        napi_val: sys::napi_value,
+      ) -> crate::Result<Self> {
+        match type_of!(env, napi_val)? {
+          ValueType::Number => {
+            let mut num = 0f64;
+            check_status!(unsafe { sys::napi_get_value_double(env, napi_val, &mut num) })?;
+            if num.fract() == 0.0 && $in_safe_range(num as $ty) && num as $ty as f64 == num {
+              Ok(SmartInt(num as $ty))
+            } else {
+              Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                  "Failed to convert Number {} into `{}`, it is not a safe integer in range",
+                  num,
+                  stringify!($ty)
+                ),
+              ))
+            }
+          }
+          ValueType::BigInt => {
+            let bigint = unsafe { BigInt::from_napi_value(env, napi_val)? };
+            match $from_bigint(&bigint) {
+              Some(val) => Ok(SmartInt(val)),
+              None => Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                  "Failed to convert BigInt into `{}` without loss of precision",
+                  stringify!($ty)
+                ),
+              )),
+            }
+          }
+          received => Err(Error::new(
+            Status::InvalidArg,
+            format!(
+              "Expect value to be Number or BigInt, but received {}",
+              received
+            ),
+          )),
+        }
+      }
+    }
+
+    impl From<$ty> for SmartInt<$ty> {
+      fn from(val: $ty) -> Self {
+        SmartInt(val)
+      }
+    }
+  };
+}
+
+impl_smart_int!(
+  i64,
+  napi_create_bigint_int64,
+  |val: i64| (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&val),
+  |bigint: &BigInt| match (bigint.sign_bit, bigint.words.as_slice()) {
+    (false, &[magnitude]) if magnitude <= i64::MAX as u64 => Some(magnitude as i64),
+    (true, &[magnitude]) if magnitude <= i64::MIN.unsigned_abs() => {
+      Some((magnitude as i64).wrapping_neg())
+    }
+    _ => None,
+  }
+);
+
+impl_smart_int!(
+  u64,
+  napi_create_bigint_uint64,
+  |val: u64| val <= MAX_SAFE_INTEGER as u64,
+  |bigint: &BigInt| match bigint.get_u64() {
+    (_, val, true) => Some(val),
+    _ => None,
+  }
+);
+
 /// <https://nodejs.org/api/n-api.html#napi_create_bigint_words>
 /// The resulting BigInt is calculated as: (–1)^sign_bit (words\[0\] × (2^64)^0 + words\[1\] × (2^64)^1 + …)
 #[derive(Debug, Clone)]
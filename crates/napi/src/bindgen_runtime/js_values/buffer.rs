@@ -122,6 +122,31 @@ impl From<Vec<u8>> for Buffer {
   }
 }
 
+impl Buffer {
+  /// Copy `data` into a new JavaScript `Buffer` allocated by `napi_create_buffer_copy`,
+  /// the returned `Buffer` references it just like a `Buffer` argument.
+  ///
+  /// The bytes are copied once, changes to `data` after the call are not visible in the `Buffer`,
+  /// and the other way around. When you already own a `Vec<u8>`, `Buffer::from` moves it into an
+  /// external JavaScript `Buffer` instead of copying it.
+  pub fn copy_from(env: &Env, data: &[u8]) -> Result<Self> {
+    let mut buf = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_buffer_copy(
+          env.0,
+          data.len(),
+          data.as_ptr() as *const c_void,
+          ptr::null_mut(),
+          &mut buf,
+        )
+      },
+      "Failed to create napi buffer copy"
+    )?;
+    unsafe { Buffer::from_napi_value(env.0, buf) }
+  }
+}
+
 impl From<Buffer> for Vec<u8> {
   fn from(buf: Buffer) -> Self {
     buf.as_ref().to_vec()
@@ -294,3 +319,58 @@ impl ValidateNapiValue for Buffer {
     Ok(ptr::null_mut())
   }
 }
+
+impl TypeName for Arc<[u8]> {
+  fn type_name() -> &'static str {
+    "Buffer"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for Arc<[u8]> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { Buffer::validate(env, napi_val) }
+  }
+}
+
+/// The content of the JavaScript `Buffer` is copied straight into the shared allocation
+impl FromNapiValue for Arc<[u8]> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let mut buf = ptr::null_mut();
+    let mut len = 0;
+    check_status!(
+      unsafe { sys::napi_get_buffer_info(env, napi_val, &mut buf, &mut len as *mut usize) },
+      "Failed to get Buffer pointer and length"
+    )?;
+    if buf.is_null() || len == 0 {
+      return Ok(Arc::from(&[][..]));
+    }
+    Ok(Arc::from(unsafe {
+      slice::from_raw_parts(buf as *const u8, len)
+    }))
+  }
+}
+
+/// The bytes are copied into a new JavaScript `Buffer`, sharing the memory as an external `Buffer`
+/// would let JavaScript write into the immutable `Arc<[u8]>`
+impl ToNapiValue for Arc<[u8]> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut ret = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_buffer_copy(
+          env,
+          val.len(),
+          val.as_ptr() as *const c_void,
+          ptr::null_mut(),
+          &mut ret,
+        )
+      },
+      "Failed to create napi buffer copy"
+    )?;
+    Ok(ret)
+  }
+}
@@ -0,0 +1,49 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{bindgen_prelude::*, sys, Error, Result, Status, ValueType};
+
+impl<T: TypeName> TypeName for RefCell<T> {
+  fn type_name() -> &'static str {
+    T::type_name()
+  }
+
+  fn value_type() -> ValueType {
+    T::value_type()
+  }
+}
+
+impl<T: ToNapiValue> ToNapiValue for RefCell<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { T::to_napi_value(env, val.into_inner()) }
+  }
+}
+
+/// Converts a snapshot of the borrowed value, taken by cloning it at the time of the conversion.
+/// Later changes made through the `RefCell` are not reflected on the JavaScript side.
+///
+/// Returns an error instead of panicking if the value is currently mutably borrowed.
+impl<T: ToNapiValue + Clone> ToNapiValue for &RefCell<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let snapshot = val
+      .try_borrow()
+      .map_err(|_| {
+        Error::new(
+          Status::GenericFailure,
+          format!(
+            "Failed to convert `RefCell<{}>` into napi value, it is already mutably borrowed",
+            std::any::type_name::<T>(),
+          ),
+        )
+      })?
+      .clone();
+    unsafe { T::to_napi_value(env, snapshot) }
+  }
+}
+
+/// See the `&RefCell<T>` implementation, the shared value is cloned, not bound
+impl<T: ToNapiValue + Clone> ToNapiValue for Rc<RefCell<T>> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { <&RefCell<T>>::to_napi_value(env, &val) }
+  }
+}
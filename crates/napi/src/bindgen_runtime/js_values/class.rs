@@ -57,8 +57,14 @@ where
   }
 }
 
-impl<T: 'static> FromNapiValue for ClassInstance<T> {
+/// The value must be an instance of the `#[napi]` class `T`, otherwise an `InvalidArg` error is returned
+impl<T: 'static> FromNapiValue for ClassInstance<T>
+where
+  &'static T: ValidateNapiValue,
+{
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
+    // `napi_unwrap` succeeds on instances of any other class, the pointer would be of the wrong type
+    unsafe { <&T>::validate(env, napi_val) }?;
     let mut value = ptr::null_mut();
     check_status!(
       unsafe { sys::napi_unwrap(env, napi_val, &mut value) },
@@ -0,0 +1,71 @@
+use std::ops::ControlFlow;
+
+use crate::{bindgen_prelude::*, check_status, sys, Error, Result, Status, ValueType};
+
+impl<B, C> TypeName for ControlFlow<B, C> {
+  fn type_name() -> &'static str {
+    "ControlFlow"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<B: FromNapiValue, C: FromNapiValue> ValidateNapiValue for ControlFlow<B, C> {}
+
+/// `Break(b)` is converted into `{ done: true, value: b }` and `Continue(c)` into
+/// `{ done: false, value: c }`, the same shape as an iterator result
+impl<B: ToNapiValue, C: ToNapiValue> ToNapiValue for ControlFlow<B, C> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut obj = Env::from(env).create_object()?;
+    match val {
+      ControlFlow::Break(b) => {
+        obj.set("done", true)?;
+        obj.set("value", b)?;
+      }
+      ControlFlow::Continue(c) => {
+        obj.set("done", false)?;
+        obj.set("value", c)?;
+      }
+    }
+
+    unsafe { Object::to_napi_value(env, obj) }
+  }
+}
+
+impl<B: FromNapiValue, C: FromNapiValue> FromNapiValue for ControlFlow<B, C> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let obj = unsafe { Object::from_napi_value(env, napi_val)? };
+    let done = obj.get::<_, bool>("done")?.ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        "Missing `done` field in ControlFlow object".to_owned(),
+      )
+    })?;
+    let mut value = std::ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_named_property(env, napi_val, "value\0".as_ptr().cast(), &mut value) },
+      "Failed to get `value` field of ControlFlow object"
+    )?;
+    let convert_err = |err: Error| {
+      Error::new(
+        err.status,
+        format!(
+          "Failed to convert `value` of ControlFlow::{}. {}",
+          if done { "Break" } else { "Continue" },
+          err.reason
+        ),
+      )
+    };
+    if done {
+      unsafe { B::from_napi_value(env, value) }
+        .map(ControlFlow::Break)
+        .map_err(convert_err)
+    } else {
+      unsafe { C::from_napi_value(env, value) }
+        .map(ControlFlow::Continue)
+        .map_err(convert_err)
+    }
+  }
+}
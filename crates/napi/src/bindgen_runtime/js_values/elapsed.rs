@@ -0,0 +1,26 @@
+use std::time::Instant;
+
+use crate::{bindgen_prelude::*, sys, ValueType};
+
+/// Seconds elapsed since an `Instant`, converted into a JavaScript `number`.
+///
+/// An `Instant` is only meaningful relative to another one in the same process, so it can't be converted directly.
+/// The elapsed time is captured when the value is converted into JavaScript, not when `Elapsed` is created,
+/// e.g. returning `Elapsed(start)` from a `#[napi]` function measures up to the moment the function returns.
+pub struct Elapsed(pub Instant);
+
+impl TypeName for Elapsed {
+  fn type_name() -> &'static str {
+    "Elapsed"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Number
+  }
+}
+
+impl ToNapiValue for Elapsed {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { f64::to_napi_value(env, val.0.elapsed().as_secs_f64()) }
+  }
+}
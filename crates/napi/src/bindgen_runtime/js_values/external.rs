@@ -1,5 +1,5 @@
 use std::{
-  any::TypeId,
+  any::{type_name, TypeId},
   ops::{Deref, DerefMut},
 };
 
@@ -75,7 +75,10 @@ impl<T: 'static> FromNapiValue for External<T> {
     } else {
       Err(Error::new(
         Status::InvalidArg,
-        "T on `get_value_external` is not the type of wrapped object".to_owned(),
+        format!(
+          "{} on `get_value_external` is not the type of wrapped object",
+          type_name::<T>()
+        ),
       ))
     }
   }
@@ -3,3 +3,132 @@ use super::ValidateNapiValue;
 pub use crate::JsFunction;
 
 impl ValidateNapiValue for JsFunction {}
+
+#[cfg(feature = "napi5")]
+pub use closure::*;
+
+#[cfg(feature = "napi5")]
+mod closure {
+  use std::ffi::c_void;
+  use std::marker::PhantomData;
+  use std::ptr;
+
+  use crate::bindgen_runtime::{FromNapiValue, ToNapiValue};
+  use crate::{check_status, sys, JsError, Result};
+
+  /// Rust closure which will be converted into a JavaScript function.
+  ///
+  /// `#[napi]` functions returning `impl Fn(A, B) -> Result<R>` are wrapped into it automatically.
+  /// The closure is dropped once the JavaScript function is garbage collected.
+  pub struct Closure<Args, Return, F> {
+    callback: F,
+    _marker: PhantomData<fn(Args) -> Return>,
+  }
+
+  impl<Args, Return, F> Closure<Args, Return, F> {
+    pub fn new(callback: F) -> Self {
+      Self {
+        callback,
+        _marker: PhantomData,
+      }
+    }
+  }
+
+  unsafe extern "C" fn finalize_closure<F>(
+    _env: sys::napi_env,
+    finalize_data: *mut c_void,
+    _finalize_hint: *mut c_void,
+  ) {
+    drop(unsafe { Box::from_raw(finalize_data as *mut F) });
+  }
+
+  macro_rules! impl_closure_to_napi_value {
+    ($len:expr, $($arg:ident: $index:tt),*) => {
+      impl<F, Return, $($arg),*> ToNapiValue for Closure<($($arg,)*), Return, F>
+      where
+        F: 'static + Fn($($arg),*) -> Result<Return>,
+        Return: ToNapiValue,
+        $($arg: FromNapiValue),*
+      {
+        unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+          unsafe extern "C" fn trampoline<F, Return, $($arg),*>(
+            env: sys::napi_env,
+            cb_info: sys::napi_callback_info,
+          ) -> sys::napi_value
+          where
+            F: 'static + Fn($($arg),*) -> Result<Return>,
+            Return: ToNapiValue,
+            $($arg: FromNapiValue),*
+          {
+            const NULL_VALUE: sys::napi_value = ptr::null_mut();
+            let mut args = [NULL_VALUE; $len];
+            let mut argc = $len;
+            let mut data = ptr::null_mut();
+            let result = check_status!(
+              unsafe {
+                sys::napi_get_cb_info(
+                  env,
+                  cb_info,
+                  &mut argc,
+                  args.as_mut_ptr(),
+                  ptr::null_mut(),
+                  &mut data,
+                )
+              },
+              "Get callback info of closure failed"
+            )
+            .and_then(|_| {
+              let callback = unsafe { &*(data as *const F) };
+              let ret = callback($(unsafe { $arg::from_napi_value(env, args[$index]) }?),*)?;
+              unsafe { Return::to_napi_value(env, ret) }
+            });
+            match result {
+              Ok(value) => value,
+              Err(err) => {
+                unsafe { JsError::from(err).throw_into(env) };
+                ptr::null_mut()
+              }
+            }
+          }
+
+          let data = Box::into_raw(Box::new(val.callback));
+          let mut func = ptr::null_mut();
+          check_status!(
+            unsafe {
+              sys::napi_create_function(
+                env,
+                ptr::null(),
+                0,
+                Some(trampoline::<F, Return, $($arg),*>),
+                data as *mut c_void,
+                &mut func,
+              )
+            },
+            "Create function from closure failed"
+          )?;
+          check_status!(
+            unsafe {
+              sys::napi_add_finalizer(
+                env,
+                func,
+                data as *mut c_void,
+                Some(finalize_closure::<F>),
+                ptr::null_mut(),
+                ptr::null_mut(),
+              )
+            },
+            "Add finalizer to closure function failed"
+          )?;
+          Ok(func)
+        }
+      }
+    };
+  }
+
+  impl_closure_to_napi_value!(0,);
+  impl_closure_to_napi_value!(1, A: 0);
+  impl_closure_to_napi_value!(2, A: 0, B: 1);
+  impl_closure_to_napi_value!(3, A: 0, B: 1, C: 2);
+  impl_closure_to_napi_value!(4, A: 0, B: 1, C: 2, D: 3);
+  impl_closure_to_napi_value!(5, A: 0, B: 1, C: 2, D: 3, E: 4);
+}
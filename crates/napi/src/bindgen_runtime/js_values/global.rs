@@ -0,0 +1,41 @@
+use std::ops::Deref;
+
+use crate::{bindgen_prelude::*, sys, Result, JSON};
+
+/// A value that can be read from the property of the same name on the JavaScript global object
+pub trait GlobalName: FromNapiValue {
+  const NAME: &'static str;
+}
+
+impl GlobalName for JSON {
+  const NAME: &'static str = "JSON";
+}
+
+/// Inject a global into a `#[napi]` function, e.g. `fn stringify(json: Global<JSON>)`.
+///
+/// The global is looked up once when the function is called and it doesn't show up in the
+/// JavaScript arguments, just like `Env`. The macro recognizes it by path, write it as `Global<T>`
+/// from the prelude or as `napi::bindgen_prelude::Global<T>`, not through a re-export or an alias.
+pub struct Global<T: GlobalName>(T);
+
+impl<T: GlobalName> Global<T> {
+  #[doc(hidden)]
+  pub unsafe fn from_env(env: sys::napi_env) -> Result<Self> {
+    Env::from(env)
+      .get_global()?
+      .get_named_property_unchecked::<T>(T::NAME)
+      .map(Global)
+  }
+
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T: GlobalName> Deref for Global<T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    &self.0
+  }
+}
@@ -0,0 +1,70 @@
+use std::cell::RefCell;
+use std::iter::Fuse;
+
+use super::state_methods::{create_object_with_state, return_this, MethodKey};
+use crate::{bindgen_prelude::*, sys, Error, NapiRaw, Result, Status};
+
+/// Rust iterator which will be converted into a synchronous JavaScript iterable iterator,
+/// an object with a `next()` method which also returns itself from `[Symbol.iterator]()`.
+///
+/// `#[napi]` functions returning `impl Iterator<Item = Result<T>>` are wrapped into it automatically.
+/// Items are pulled from the iterator one `next()` call at a time, an `Err` item is thrown from `next()`.
+/// The iterator is dropped once the JavaScript object is garbage collected.
+pub struct Iterable<I> {
+  iter: I,
+}
+
+impl<I> Iterable<I> {
+  pub fn new(iter: I) -> Self {
+    Self { iter }
+  }
+}
+
+impl<I, T> ToNapiValue for Iterable<I>
+where
+  I: 'static + Iterator<Item = Result<T>>,
+  T: ToNapiValue,
+{
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let iterator_symbol = Env::from(env).get_symbol_iterator()?;
+    unsafe {
+      create_object_with_state(
+        env,
+        RefCell::new(val.iter.fuse()),
+        &[
+          (MethodKey::Name("next\0"), next::<I, T>),
+          (MethodKey::Symbol(iterator_symbol.raw()), return_this),
+        ],
+      )
+    }
+  }
+}
+
+unsafe fn next<I, T>(
+  env: sys::napi_env,
+  _this: sys::napi_value,
+  iter: &RefCell<Fuse<I>>,
+) -> Result<sys::napi_value>
+where
+  I: 'static + Iterator<Item = Result<T>>,
+  T: ToNapiValue,
+{
+  let mut iter = iter.try_borrow_mut().map_err(|_| {
+    Error::new(
+      Status::GenericFailure,
+      "Iterable `next()` is called while it's running".to_owned(),
+    )
+  })?;
+  let mut result = Env::from(env).create_object()?;
+  match iter.next().transpose()? {
+    Some(value) => {
+      result.set("done", false)?;
+      result.set("value", value)?;
+    }
+    None => {
+      result.set("done", true)?;
+      result.set("value", ())?;
+    }
+  }
+  unsafe { Object::to_napi_value(env, result) }
+}
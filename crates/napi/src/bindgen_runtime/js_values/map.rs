@@ -1,7 +1,10 @@
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
+use std::ops::{Deref, DerefMut};
 
 use crate::bindgen_prelude::{Env, Result, ToNapiValue, *};
+use crate::{check_status, type_of, JsFunction, JsObject, JsUnknown, NapiRaw, NapiValue};
 
 impl<K, V, S> TypeName for HashMap<K, V, S> {
   fn type_name() -> &'static str {
@@ -48,3 +51,247 @@ where
     Ok(map)
   }
 }
+
+/// A `HashMap` converted to and from a plain object like `HashMap<String, V>`,
+/// for keys which aren't strings but are converted from and into property names.
+///
+/// Keys must convert to a JavaScript string, number or boolean, e.g. numbers, `bool` or `#[napi]` enums.
+/// Property names are always strings in JavaScript, so they are converted back through the number or boolean
+/// they were created from if the key type doesn't accept strings.
+pub struct PropertyMap<K, V, S = RandomState>(pub HashMap<K, V, S>);
+
+impl<K, V, S> PropertyMap<K, V, S> {
+  pub fn into_inner(self) -> HashMap<K, V, S> {
+    self.0
+  }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for PropertyMap<K, V, S> {
+  fn from(map: HashMap<K, V, S>) -> Self {
+    Self(map)
+  }
+}
+
+impl<K, V, S> Deref for PropertyMap<K, V, S> {
+  type Target = HashMap<K, V, S>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<K, V, S> DerefMut for PropertyMap<K, V, S> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
+
+impl<K, V, S> TypeName for PropertyMap<K, V, S> {
+  fn type_name() -> &'static str {
+    "PropertyMap"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<K, V, S> ValidateNapiValue for PropertyMap<K, V, S>
+where
+  K: FromNapiValue + Eq + Hash,
+  V: FromNapiValue,
+  S: BuildHasher + Default,
+{
+}
+
+impl<K, V, S> ToNapiValue for PropertyMap<K, V, S>
+where
+  K: ToNapiValue,
+  V: ToNapiValue,
+{
+  unsafe fn to_napi_value(raw_env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env = Env::from(raw_env);
+    let obj = env.create_object()?;
+    for (k, v) in val.0.into_iter() {
+      let key = unsafe { K::to_napi_value(raw_env, k)? };
+      match type_of!(raw_env, key)? {
+        ValueType::String | ValueType::Number | ValueType::Boolean => {}
+        key_type => {
+          return Err(Error::new(
+            Status::InvalidArg,
+            format!(
+              "PropertyMap key must be converted to a string, number or boolean, but got {}",
+              key_type
+            ),
+          ))
+        }
+      }
+      let value = unsafe { V::to_napi_value(raw_env, v)? };
+      check_status!(
+        unsafe { sys::napi_set_property(raw_env, obj.0.value, key, value) },
+        "Failed to set PropertyMap entry on Object"
+      )?;
+    }
+
+    unsafe { Object::to_napi_value(raw_env, obj) }
+  }
+}
+
+// Property names are always strings, try the number or boolean they may be converted from
+// if the key type doesn't accept strings, e.g. `#[napi]` enums
+unsafe fn key_from_property_name<K: FromNapiValue>(env: sys::napi_env, name: String) -> Result<K> {
+  let string_key = unsafe { String::to_napi_value(env, name.clone())? };
+  let string_err = match unsafe { K::from_napi_value(env, string_key) } {
+    Ok(key) => return Ok(key),
+    Err(err) => err,
+  };
+  let fallback = match name.as_str() {
+    "true" => Some(unsafe { bool::to_napi_value(env, true)? }),
+    "false" => Some(unsafe { bool::to_napi_value(env, false)? }),
+    _ => match name.parse::<f64>() {
+      Ok(n) if n.to_string() == name => Some(unsafe { f64::to_napi_value(env, n)? }),
+      _ => None,
+    },
+  };
+  if let Some(key) = fallback {
+    if let Ok(key) = unsafe { K::from_napi_value(env, key) } {
+      return Ok(key);
+    }
+  }
+  Err(Error::new(
+    Status::InvalidArg,
+    format!(
+      "Failed to convert property name `{}` into PropertyMap key. {}",
+      name, string_err
+    ),
+  ))
+}
+
+impl<K, V, S> FromNapiValue for PropertyMap<K, V, S>
+where
+  K: FromNapiValue + Eq + Hash,
+  V: FromNapiValue,
+  S: BuildHasher + Default,
+{
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let obj = unsafe { Object::from_napi_value(env, napi_val)? };
+    let mut map = HashMap::with_hasher(S::default());
+    for key in Object::keys(&obj)?.into_iter() {
+      if let Some(val) = obj.get(&key)? {
+        map.insert(unsafe { key_from_property_name(env, key)? }, val);
+      }
+    }
+
+    Ok(Self(map))
+  }
+}
+
+/// A `HashMap` converted to and from a JavaScript `Map` instead of a plain object.
+///
+/// Keys keep their JavaScript type, e.g. numbers stay numbers,
+/// so any key type that can be converted works, not only strings.
+pub struct JsMap<K, V, S = RandomState>(pub HashMap<K, V, S>);
+
+impl<K, V, S> JsMap<K, V, S> {
+  pub fn into_inner(self) -> HashMap<K, V, S> {
+    self.0
+  }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for JsMap<K, V, S> {
+  fn from(map: HashMap<K, V, S>) -> Self {
+    Self(map)
+  }
+}
+
+impl<K, V, S> Deref for JsMap<K, V, S> {
+  type Target = HashMap<K, V, S>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<K, V, S> DerefMut for JsMap<K, V, S> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
+
+impl<K, V, S> TypeName for JsMap<K, V, S> {
+  fn type_name() -> &'static str {
+    "Map"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<K, V, S> ValidateNapiValue for JsMap<K, V, S>
+where
+  K: FromNapiValue + Eq + Hash,
+  V: FromNapiValue,
+  S: BuildHasher + Default,
+{
+}
+
+fn map_constructor(env: &Env) -> Result<JsFunction> {
+  env.get_global()?.get_named_property_unchecked("Map")
+}
+
+impl<K, V, S> ToNapiValue for JsMap<K, V, S>
+where
+  K: ToNapiValue,
+  V: ToNapiValue,
+{
+  unsafe fn to_napi_value(raw_env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env = Env::from(raw_env);
+    let map = map_constructor(&env)?.new_instance::<JsUnknown>(&[])?;
+    let set = map.get_named_property_unchecked::<JsFunction>("set")?;
+    for (k, v) in val.0.into_iter() {
+      let key = unsafe { JsUnknown::from_raw_unchecked(raw_env, K::to_napi_value(raw_env, k)?) };
+      let value = unsafe { JsUnknown::from_raw_unchecked(raw_env, V::to_napi_value(raw_env, v)?) };
+      set.call(Some(&map), &[key, value])?;
+    }
+
+    Ok(unsafe { map.raw() })
+  }
+}
+
+/// The value must be a JavaScript `Map`, its entries are read in insertion order
+impl<K, V, S> FromNapiValue for JsMap<K, V, S>
+where
+  K: FromNapiValue + Eq + Hash,
+  V: FromNapiValue,
+  S: BuildHasher + Default,
+{
+  unsafe fn from_napi_value(raw_env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let env = Env::from(raw_env);
+    let value = unsafe { JsUnknown::from_raw_unchecked(raw_env, napi_val) };
+    if !value.instanceof(map_constructor(&env)?)? {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expect value to be a Map".to_owned(),
+      ));
+    }
+    let entries = env
+      .get_global()?
+      .get_named_property_unchecked::<JsObject>("Array")?
+      .get_named_property_unchecked::<JsFunction>("from")?
+      .call(None, &[value])?;
+    let entries = unsafe { entries.cast::<JsObject>() };
+    let len = entries.get_array_length()?;
+    let mut map = HashMap::with_capacity_and_hasher(len as usize, S::default());
+    for i in 0..len {
+      let entry = entries.get_element_unchecked::<JsObject>(i)?;
+      let key = entry.get_element::<JsUnknown>(0)?;
+      let value = entry.get_element::<JsUnknown>(1)?;
+      map.insert(unsafe { K::from_napi_value(raw_env, key.raw())? }, unsafe {
+        V::from_napi_value(raw_env, value.raw())?
+      });
+    }
+
+    Ok(Self(map))
+  }
+}
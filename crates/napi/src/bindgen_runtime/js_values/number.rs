@@ -1,8 +1,8 @@
 use super::{check_status, sys};
-use crate::{bindgen_prelude::ToNapiValue, type_of, Error, Result};
+use crate::{bindgen_prelude::ToNapiValue, type_of, Error, Result, Status};
 
 macro_rules! impl_number_conversions {
-  ( $( ($name:literal, $t:ty as $st:ty, $get:ident, $create:ident) ,)* ) => {
+  ( $( ($name:literal, $t:ty as $st:ty, $get:ident, $create:ident $(, $vec_from_typed_array:path)?) ,)* ) => {
     $(
       impl $crate::bindgen_prelude::TypeName for $t {
         fn type_name() -> &'static str {
@@ -44,6 +44,12 @@ macro_rules! impl_number_conversions {
 
           ret.try_into().map_err(|_| Error::from_reason(concat!("Failed to convert ", stringify!($st), " to ", stringify!($t))))
         }
+
+        $(
+          unsafe fn vec_from_typed_array(env: $crate::sys::napi_env, napi_val: $crate::sys::napi_value) -> Result<Option<Vec<Self>>> {
+            unsafe { $vec_from_typed_array(env, napi_val) }
+          }
+        )?
       }
     )*
   };
@@ -57,7 +63,13 @@ impl_number_conversions!(
   ("u32", u32 as u32, napi_get_value_uint32, napi_create_uint32),
   ("i32", i32 as i32, napi_get_value_int32, napi_create_int32),
   ("i64", i64 as i64, napi_get_value_int64, napi_create_int64),
-  ("f64", f64 as f64, napi_get_value_double, napi_create_double),
+  (
+    "f64",
+    f64 as f64,
+    napi_get_value_double,
+    napi_create_double,
+    super::array::vec_from_float64_array
+  ),
 );
 
 impl ToNapiValue for f32 {
@@ -72,3 +84,99 @@ impl ToNapiValue for f32 {
     Ok(ptr)
   }
 }
+
+unsafe fn get_value_double(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+  name: &str,
+) -> Result<f64> {
+  let mut ret = 0f64;
+  check_status!(
+    unsafe { sys::napi_get_value_double(env, napi_val, &mut ret) },
+    "Failed to convert napi value {:?} into rust type `{}`",
+    type_of!(env, napi_val)?,
+    name,
+  )?;
+  Ok(ret)
+}
+
+impl crate::bindgen_prelude::TypeName for f32 {
+  fn type_name() -> &'static str {
+    "f32"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Number
+  }
+}
+
+impl crate::bindgen_prelude::ValidateNapiValue for f32 {}
+
+impl crate::bindgen_prelude::FromNapiValue for f32 {
+  /// Lossy conversion, values out of the `f32` range become `Infinity`/`-Infinity`.
+  ///
+  /// Use [`CheckedF32`] if you want an error instead.
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let ret = unsafe { get_value_double(env, napi_val, "f32") }?;
+    Ok(ret as f32)
+  }
+}
+
+/// A `f32` that refuses JavaScript numbers out of the `f32` range instead of overflowing them to `Infinity`.
+///
+/// `Infinity`, `-Infinity` and `NaN` from JavaScript are passed through as is.
+/// Precision loss within the range is accepted, as `f32` can't represent most `f64` values exactly.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct CheckedF32(pub f32);
+
+impl From<CheckedF32> for f32 {
+  fn from(value: CheckedF32) -> Self {
+    value.0
+  }
+}
+
+impl From<f32> for CheckedF32 {
+  fn from(value: f32) -> Self {
+    CheckedF32(value)
+  }
+}
+
+impl std::ops::Deref for CheckedF32 {
+  type Target = f32;
+
+  fn deref(&self) -> &f32 {
+    &self.0
+  }
+}
+
+impl crate::bindgen_prelude::TypeName for CheckedF32 {
+  fn type_name() -> &'static str {
+    "CheckedF32"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Number
+  }
+}
+
+impl crate::bindgen_prelude::ValidateNapiValue for CheckedF32 {}
+
+impl ToNapiValue for CheckedF32 {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { f32::to_napi_value(env, val.0) }
+  }
+}
+
+impl crate::bindgen_prelude::FromNapiValue for CheckedF32 {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let ret = unsafe { get_value_double(env, napi_val, "CheckedF32") }?;
+    let value = ret as f32;
+    if value.is_infinite() && ret.is_finite() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Value {:e} is out of the range of f32", ret),
+      ));
+    }
+    Ok(CheckedF32(value))
+  }
+}
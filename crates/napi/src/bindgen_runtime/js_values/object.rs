@@ -59,24 +59,73 @@ impl Object {
     }
   }
 
-  pub fn keys(obj: &Object) -> Result<Vec<String>> {
-    let mut names = ptr::null_mut();
+  /// Own enumerable string keys in the order of `Object.keys`, symbol keys are skipped and
+  /// index keys are converted into strings
+  pub fn keys(&self) -> Result<Vec<String>> {
+    unsafe { own_enumerable_keys(self.0.env, self.0.value)? }
+      .into_iter()
+      .map(|key| unsafe { String::from_napi_value(self.0.env, key) })
+      .collect()
+  }
+}
+
+/// Own enumerable string keys of `obj`, the same keys `Object.keys` returns.
+///
+/// `napi_get_property_names` includes inherited keys, they have to be filtered out without `napi6`.
+pub(crate) unsafe fn own_enumerable_keys(
+  env: sys::napi_env,
+  obj: sys::napi_value,
+) -> Result<Vec<sys::napi_value>> {
+  let mut names = ptr::null_mut();
+  #[cfg(feature = "napi6")]
+  check_status!(
     unsafe {
+      sys::napi_get_all_property_names(
+        env,
+        obj,
+        sys::KeyCollectionMode::own_only,
+        sys::KeyFilter::enumerable | sys::KeyFilter::skip_symbols,
+        sys::KeyConversion::numbers_to_strings,
+        &mut names,
+      )
+    },
+    "Failed to get property names of given object"
+  )?;
+  #[cfg(not(feature = "napi6"))]
+  check_status!(
+    unsafe { sys::napi_get_property_names(env, obj, &mut names) },
+    "Failed to get property names of given object"
+  )?;
+  let mut len = 0;
+  check_status!(
+    unsafe { sys::napi_get_array_length(env, names, &mut len) },
+    "Failed to get the length of property names"
+  )?;
+
+  let mut keys = Vec::with_capacity(len as usize);
+  for i in 0..len {
+    let mut key = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_element(env, names, i, &mut key) },
+      "Failed to get property name with index `{}`",
+      i
+    )?;
+    #[cfg(not(feature = "napi6"))]
+    {
+      let mut is_own = false;
       check_status!(
-        sys::napi_get_property_names(obj.0.env, obj.0.value, &mut names),
-        "Failed to get property names of given object"
+        unsafe { sys::napi_has_own_property(env, obj, key, &mut is_own) },
+        "Failed to check property name with index `{}`",
+        i
       )?;
+      if !is_own {
+        continue;
+      }
     }
-
-    let names = unsafe { Array::from_napi_value(obj.0.env, names)? };
-    let mut ret = vec![];
-
-    for i in 0..names.len() {
-      ret.push(names.get::<String>(i)?.unwrap());
-    }
-
-    Ok(ret)
+    keys.push(key);
   }
+
+  Ok(keys)
 }
 
 impl TypeName for Object {
@@ -88,3 +137,5 @@ impl TypeName for Object {
     ValueType::Object
   }
 }
+
+impl ValidateNapiValue for Object {}
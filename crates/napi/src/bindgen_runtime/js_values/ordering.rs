@@ -0,0 +1,32 @@
+use std::cmp::Ordering;
+
+use crate::{bindgen_prelude::*, sys, ValueType};
+
+impl TypeName for Ordering {
+  fn type_name() -> &'static str {
+    "Ordering"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Number
+  }
+}
+
+impl ValidateNapiValue for Ordering {}
+
+/// `Less`, `Equal` and `Greater` are converted into `-1`, `0` and `1`,
+/// the return value convention of `Array.prototype.sort` comparators
+impl ToNapiValue for Ordering {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { i32::to_napi_value(env, val as i32) }
+  }
+}
+
+/// Any negative number is `Less` and any positive number is `Greater`,
+/// `NaN` is treated as `Equal` like `Array.prototype.sort` does
+impl FromNapiValue for Ordering {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let value = unsafe { f64::from_napi_value(env, napi_val)? };
+    Ok(value.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+  }
+}
@@ -0,0 +1,73 @@
+use crate::{bindgen_prelude::*, sys, JsError, JsUnknown, NapiValue};
+
+/// `Result` which will be converted into `{ ok: true, value }` or `{ ok: false, error }`
+/// instead of throwing the error into JavaScript.
+///
+/// `#[napi(return_result_object)]` functions wrap their returned `Result` into it,
+/// which avoids the cost of exceptions for functions called in hot loops.
+pub struct ResultObject<T, E = Error>(pub std::result::Result<T, E>);
+
+impl<T: ToNapiValue, E> ToNapiValue for ResultObject<T, E>
+where
+  JsError: From<E>,
+{
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut obj = Env::from(env).create_object()?;
+    match val.0 {
+      Ok(value) => {
+        obj.set("ok", true)?;
+        obj.set("value", value)?;
+      }
+      Err(err) => {
+        let error = unsafe { JsError::from(err).into_value(env) };
+        obj.set("ok", false)?;
+        obj.set("error", unsafe {
+          JsUnknown::from_raw_unchecked(env, error)
+        })?;
+      }
+    }
+
+    unsafe { Object::to_napi_value(env, obj) }
+  }
+}
+
+/// Results of a batch operation, converted into an array shaped like the one `Promise.allSettled` resolves with,
+/// `{ status: 'fulfilled', value }` for each `Ok` and `{ status: 'rejected', reason }` for each `Err`.
+///
+/// A failing element doesn't throw, the rest of the batch is still returned to JavaScript.
+pub struct SettledResults<T, E = Error>(pub Vec<std::result::Result<T, E>>);
+
+impl<T, E> From<Vec<std::result::Result<T, E>>> for SettledResults<T, E> {
+  fn from(results: Vec<std::result::Result<T, E>>) -> Self {
+    Self(results)
+  }
+}
+
+impl<T: ToNapiValue, E> ToNapiValue for SettledResults<T, E>
+where
+  JsError: From<E>,
+{
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env_wrapper = Env::from(env);
+    let mut array = env_wrapper.create_array_with_length(val.0.len())?;
+    for (i, result) in val.0.into_iter().enumerate() {
+      let mut obj = env_wrapper.create_object()?;
+      match result {
+        Ok(value) => {
+          obj.set("status", "fulfilled")?;
+          obj.set("value", value)?;
+        }
+        Err(err) => {
+          let reason = unsafe { JsError::from(err).into_value(env) };
+          obj.set("status", "rejected")?;
+          obj.set("reason", unsafe {
+            JsUnknown::from_raw_unchecked(env, reason)
+          })?;
+        }
+      }
+      array.set_element(i as u32, obj)?;
+    }
+
+    unsafe { Object::to_napi_value(env, array) }
+  }
+}
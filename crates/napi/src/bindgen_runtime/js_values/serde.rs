@@ -1,10 +1,10 @@
+use std::ptr;
+
 use serde_json::{Map, Number, Value};
 
-use crate::{
-  bindgen_runtime::Null, check_status, sys, type_of, Error, JsObject, Result, Status, ValueType,
-};
+use crate::{bindgen_runtime::Null, check_status, sys, type_of, Error, Result, Status, ValueType};
 
-use super::{FromNapiValue, Object, ToNapiValue};
+use super::{object::own_enumerable_keys, FromNapiValue, Object, ToNapiValue};
 
 impl ToNapiValue for Value {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
@@ -90,19 +90,23 @@ impl ToNapiValue for Map<String, Value> {
   }
 }
 
+/// Own enumerable string keys are converted recursively without going through the serde `Deserializer`,
+/// properties with `undefined` values are skipped like `JSON.stringify` does
 impl FromNapiValue for Map<String, Value> {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
-    let obj = JsObject(crate::Value {
-      env,
-      value: napi_val,
-      value_type: ValueType::Object,
-    });
-
     let mut map = Map::new();
-    for key in Object::keys(&obj)?.into_iter() {
-      if let Some(val) = obj.get(&key)? {
-        map.insert(key, val);
+    for key in unsafe { own_enumerable_keys(env, napi_val)? } {
+      let mut value = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_get_property(env, napi_val, key, &mut value) },
+        "Failed to get property of given object"
+      )?;
+      if type_of!(env, value)? == ValueType::Undefined {
+        continue;
       }
+      map.insert(unsafe { String::from_napi_value(env, key)? }, unsafe {
+        Value::from_napi_value(env, value)?
+      });
     }
 
     Ok(map)
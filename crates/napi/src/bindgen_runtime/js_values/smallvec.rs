@@ -0,0 +1,64 @@
+use smallvec::{Array as SmallVecArray, SmallVec};
+
+use crate::{bindgen_prelude::*, sys, ValueType};
+
+impl<A: SmallVecArray> TypeName for SmallVec<A> {
+  fn type_name() -> &'static str {
+    "Array<T>"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<A> ToNapiValue for SmallVec<A>
+where
+  A: SmallVecArray,
+  A::Item: ToNapiValue,
+{
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut arr = Array::new(env, val.len() as u32)?;
+
+    for (i, v) in val.into_iter().enumerate() {
+      arr.set(i as u32, v)?;
+    }
+
+    unsafe { Array::to_napi_value(env, arr) }
+  }
+}
+
+/// Elements are stored inline if the JavaScript `Array` is not longer than the inline capacity of `A`
+impl<A> FromNapiValue for SmallVec<A>
+where
+  A: SmallVecArray,
+  A::Item: FromNapiValue,
+{
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let arr = unsafe { Array::from_napi_value(env, napi_val)? };
+    let mut vec = SmallVec::with_capacity(arr.len() as usize);
+
+    for i in 0..arr.len() {
+      if let Some(val) = arr.get::<A::Item>(i)? {
+        vec.push(val);
+      } else {
+        return Err(Error::new(
+          Status::InvalidArg,
+          "Found inconsistent data type in Array<T> when converting to Rust SmallVec<T>".to_owned(),
+        ));
+      }
+    }
+
+    Ok(vec)
+  }
+}
+
+impl<A> ValidateNapiValue for SmallVec<A>
+where
+  A: SmallVecArray,
+  A::Item: FromNapiValue,
+{
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { Vec::<A::Item>::validate(env, napi_val) }
+  }
+}
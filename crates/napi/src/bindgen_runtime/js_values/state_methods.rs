@@ -0,0 +1,131 @@
+use std::ffi::c_void;
+use std::os::raw::c_char;
+use std::ptr;
+use std::rc::Rc;
+
+use crate::{check_status, sys, JsError, Result};
+
+/// Native method defined by [`create_object_with_state`], called with `this` and the shared state
+pub(crate) type StateMethod<S> =
+  unsafe fn(env: sys::napi_env, this: sys::napi_value, state: &S) -> Result<sys::napi_value>;
+
+pub(crate) enum MethodKey {
+  /// Nul terminated name
+  Name(&'static str),
+  Symbol(sys::napi_value),
+}
+
+/// Create an object with native `methods` sharing `state`.
+///
+/// Every method function receives the state through its callback `data`, so the state is not reachable
+/// from JavaScript and calling a method with another `this` still uses the state it was created with.
+/// The state is dropped once the object and all of its method functions are garbage collected.
+pub(crate) unsafe fn create_object_with_state<S: 'static>(
+  env: sys::napi_env,
+  state: S,
+  methods: &[(MethodKey, StateMethod<S>)],
+) -> Result<sys::napi_value> {
+  let mut object = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_create_object(env, &mut object) },
+    "Failed to create object"
+  )?;
+  let state = Rc::new(state);
+  let mut properties = Vec::with_capacity(methods.len());
+  for (key, method) in methods {
+    let (utf8name, name, name_len) = match key {
+      MethodKey::Name(name) => (
+        name.as_ptr() as *const c_char,
+        ptr::null_mut(),
+        name.len() - 1,
+      ),
+      MethodKey::Symbol(symbol) => (ptr::null(), *symbol, 0),
+    };
+    let data = Box::into_raw(Box::new((Rc::clone(&state), *method)));
+    let mut func = ptr::null_mut();
+    let mut status = unsafe {
+      sys::napi_create_function(
+        env,
+        utf8name,
+        name_len,
+        Some(call_state_method::<S>),
+        data as *mut c_void,
+        &mut func,
+      )
+    };
+    if status == sys::Status::napi_ok {
+      // functions are objects, wrapping one ties the lifetime of `data` to it
+      status = unsafe {
+        sys::napi_wrap(
+          env,
+          func,
+          data as *mut c_void,
+          Some(drop_state_method::<S>),
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      };
+    }
+    if status != sys::Status::napi_ok {
+      // the function is never exposed, nothing can call it with the freed `data`
+      drop(unsafe { Box::from_raw(data) });
+      check_status!(status, "Failed to create native method")?;
+    }
+    properties.push(sys::napi_property_descriptor {
+      utf8name,
+      name,
+      method: None,
+      getter: None,
+      setter: None,
+      value: func,
+      attributes: sys::PropertyAttributes::default,
+      data: ptr::null_mut(),
+    });
+  }
+  check_status!(
+    unsafe { sys::napi_define_properties(env, object, properties.len(), properties.as_ptr()) },
+    "Failed to define native methods"
+  )?;
+  Ok(object)
+}
+
+/// [`StateMethod`] returning `this`, e.g. for `[Symbol.iterator]()` of an iterator
+pub(crate) unsafe fn return_this<S>(
+  _env: sys::napi_env,
+  this: sys::napi_value,
+  _state: &S,
+) -> Result<sys::napi_value> {
+  Ok(this)
+}
+
+unsafe extern "C" fn call_state_method<S: 'static>(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let mut this = ptr::null_mut();
+  let mut data = ptr::null_mut();
+  let mut argc = 0;
+  let result = check_status!(
+    unsafe { sys::napi_get_cb_info(env, info, &mut argc, ptr::null_mut(), &mut this, &mut data) },
+    "Failed to get callback info of native method"
+  )
+  .and_then(|_| {
+    let (state, method) = unsafe { &*(data as *const (Rc<S>, StateMethod<S>)) };
+    unsafe { method(env, this, state) }
+  });
+  match result {
+    Ok(value) => value,
+    Err(e) => {
+      unsafe { JsError::from(e).throw_into(env) };
+      ptr::null_mut()
+    }
+  }
+}
+
+unsafe extern "C" fn drop_state_method<S: 'static>(
+  _env: sys::napi_env,
+  data: *mut c_void,
+  _hint: *mut c_void,
+) {
+  drop(unsafe { Box::from_raw(data as *mut (Rc<S>, StateMethod<S>)) });
+}
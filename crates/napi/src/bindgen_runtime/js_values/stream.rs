@@ -0,0 +1,284 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+use tokio::sync::Mutex;
+
+use super::state_methods::{create_object_with_state, return_this, MethodKey};
+use crate::{bindgen_prelude::*, sys, JsSymbol, NapiRaw, Result};
+
+type Release = Box<dyn FnOnce(sys::napi_env) + Send>;
+
+struct StreamState<S> {
+  stream: Mutex<Option<Pin<Box<S>>>>,
+  // set by `return()`, checked by the pending `next()` without waiting for the lock it holds
+  closed: AtomicBool,
+  waker: std::sync::Mutex<Option<Waker>>,
+  release: std::sync::Mutex<Option<Release>>,
+}
+
+impl<S> StreamState<S> {
+  /// Must be called on the JavaScript thread once the stream is dropped
+  fn release(&self, env: sys::napi_env) {
+    if let Some(release) = self
+      .release
+      .lock()
+      .expect("AsyncIterable release lock failed")
+      .take()
+    {
+      release(env);
+    }
+  }
+
+  /// Drop the stream and release right away, unless a pending `next()` holds it,
+  /// which then drops it and releases once it's resolved
+  fn drop_stream(&self, env: sys::napi_env) {
+    if let Ok(mut stream) = self.stream.try_lock() {
+      stream.take();
+      drop(stream);
+      self.release(env);
+    }
+  }
+
+  fn close(&self) {
+    self.closed.store(true, Ordering::SeqCst);
+    if let Some(waker) = self
+      .waker
+      .lock()
+      .expect("AsyncIterable waker lock failed")
+      .take()
+    {
+      waker.wake();
+    }
+  }
+}
+
+type SharedStream<S> = Arc<StreamState<S>>;
+
+/// The state owned by the JavaScript object, it's dropped on the JavaScript thread once the object is garbage collected
+struct StreamHandle<S> {
+  env: sys::napi_env,
+  stream: SharedStream<S>,
+}
+
+impl<S> Drop for StreamHandle<S> {
+  fn drop(&mut self) {
+    self.stream.close();
+    self.stream.drop_stream(self.env);
+  }
+}
+
+/// Rust `Stream` which will be converted into a JavaScript async iterable iterator, an object with
+/// `next()` and `return()` methods returning `Promise`s, which also returns itself from `[Symbol.asyncIterator]()`.
+///
+/// `#[napi]` functions returning `impl Stream<Item = Result<T>>` are wrapped into it automatically,
+/// so they can be consumed by `for await` in JavaScript.
+/// `async fn`s returning `impl Stream` return the async iterator right away too, see [`AsyncIterable::from_future`].
+/// Items are polled on the tokio runtime one `next()` call at a time, an `Err` item rejects the `next()` promise.
+/// The stream is dropped once it's exhausted, the consumer stops iterating (e.g. `break` in `for await` calls `return()`),
+/// or the JavaScript object is garbage collected.
+/// `return()` also cancels a pending `next()`, which then resolves as done.
+pub struct AsyncIterable<S> {
+  stream: S,
+  release: Option<Release>,
+}
+
+impl<S> AsyncIterable<S> {
+  pub fn new(stream: S) -> Self {
+    Self {
+      stream,
+      release: None,
+    }
+  }
+
+  #[doc(hidden)]
+  /// Call `release` on the JavaScript thread after the stream is dropped, e.g. to delete the references
+  /// which keep the borrowed arguments of an `async fn` alive while the stream is iterated.
+  pub fn with_release<R>(mut self, release: R) -> Self
+  where
+    R: 'static + Send + FnOnce(sys::napi_env),
+  {
+    self.release = Some(Box::new(release));
+    self
+  }
+}
+
+impl<F, S, T> AsyncIterable<FutureStream<F, S>>
+where
+  F: Future<Output = Result<S>>,
+  S: Stream<Item = Result<T>>,
+{
+  /// Iterate the stream produced by `future`, which is awaited by the first `next()` call.
+  ///
+  /// If the future fails, its error rejects the first `next()` promise and the iteration is done.
+  pub fn from_future(future: F) -> Self {
+    Self::new(FutureStream(FutureStreamState::Future(Box::pin(future))))
+  }
+}
+
+/// Stream produced by a future, created by [`AsyncIterable::from_future`]
+pub struct FutureStream<F, S>(FutureStreamState<F, S>);
+
+enum FutureStreamState<F, S> {
+  Future(Pin<Box<F>>),
+  Stream(Pin<Box<S>>),
+  Done,
+}
+
+impl<F, S, T> Stream for FutureStream<F, S>
+where
+  F: Future<Output = Result<S>>,
+  S: Stream<Item = Result<T>>,
+{
+  type Item = Result<T>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let state = &mut self.get_mut().0;
+    loop {
+      match state {
+        FutureStreamState::Future(future) => match future.as_mut().poll(cx) {
+          Poll::Pending => return Poll::Pending,
+          Poll::Ready(Ok(stream)) => *state = FutureStreamState::Stream(Box::pin(stream)),
+          Poll::Ready(Err(err)) => {
+            *state = FutureStreamState::Done;
+            return Poll::Ready(Some(Err(err)));
+          }
+        },
+        FutureStreamState::Stream(stream) => return stream.as_mut().poll_next(cx),
+        FutureStreamState::Done => return Poll::Ready(None),
+      }
+    }
+  }
+}
+
+impl<S, T> ToNapiValue for AsyncIterable<S>
+where
+  S: 'static + Send + Stream<Item = Result<T>>,
+  T: 'static + Send + ToNapiValue,
+{
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let async_iterator_symbol = Env::from(env)
+      .get_global()?
+      .get_named_property_unchecked::<Object>("Symbol")?
+      .get_named_property_unchecked::<JsSymbol>("asyncIterator")?;
+    let stream: SharedStream<S> = Arc::new(StreamState {
+      stream: Mutex::new(Some(Box::pin(val.stream))),
+      closed: AtomicBool::new(false),
+      waker: std::sync::Mutex::new(None),
+      release: std::sync::Mutex::new(val.release),
+    });
+    unsafe {
+      create_object_with_state(
+        env,
+        StreamHandle { env, stream },
+        &[
+          (MethodKey::Name("next\0"), next::<S, T>),
+          (MethodKey::Name("return\0"), return_::<S>),
+          (MethodKey::Symbol(async_iterator_symbol.raw()), return_this),
+        ],
+      )
+    }
+  }
+}
+
+/// `StreamExt::next` without depending on `futures-util`, which also ends once `return()` is called
+struct Next<'a, S: ?Sized, St> {
+  stream: Pin<&'a mut S>,
+  state: &'a StreamState<St>,
+}
+
+impl<S: ?Sized + Stream, St> Future for Next<'_, S, St> {
+  type Output = Option<S::Item>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let this = self.get_mut();
+    // register before checking the flag, so that a `return()` in between still wakes this poll
+    *this
+      .state
+      .waker
+      .lock()
+      .expect("AsyncIterable waker lock failed") = Some(cx.waker().clone());
+    if this.state.closed.load(Ordering::SeqCst) {
+      return Poll::Ready(None);
+    }
+    this.stream.as_mut().poll_next(cx)
+  }
+}
+
+unsafe fn iterator_result<T: ToNapiValue>(
+  env: sys::napi_env,
+  item: Option<T>,
+) -> Result<sys::napi_value> {
+  let mut result = Env::from(env).create_object()?;
+  match item {
+    Some(value) => {
+      result.set("done", false)?;
+      result.set("value", value)?;
+    }
+    None => {
+      result.set("done", true)?;
+      result.set("value", ())?;
+    }
+  }
+  unsafe { Object::to_napi_value(env, result) }
+}
+
+unsafe fn next<S, T>(
+  env: sys::napi_env,
+  _this: sys::napi_value,
+  handle: &StreamHandle<S>,
+) -> Result<sys::napi_value>
+where
+  S: 'static + Send + Stream<Item = Result<T>>,
+  T: 'static + Send + ToNapiValue,
+{
+  let stream = Arc::clone(&handle.stream);
+  let state = Arc::clone(&handle.stream);
+  execute_tokio_future(
+    env,
+    async move {
+      let state = &*stream;
+      let mut stream = state.stream.lock().await;
+      let item = match stream.as_mut() {
+        Some(s) => {
+          Next {
+            stream: s.as_mut(),
+            state,
+          }
+          .await
+        }
+        None => None,
+      };
+      if item.is_none() {
+        // drop the exhausted or closed stream early instead of waiting for the GC
+        stream.take();
+      }
+      item.transpose()
+    },
+    move |env, item| {
+      if item.is_none() {
+        state.release(env);
+      }
+      unsafe { iterator_result(env, item) }
+    },
+  )
+}
+
+unsafe fn return_<S>(
+  env: sys::napi_env,
+  _this: sys::napi_value,
+  handle: &StreamHandle<S>,
+) -> Result<sys::napi_value>
+where
+  S: 'static + Send,
+{
+  // a pending `next()` holds the lock, it's woken up and drops the stream itself
+  handle.stream.close();
+  handle.stream.drop_stream(env);
+  execute_tokio_future(env, async move { Ok(()) }, |env, _| unsafe {
+    iterator_result::<()>(env, None)
+  })
+}
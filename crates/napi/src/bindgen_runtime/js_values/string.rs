@@ -1,10 +1,12 @@
 use crate::{bindgen_prelude::*, check_status, check_status_and_type, sys, Error, Result, Status};
 
-use std::ffi::{c_void, CStr};
+use std::borrow::Cow;
+use std::ffi::{c_void, CStr, CString};
 use std::fmt::Display;
 use std::mem;
 use std::ops::Deref;
 use std::ptr;
+use std::sync::Arc;
 
 impl TypeName for String {
   fn type_name() -> &'static str {
@@ -139,7 +141,127 @@ impl FromNapiValue for &str {
 
 impl ToNapiValue for &str {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
-    unsafe { String::to_napi_value(env, val.to_owned()) }
+    let mut ptr = ptr::null_mut();
+
+    check_status!(
+      unsafe { sys::napi_create_string_utf8(env, val.as_ptr() as *const _, val.len(), &mut ptr) },
+      "Failed to convert rust `&str` into napi `string`"
+    )?;
+
+    Ok(ptr)
+  }
+}
+
+impl TypeName for Cow<'_, str> {
+  fn type_name() -> &'static str {
+    "String"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::String
+  }
+}
+
+impl ValidateNapiValue for Cow<'static, str> {}
+
+/// Node-API always copies the content of a JavaScript string into a buffer owned by the caller,
+/// so there is nothing to borrow from and the result is always `Cow::Owned`.
+impl FromNapiValue for Cow<'static, str> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { String::from_napi_value(env, napi_val) }.map(Cow::Owned)
+  }
+}
+
+/// `Cow::Borrowed` is converted straight from the borrowed `&str`, without allocating a `String`
+impl ToNapiValue for Cow<'_, str> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    match val {
+      Cow::Borrowed(s) => unsafe { <&str>::to_napi_value(env, s) },
+      Cow::Owned(s) => unsafe { String::to_napi_value(env, s) },
+    }
+  }
+}
+
+impl TypeName for Arc<str> {
+  fn type_name() -> &'static str {
+    "String"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::String
+  }
+}
+
+impl ValidateNapiValue for Arc<str> {}
+
+/// Node-API copies the string into a `String`, which is copied again into the shared allocation
+impl FromNapiValue for Arc<str> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { String::from_napi_value(env, napi_val) }.map(Arc::from)
+  }
+}
+
+impl ToNapiValue for Arc<str> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { <&str>::to_napi_value(env, &val) }
+  }
+}
+
+impl TypeName for CString {
+  fn type_name() -> &'static str {
+    "String"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::String
+  }
+}
+
+impl ValidateNapiValue for CString {}
+
+/// Fails with `InvalidArg` if the JavaScript string contains an interior nul character
+impl FromNapiValue for CString {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let s = unsafe { String::from_napi_value(env, napi_val)? };
+    CString::new(s).map_err(|e| {
+      Error::new(
+        Status::InvalidArg,
+        format!(
+          "Failed to convert JavaScript string into rust type `CString`, {}",
+          e
+        ),
+      )
+    })
+  }
+}
+
+/// Fails with `InvalidArg` if the `CString` isn't valid UTF-8
+impl ToNapiValue for CString {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { <&CStr>::to_napi_value(env, val.as_c_str()) }
+  }
+}
+
+impl TypeName for &CStr {
+  fn type_name() -> &'static str {
+    "String"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::String
+  }
+}
+
+/// Fails with `InvalidArg` if the `CStr` isn't valid UTF-8
+impl ToNapiValue for &CStr {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let s = val.to_str().map_err(|e| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Failed to convert rust `CStr` into napi `string`, {}", e),
+      )
+    })?;
+    unsafe { <&str>::to_napi_value(env, s) }
   }
 }
 
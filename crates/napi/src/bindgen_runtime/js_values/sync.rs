@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::{bindgen_prelude::*, sys, Error, Result, Status, ValueType};
+
+fn poisoned_error<T>(lock: &str) -> Error {
+  Error::new(
+    Status::GenericFailure,
+    format!(
+      "Failed to convert `{}<{}>` into napi value, the lock is poisoned",
+      lock,
+      std::any::type_name::<T>(),
+    ),
+  )
+}
+
+macro_rules! impl_lock_snapshot {
+  ($lock:ident, $acquire:ident, $(#[$ref_doc:meta])*) => {
+    impl<T: TypeName> TypeName for $lock<T> {
+      fn type_name() -> &'static str {
+        T::type_name()
+      }
+
+      fn value_type() -> ValueType {
+        T::value_type()
+      }
+    }
+
+    impl<T: ToNapiValue> ToNapiValue for $lock<T> {
+      unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        let inner = val
+          .into_inner()
+          .map_err(|_| poisoned_error::<T>(stringify!($lock)))?;
+        unsafe { T::to_napi_value(env, inner) }
+      }
+    }
+
+    $(#[$ref_doc])*
+    impl<T: ToNapiValue + Clone> ToNapiValue for &$lock<T> {
+      unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        let snapshot = val
+          .$acquire()
+          .map_err(|_| poisoned_error::<T>(stringify!($lock)))?
+          .clone();
+        unsafe { T::to_napi_value(env, snapshot) }
+      }
+    }
+
+    #[doc = concat!("Same as `&", stringify!($lock), "<T>`, other owners of the `Arc` keep using the lock")]
+    impl<T: ToNapiValue + Clone> ToNapiValue for Arc<$lock<T>> {
+      unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+        unsafe { <&$lock<T>>::to_napi_value(env, &val) }
+      }
+    }
+  };
+}
+
+impl_lock_snapshot!(
+  Mutex,
+  lock,
+  /// Clones the guarded value while holding the lock, the JavaScript value does not follow
+  /// later changes.
+  ///
+  /// The JavaScript thread blocks until the lock is acquired, so avoid converting a `Mutex`
+  /// that other threads hold for long. Converting while the current thread holds the guard
+  /// deadlocks or panics, as with [`Mutex::lock`].
+  ///
+  /// A lock poisoned by a panicking thread is reported as an error, the possibly broken value
+  /// is not exposed to JavaScript.
+);
+impl_lock_snapshot!(
+  RwLock,
+  read,
+  /// Clones the value under a read lock, the JavaScript value does not follow later writes.
+  ///
+  /// Other readers do not block the conversion, but the JavaScript thread waits for a pending
+  /// writer to release the lock. Converting while the current thread holds a write guard
+  /// deadlocks or panics, as with [`RwLock::read`].
+  ///
+  /// A lock poisoned by a writer that panicked is reported as an error, the possibly broken
+  /// value is not exposed to JavaScript.
+);
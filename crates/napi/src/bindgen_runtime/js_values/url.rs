@@ -0,0 +1,51 @@
+use url::Url;
+
+use crate::{bindgen_prelude::*, sys, type_of, Error, Result, Status, ValueType};
+
+impl TypeName for Url {
+  fn type_name() -> &'static str {
+    "Url"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Unknown
+  }
+}
+
+impl ValidateNapiValue for Url {}
+
+/// Accepts either a string or a WHATWG `URL` object, which is read through its `href`
+impl FromNapiValue for Url {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let href = match type_of!(env, napi_val)? {
+      ValueType::String => unsafe { String::from_napi_value(env, napi_val)? },
+      ValueType::Object => {
+        let obj = unsafe { Object::from_napi_value(env, napi_val)? };
+        obj.get::<_, String>("href")?.ok_or_else(|| {
+          Error::new(
+            Status::InvalidArg,
+            "Missing `href` field in URL object".to_owned(),
+          )
+        })?
+      }
+      ty => {
+        return Err(Error::new(
+          Status::InvalidArg,
+          format!("Expect a string or URL object, got {}", ty),
+        ))
+      }
+    };
+    Url::parse(&href).map_err(|err| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Failed to parse `{}` as URL, {}", href, err),
+      )
+    })
+  }
+}
+
+impl ToNapiValue for Url {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { String::to_napi_value(env, String::from(val)) }
+  }
+}
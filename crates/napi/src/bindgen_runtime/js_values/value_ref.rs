@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::ffi::c_void;
+use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
 use std::rc::{Rc, Weak};
 
@@ -19,6 +20,34 @@ type RefInformation = (
 pub(crate) static REFERENCE_MAP: Lazy<PersistedPerInstanceHashMap<*mut c_void, RefInformation>> =
   Lazy::new(Default::default);
 
+/// Whether a `Reference` to the class instance wrapping `t` is alive
+pub(crate) fn is_referenced(t: *mut c_void) -> bool {
+  REFERENCE_MAP.borrow_mut(|map| {
+    map.get(&t).map_or(false, |(_, _, finalize_callbacks_ptr)| {
+      let finalize_callbacks = ManuallyDrop::new(unsafe { Rc::from_raw(*finalize_callbacks_ptr) });
+      Rc::strong_count(&finalize_callbacks) > 1
+    })
+  })
+}
+
+/// Forget the class instance wrapping `t` after it's removed from its JavaScript object,
+/// the finalize callbacks are called like they would be on garbage collection
+pub(crate) unsafe fn remove_ref(env: crate::sys::napi_env, t: *mut c_void) {
+  if let Some((_, napi_ref, finalize_callbacks_ptr)) =
+    REFERENCE_MAP.borrow_mut(|map| map.remove(&t))
+  {
+    let finalize_callbacks = unsafe { Rc::from_raw(finalize_callbacks_ptr) };
+    let finalize = unsafe { Box::from_raw(finalize_callbacks.get()) };
+    finalize();
+    let delete_reference_status = unsafe { crate::sys::napi_delete_reference(env, napi_ref) };
+    debug_assert!(
+      delete_reference_status == crate::sys::Status::napi_ok,
+      "Delete reference of removed wrap failed {}",
+      Status::from(delete_reference_status)
+    );
+  }
+}
+
 /// ### Experimental feature
 ///
 /// Create a `reference` from `Class` instance.
@@ -0,0 +1,43 @@
+use std::sync::Weak;
+
+use super::state_methods::{create_object_with_state, MethodKey};
+use crate::{bindgen_prelude::*, sys, Result, ValueType};
+
+/// A `Weak` reference passed to JavaScript as an object with an `isAlive()` method,
+/// which returns whether the value it points to can still be upgraded.
+///
+/// The `Weak` is kept by the native `isAlive` method and dropped once the object and the method are garbage collected,
+/// so the JavaScript side never keeps the value itself alive.
+pub struct WeakHandle<T: 'static>(pub Weak<T>);
+
+impl<T: 'static> From<Weak<T>> for WeakHandle<T> {
+  fn from(weak: Weak<T>) -> Self {
+    WeakHandle(weak)
+  }
+}
+
+impl<T: 'static> TypeName for WeakHandle<T> {
+  fn type_name() -> &'static str {
+    "WeakHandle"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<T: 'static> ToNapiValue for WeakHandle<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe {
+      create_object_with_state(env, val.0, &[(MethodKey::Name("isAlive\0"), is_alive::<T>)])
+    }
+  }
+}
+
+unsafe fn is_alive<T: 'static>(
+  env: sys::napi_env,
+  _this: sys::napi_value,
+  weak: &Weak<T>,
+) -> Result<sys::napi_value> {
+  unsafe { bool::to_napi_value(env, weak.upgrade().is_some()) }
+}
@@ -25,6 +25,13 @@ pub trait ObjectFinalize: Sized {
   }
 }
 
+/// Implemented by `#[napi(cleanup)]` classes, `on_env_exit` is registered as an
+/// [env cleanup hook](https://nodejs.org/api/n-api.html#napi_add_env_cleanup_hook) of every env
+/// the module is loaded into, e.g. to shut down global thread pools owned by the class.
+pub trait NapiCleanup {
+  fn on_env_exit();
+}
+
 /// # Safety
 ///
 /// called when node wrapper objects destroyed
@@ -8,8 +8,8 @@ use std::thread::ThreadId;
 use once_cell::sync::Lazy;
 
 use crate::{
-  check_status, check_status_or_throw, sys, Env, JsError, JsFunction, Property, Result, Value,
-  ValueType,
+  check_status, check_status_or_throw, sys, Env, JsError, JsFunction, JsObject, NapiValue,
+  Property, PropertyAttributes, Result, Value, ValueType,
 };
 
 pub type ExportRegisterCallback = unsafe fn(sys::napi_env) -> Result<sys::napi_value>;
@@ -110,6 +110,10 @@ unsafe impl<K, V> Sync for PersistedPerInstanceHashMap<K, V> {}
 type FnRegisterMap =
   PersistedPerInstanceHashMap<ExportRegisterCallback, (sys::napi_callback, &'static str)>;
 type RegisteredClassesMap = PersistedPerInstanceHashMap<ThreadId, RegisteredClasses>;
+type ClassExtendsMap = PersistedPerInstanceHashMap<
+  /* rust name */ &'static str,
+  /* superclass rust name */ &'static str,
+>;
 
 static MODULE_REGISTER_CALLBACK: Lazy<ModuleRegisterCallback> = Lazy::new(Default::default);
 static MODULE_CLASS_PROPERTIES: Lazy<ModuleClassProperty> = Lazy::new(Default::default);
@@ -117,6 +121,8 @@ static IS_FIRST_MODULE: AtomicBool = AtomicBool::new(true);
 static FIRST_MODULE_REGISTERED: AtomicBool = AtomicBool::new(false);
 static REGISTERED_CLASSES: Lazy<RegisteredClassesMap> = Lazy::new(Default::default);
 static FN_REGISTER_MAP: Lazy<FnRegisterMap> = Lazy::new(Default::default);
+static CLASS_EXTENDS: Lazy<ClassExtendsMap> = Lazy::new(Default::default);
+static CLASS_CLEANUP_HOOKS: Lazy<PersistedPerInstanceVec<fn()>> = Lazy::new(Default::default);
 #[cfg(feature = "napi4")]
 pub(crate) static CUSTOM_GC_TSFN: AtomicPtr<sys::napi_threadsafe_function__> =
   AtomicPtr::new(ptr::null_mut());
@@ -195,6 +201,62 @@ pub fn register_class(
   });
 }
 
+#[doc(hidden)]
+pub fn register_class_extends(rust_name: &'static str, parent_rust_name: &'static str) {
+  CLASS_EXTENDS.borrow_mut(|inner| {
+    inner.insert(rust_name, parent_rust_name);
+  });
+}
+
+#[doc(hidden)]
+pub fn register_class_cleanup(on_env_exit: fn()) {
+  CLASS_CLEANUP_HOOKS.push(on_env_exit);
+}
+
+#[cfg(feature = "napi3")]
+unsafe extern "C" fn call_class_cleanup(on_env_exit: *mut std::ffi::c_void) {
+  let on_env_exit: fn() = unsafe { std::mem::transmute(on_env_exit) };
+  on_env_exit();
+}
+
+/// `Object.setPrototypeOf(Class.prototype, Parent.prototype)`, so `instanceof Parent` holds,
+/// and `Object.setPrototypeOf(Class, Parent)`, so the static methods are inherited like with `class Class extends Parent`
+///
+/// A `factory` of the superclass called on the subclass still returns a superclass instance.
+unsafe fn inherit_class_prototype(
+  env: sys::napi_env,
+  registered_classes: &HashMap<String, sys::napi_ref>,
+  js_name: &str,
+  parent_js_name: &str,
+) -> Result<()> {
+  let get_ctor = |name: &str| -> Result<JsObject> {
+    let ctor_ref = registered_classes.get(name).ok_or_else(|| {
+      crate::Error::new(
+        crate::Status::InvalidArg,
+        format!("Class `{}` is not registered", name.trim_end_matches('\0')),
+      )
+    })?;
+    let mut ctor = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(env, *ctor_ref, &mut ctor) },
+      "Failed to get constructor of class `{}`",
+      name.trim_end_matches('\0')
+    )?;
+    Ok(unsafe { JsObject::from_raw_unchecked(env, ctor) })
+  };
+  let ctor = get_ctor(js_name)?;
+  let parent_ctor = get_ctor(parent_js_name)?;
+  let prototype = ctor.get_named_property_unchecked::<JsObject>("prototype")?;
+  let parent_prototype = parent_ctor.get_named_property_unchecked::<JsObject>("prototype")?;
+  let set_prototype_of = Env::from(env)
+    .get_global()?
+    .get_named_property_unchecked::<JsObject>("Object")?
+    .get_named_property_unchecked::<JsFunction>("setPrototypeOf")?;
+  set_prototype_of.call(None, &[prototype, parent_prototype])?;
+  set_prototype_of.call(None, &[ctor, parent_ctor])?;
+  Ok(())
+}
+
 #[inline]
 /// Get `JsFunction` from defined Rust `fn`
 /// ```rust
@@ -375,6 +437,7 @@ unsafe extern "C" fn napi_register_module_v1(
   });
 
   let mut registered_classes = HashMap::new();
+  let mut class_extends = vec![];
 
   MODULE_CLASS_PROPERTIES.borrow_mut(|inner| {
     inner.iter().for_each(|(rust_name, js_mods)| {
@@ -411,9 +474,51 @@ unsafe extern "C" fn napi_register_module_v1(
               exports_objects.insert(js_mod_str.to_string());
             }
           }
-          let (ctor, props): (Vec<_>, Vec<_>) = props.iter().partition(|prop| prop.is_ctor);
+          let (ctor, mut props): (Vec<_>, Vec<_>) = props.iter().partition(|prop| prop.is_ctor);
 
           let ctor = ctor.get(0).map(|c| c.raw().method.unwrap()).unwrap_or(noop);
+          // V8 refuses to call methods of a class on instances of another class,
+          // so the instance properties of the superclasses are defined on the subclass as well
+          CLASS_EXTENDS.borrow_mut(|extends| {
+            let mut current = *rust_name;
+            while let Some(parent) = extends.get(current) {
+              let parent_mods = inner.get(parent);
+              // the superclass is looked up in other namespaces only if it's exported from a single one
+              let parent_class = parent_mods.and_then(|parent_mods| {
+                parent_mods.get(js_mod).or_else(|| {
+                  if parent_mods.len() == 1 {
+                    parent_mods.values().next()
+                  } else {
+                    None
+                  }
+                })
+              });
+              if parent_class.is_none() && parent_mods.is_some() {
+                class_extends.push(Err(crate::Error::new(
+                  crate::Status::InvalidArg,
+                  format!(
+                    "Class `{}` extends `{}`, which is exported from several namespaces other than the one of `{}`",
+                    current, parent, rust_name
+                  ),
+                )));
+                break;
+              }
+              if let Some((parent_js_name, parent_props)) = parent_class {
+                if current == *rust_name {
+                  class_extends.push(Ok((js_name.to_string(), parent_js_name.to_string())));
+                }
+                for prop in parent_props {
+                  if !prop.is_ctor
+                    && !prop.attrs.contains(PropertyAttributes::Static)
+                    && !props.iter().any(|p| p.name == prop.name)
+                  {
+                    props.push(prop);
+                  }
+                }
+              }
+              current = parent;
+            }
+          });
           let raw_props: Vec<_> = props.iter().map(|prop| prop.raw()).collect();
 
           let js_class_name = CStr::from_bytes_with_nul_unchecked(js_name.as_bytes());
@@ -461,6 +566,15 @@ unsafe extern "C" fn napi_register_module_v1(
       }
     });
 
+    for extends in class_extends {
+      if let Err(e) = extends.and_then(|(js_name, parent_js_name)| unsafe {
+        inherit_class_prototype(env, &registered_classes, &js_name, &parent_js_name)
+      }) {
+        unsafe { JsError::from(e).throw_into(env) };
+        break;
+      }
+    }
+
     REGISTERED_CLASSES.borrow_mut(|map| {
       map.insert(
         std::thread::current().id(),
@@ -469,6 +583,23 @@ unsafe extern "C" fn napi_register_module_v1(
     });
   });
 
+  #[cfg(feature = "napi3")]
+  CLASS_CLEANUP_HOOKS.borrow_mut(|inner| {
+    inner.iter().for_each(|on_env_exit| {
+      check_status_or_throw!(
+        env,
+        unsafe {
+          sys::napi_add_env_cleanup_hook(
+            env,
+            Some(call_class_cleanup),
+            *on_env_exit as *mut std::ffi::c_void,
+          )
+        },
+        "Failed to add env cleanup hook of class"
+      );
+    })
+  });
+
   #[cfg(feature = "compat-mode")]
   MODULE_EXPORTS.borrow_mut(|inner| {
     inner.iter().for_each(|callback| unsafe {
@@ -1,8 +1,9 @@
+use std::os::raw::c_void;
 use std::ptr;
 
 use crate::bindgen_runtime::{FromNapiValue, TypeName};
 use crate::check_status;
-use crate::{sys, Either, Env, Error, JsUndefined, NapiValue, Result, Status};
+use crate::{sys, Either, Env, Error, JsUndefined, JsUnknown, NapiValue, Result, Status};
 
 /// Function call context
 pub struct CallContext<'env> {
@@ -82,6 +83,61 @@ impl<'env> CallContext<'env> {
       .collect()
   }
 
+  /// Get all the arguments of this call from `napi_get_cb_info`.
+  ///
+  /// Unlike [`get_all`](Self::get_all), arguments are not truncated to the `#[js_function(arg_len)]`.
+  pub fn get_all_args(&self) -> Result<Vec<JsUnknown>> {
+    let mut argc = 0;
+    check_status!(unsafe {
+      sys::napi_get_cb_info(
+        self.env.0,
+        self.callback_info,
+        &mut argc,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    })?;
+    let mut raw_args = vec![ptr::null_mut(); argc];
+    check_status!(unsafe {
+      sys::napi_get_cb_info(
+        self.env.0,
+        self.callback_info,
+        &mut argc,
+        raw_args.as_mut_ptr(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    })?;
+    Ok(
+      raw_args
+        .into_iter()
+        .map(|raw| unsafe { JsUnknown::from_raw_unchecked(self.env.0, raw) })
+        .collect(),
+    )
+  }
+
+  /// Get the `data` pointer passed to `napi_create_function` from `napi_get_cb_info`.
+  pub fn get_data(&self) -> Result<*mut c_void> {
+    let mut data = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_cb_info(
+        self.env.0,
+        self.callback_info,
+        &mut 0,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        &mut data,
+      )
+    })?;
+    Ok(data)
+  }
+
+  /// Raw `this` value of this call.
+  pub fn raw_this(&self) -> sys::napi_value {
+    self.raw_this
+  }
+
   pub fn get_new_target<V>(&self) -> Result<V>
   where
     V: NapiValue,
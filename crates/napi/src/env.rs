@@ -7,9 +7,7 @@ use std::mem;
 use std::os::raw::{c_char, c_void};
 use std::ptr;
 
-use crate::bindgen_runtime::FromNapiValue;
-#[cfg(all(feature = "napi4"))]
-use crate::bindgen_runtime::ToNapiValue;
+use crate::bindgen_runtime::{FromNapiValue, ObjectFinalize, ToNapiValue};
 use crate::{
   async_work::{self, AsyncWorkPromise},
   check_status,
@@ -38,6 +36,17 @@ pub type Callback = unsafe extern "C" fn(sys::napi_env, sys::napi_callback_info)
 
 pub(crate) static EMPTY_VEC: Vec<u8> = vec![];
 
+macro_rules! create_typed_array_copy_fns {
+  ($($name:ident: $rust_type:ident => $js_type:literal),* $(,)?) => {
+    $(
+      #[doc = concat!("Copy `data` into a new `", $js_type, "`, see `create_typed_array_copy`.")]
+      pub fn $name(&self, data: &[$rust_type]) -> Result<JsTypedArray> {
+        self.create_typed_array_copy(data)
+      }
+    )*
+  };
+}
+
 #[derive(Clone, Copy)]
 /// `Env` is used to represent a context that the underlying N-API implementation can use to persist VM-specific state.
 ///
@@ -56,6 +65,11 @@ impl From<sys::napi_env> for Env {
   }
 }
 
+/// Created by `Env::add_promise_rejection_callback`
+/// And used by `Env::remove_promise_rejection_callback`, dropping it keeps the callback registered
+#[cfg(feature = "napi5")]
+pub struct PromiseRejectionCallback(sys::napi_ref);
+
 impl Env {
   #[allow(clippy::missing_safety_doc)]
   pub unsafe fn from_raw(env: sys::napi_env) -> Self {
@@ -98,6 +112,13 @@ impl Env {
     Ok(unsafe { JsNumber::from_raw_unchecked(self.0, raw_value) })
   }
 
+  /// Create a number from its raw IEEE 754 bit pattern, the counterpart of `JsNumber::to_bits`.
+  ///
+  /// The sign of zero is kept, but the JavaScript engine is free to canonicalize `NaN` payloads.
+  pub fn create_double_from_bits(&self, bits: u64) -> Result<JsNumber> {
+    self.create_double(f64::from_bits(bits))
+  }
+
   /// [n_api_napi_create_bigint_int64](https://nodejs.org/api/n-api.html#n_api_napi_create_bigint_int64)
   #[cfg(feature = "napi6")]
   pub fn create_bigint_from_i64(&self, value: i64) -> Result<JsBigInt> {
@@ -113,6 +134,22 @@ impl Env {
     Ok(JsBigInt::from_raw_unchecked(self.0, raw_value, 1))
   }
 
+  /// Create a JavaScript array of `BigInt`s from `values`, the array is created with its final length upfront
+  #[cfg(feature = "napi6")]
+  pub fn create_bigint_array_i64(&self, values: &[i64]) -> Result<JsObject> {
+    let array = self.create_array_with_length(values.len())?;
+    for (index, value) in values.iter().enumerate() {
+      let mut raw_value = ptr::null_mut();
+      check_status!(unsafe { sys::napi_create_bigint_int64(self.0, *value, &mut raw_value) })?;
+      check_status!(
+        unsafe { sys::napi_set_element(self.0, array.0.value, index as u32, raw_value) },
+        "Failed to set BigInt at index {}",
+        index
+      )?;
+    }
+    Ok(array)
+  }
+
   #[cfg(feature = "napi6")]
   pub fn create_bigint_from_i128(&self, value: i128) -> Result<JsBigInt> {
     let mut raw_value = ptr::null_mut();
@@ -220,12 +257,65 @@ impl Env {
     Ok(unsafe { JsSymbol::from_raw_unchecked(self.0, result) })
   }
 
+  /// Get the well-known `Symbol.iterator` symbol, the key of the iterator protocol method.
+  pub fn get_symbol_iterator(&self) -> Result<JsSymbol> {
+    self
+      .get_global()?
+      .get_named_property_unchecked::<JsObject>("Symbol")?
+      .get_named_property_unchecked::<JsSymbol>("iterator")
+  }
+
+  /// Make `obj` iterable by defining its `[Symbol.iterator]()` method.
+  ///
+  /// Every call of `[Symbol.iterator]()` returns a new iterator object whose `next` is the provided function,
+  /// which should return `{ done, value }` objects. Any iteration state lives in `next` itself.
+  pub fn make_iterable(&self, obj: &mut JsObject, next: JsFunction) -> Result<()> {
+    let iterator_symbol = self.get_symbol_iterator()?;
+    let make_iterator = self.create_function("[Symbol.iterator]", make_iterator)?;
+    // bind `next` as the first argument instead of keeping it in a property of `obj`,
+    // so it's not reachable from JavaScript and doesn't depend on `this`
+    let make_iterator = make_iterator.coerce_to_object()?;
+    let bind = make_iterator.get_named_property::<JsFunction>("bind")?;
+    let iterator_method = bind.call(
+      Some(&make_iterator),
+      &[self.get_undefined()?.into_unknown(), next.into_unknown()],
+    )?;
+    let properties = [sys::napi_property_descriptor {
+      utf8name: ptr::null(),
+      name: iterator_symbol.0.value,
+      method: None,
+      getter: None,
+      setter: None,
+      value: iterator_method.0.value,
+      attributes: sys::PropertyAttributes::default,
+      data: ptr::null_mut(),
+    }];
+    check_status!(
+      unsafe {
+        sys::napi_define_properties(self.0, obj.0.value, properties.len(), properties.as_ptr())
+      },
+      "Failed to define the iterator protocol on object"
+    )
+  }
+
   pub fn create_object(&self) -> Result<JsObject> {
     let mut raw_value = ptr::null_mut();
     check_status!(unsafe { sys::napi_create_object(self.0, &mut raw_value) })?;
     Ok(unsafe { JsObject::from_raw_unchecked(self.0, raw_value) })
   }
 
+  /// Creates an instance of the registered `#[napi]` class `T` wrapping `native`, without calling its `constructor`.
+  ///
+  /// The object has the prototype of the class constructor, so it passes `instanceof` the class,
+  /// the same way instances returned from `#[napi(factory)]` methods do.
+  pub fn new_instance<T: ObjectFinalize + ToNapiValue + 'static>(
+    &self,
+    native: T,
+  ) -> Result<JsObject> {
+    let instance = unsafe { T::to_napi_value(self.0, native)? };
+    Ok(unsafe { JsObject::from_raw_unchecked(self.0, instance) })
+  }
+
   pub fn create_empty_array(&self) -> Result<JsObject> {
     let mut raw_value = ptr::null_mut();
     check_status!(unsafe { sys::napi_create_array(self.0, &mut raw_value) })?;
@@ -474,6 +564,148 @@ impl Env {
     ))
   }
 
+  /// Move `data` into an external `ArrayBuffer` without copying it, the box is dropped once the `ArrayBuffer` is garbage collected.
+  ///
+  /// Unlike `create_arraybuffer_with_data`, there is no spare capacity to keep track of,
+  /// the allocation is exactly as large as the `ArrayBuffer`.
+  pub fn create_arraybuffer_from_boxed_slice(&self, data: Box<[u8]>) -> Result<JsArrayBufferValue> {
+    let length = data.len();
+    let mut raw_value = ptr::null_mut();
+    if length == 0 {
+      // See `create_arraybuffer_with_data`, empty boxed slices use a dangling data pointer too
+      check_status!(unsafe {
+        sys::napi_create_arraybuffer(self.0, length, ptr::null_mut(), &mut raw_value)
+      })?;
+      return Ok(JsArrayBufferValue::new(
+        unsafe { JsArrayBuffer::from_raw_unchecked(self.0, raw_value) },
+        ptr::null_mut(),
+        length,
+      ));
+    }
+    let data_ptr = Box::into_raw(data) as *mut u8;
+    let status = unsafe {
+      sys::napi_create_external_arraybuffer(
+        self.0,
+        data_ptr.cast(),
+        length,
+        Some(drop_boxed_slice),
+        // the hint is the length itself, not a pointer
+        length as *mut c_void,
+        &mut raw_value,
+      )
+    };
+    let data_ptr = if status == sys::Status::napi_ok {
+      data_ptr.cast()
+    } else {
+      // the finalizer won't be called, take the box back
+      let data = unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(data_ptr, length)) };
+      if status != sys::Status::napi_no_external_buffers_allowed {
+        check_status!(status)?;
+      }
+      // copy it into a regular `ArrayBuffer` if external buffers are not allowed
+      let mut underlying_data = ptr::null_mut();
+      check_status!(unsafe {
+        sys::napi_create_arraybuffer(self.0, length, &mut underlying_data, &mut raw_value)
+      })?;
+      unsafe { ptr::copy_nonoverlapping(data.as_ptr(), underlying_data.cast(), length) };
+      underlying_data
+    };
+    Ok(JsArrayBufferValue::new(
+      unsafe { JsArrayBuffer::from_raw_unchecked(self.0, raw_value) },
+      data_ptr,
+      length,
+    ))
+  }
+
+  /// Move `data` into an external `ArrayBuffer` and view it with the `TypedArray` of its element type,
+  /// e.g. `Vec<f64>` becomes a `Float64Array`, without copying.
+  ///
+  /// The `Vec` is dropped when the `ArrayBuffer` is garbage collected.
+  /// It's copied into a new `ArrayBuffer` if the runtime doesn't allow external buffers, e.g. Electron.
+  pub fn create_external_arraybuffer<T: TypedArrayElement>(
+    &self,
+    data: Vec<T>,
+  ) -> Result<JsTypedArray> {
+    let length = data.len();
+    let byte_length = mem::size_of_val(data.as_slice());
+    let mut data = mem::ManuallyDrop::new(data);
+    let data_ptr = data.as_mut_ptr();
+    let mut raw_value = ptr::null_mut();
+    if length == 0 {
+      // Same as `create_arraybuffer_with_data`, empty `Vec`s don't own a valid data pointer
+      unsafe { mem::ManuallyDrop::drop(&mut data) };
+      check_status!(unsafe {
+        sys::napi_create_arraybuffer(self.0, 0, ptr::null_mut(), &mut raw_value)
+      })?;
+    } else {
+      let hint_ptr = Box::into_raw(Box::new((length, data.capacity())));
+      let status = unsafe {
+        sys::napi_create_external_arraybuffer(
+          self.0,
+          data_ptr.cast(),
+          byte_length,
+          Some(drop_typed_buffer::<T>),
+          hint_ptr.cast(),
+          &mut raw_value,
+        )
+      };
+      if status == sys::Status::napi_no_external_buffers_allowed {
+        drop(unsafe { Box::from_raw(hint_ptr) });
+        let mut underlying_data = ptr::null_mut();
+        let status = unsafe {
+          sys::napi_create_arraybuffer(self.0, byte_length, &mut underlying_data, &mut raw_value)
+        };
+        if status == sys::Status::napi_ok {
+          unsafe {
+            ptr::copy_nonoverlapping(data_ptr.cast::<u8>(), underlying_data.cast(), byte_length)
+          };
+        }
+        unsafe { mem::ManuallyDrop::drop(&mut data) };
+        check_status!(status)?;
+      } else if status != sys::Status::napi_ok {
+        drop(unsafe { Box::from_raw(hint_ptr) });
+        unsafe { mem::ManuallyDrop::drop(&mut data) };
+        check_status!(status)?;
+      }
+    }
+    let arraybuffer = unsafe { JsArrayBuffer::from_raw_unchecked(self.0, raw_value) };
+    arraybuffer.into_typedarray(T::TYPED_ARRAY_TYPE, length, 0)
+  }
+
+  /// Copy `data` into a new `ArrayBuffer` and view it with the `TypedArray` of its element type,
+  /// e.g. `&[f64]` becomes a `Float64Array`.
+  ///
+  /// Unlike `create_external_arraybuffer`, the `TypedArray` doesn't borrow or own any Rust memory.
+  pub fn create_typed_array_copy<T: TypedArrayElement>(&self, data: &[T]) -> Result<JsTypedArray> {
+    let byte_length = mem::size_of_val(data);
+    let arraybuffer = self.create_arraybuffer(byte_length)?;
+    if byte_length > 0 {
+      unsafe {
+        ptr::copy_nonoverlapping(data.as_ptr().cast::<u8>(), arraybuffer.data(), byte_length)
+      };
+    }
+    arraybuffer
+      .into_raw()
+      .into_typedarray(T::TYPED_ARRAY_TYPE, data.len(), 0)
+  }
+
+  create_typed_array_copy_fns!(
+    create_int8_array: i8 => "Int8Array",
+    create_uint8_array: u8 => "Uint8Array",
+    create_int16_array: i16 => "Int16Array",
+    create_uint16_array: u16 => "Uint16Array",
+    create_int32_array: i32 => "Int32Array",
+    create_uint32_array: u32 => "Uint32Array",
+    create_float32_array: f32 => "Float32Array",
+    create_float64_array: f64 => "Float64Array",
+  );
+
+  #[cfg(feature = "napi6")]
+  create_typed_array_copy_fns!(
+    create_bigint64_array: i64 => "BigInt64Array",
+    create_biguint64_array: u64 => "BigUint64Array",
+  );
+
   /// # Safety
   /// Mostly the same with `create_arraybuffer_with_data`
   ///
@@ -563,6 +795,19 @@ impl Env {
     Ok(unsafe { JsFunction::from_raw_unchecked(self.0, raw_result) })
   }
 
+  /// Create a function and a reference to it, so it can be kept and called after the current scope.
+  ///
+  /// Get the function back with `reference.get_value(&env)`,
+  /// and release it with `reference.unref(env)` once it's no longer needed.
+  pub fn create_function_reference(
+    &self,
+    name: &str,
+    callback: Callback,
+  ) -> Result<Ref<JsFunction>> {
+    let func = self.create_function(name, callback)?;
+    Ref::new(func.0, 1, func)
+  }
+
   #[cfg(feature = "napi5")]
   pub fn create_function_from_closure<R, F>(&self, name: &str, callback: F) -> Result<JsFunction>
   where
@@ -696,6 +941,25 @@ impl Env {
     Ok(unsafe { JsFunction::from_raw_unchecked(self.0, raw_result) })
   }
 
+  /// Create a JavaScript function carrying native `data`, the `callback` receives a reference to it on every call.
+  ///
+  /// `data` is boxed and dropped when the function is garbage collected.
+  /// Functions sharing state can each hold a clone of an `Rc`.
+  #[cfg(feature = "napi5")]
+  pub fn create_function_with_data<T, R, F>(
+    &self,
+    name: &str,
+    data: T,
+    callback: F,
+  ) -> Result<JsFunction>
+  where
+    T: 'static,
+    F: 'static + Fn(crate::CallContext<'_>, &T) -> Result<R>,
+    R: NapiRaw,
+  {
+    self.create_function_from_closure(name, move |ctx| callback(ctx, &data))
+  }
+
   /// This API retrieves a napi_extended_error_info structure with information about the last error that occurred.
   ///
   /// The content of the napi_extended_error_info returned is only valid up until an n-api function is called on the same env.
@@ -714,6 +978,36 @@ impl Env {
     check_status!(unsafe { sys::napi_throw(self.0, value.raw()) })
   }
 
+  /// Take the pending JavaScript exception and clear it, returns `None` if no exception is pending.
+  ///
+  /// Pass the exception to [`Env::throw`] to rethrow it.
+  ///
+  /// <https://nodejs.org/api/n-api.html#napi_get_and_clear_last_exception>
+  pub fn get_and_clear_last_exception(&self) -> Result<Option<JsUnknown>> {
+    let mut is_pending = false;
+    check_status!(unsafe { sys::napi_is_exception_pending(self.0, &mut is_pending) })?;
+    if !is_pending {
+      return Ok(None);
+    }
+    let mut exception = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_and_clear_last_exception(self.0, &mut exception) })?;
+    Ok(Some(unsafe {
+      JsUnknown::from_raw_unchecked(self.0, exception)
+    }))
+  }
+
+  /// Return `Err` wrapping the pending JavaScript exception after clearing it, or `Ok(())` if none is pending.
+  ///
+  /// Propagating the `Err` out of the native function throws the original exception value again,
+  /// so `env.propagate_pending_exception()?` is enough to bubble up an exception after a raw call
+  /// into JavaScript which leaves it pending instead of returning it.
+  pub fn propagate_pending_exception(&self) -> Result<()> {
+    match self.get_and_clear_last_exception()? {
+      Some(exception) => Err(Error::from(exception)),
+      None => Ok(()),
+    }
+  }
+
   /// This API throws a JavaScript Error with the text provided.
   pub fn throw_error(&self, msg: &str, code: Option<&str>) -> Result<()> {
     let code = code.and_then(|s| CString::new(s).ok());
@@ -727,6 +1021,37 @@ impl Env {
     })
   }
 
+  /// This API throws a JavaScript Error with the text provided and sets the given properties on it,
+  /// e.g. `err.retryable` or `err.statusCode`.
+  ///
+  /// See `Error::with_property` to attach properties to a returned `Error` instead.
+  pub fn throw_error_with_props(
+    &self,
+    msg: &str,
+    code: Option<&str>,
+    props: &[(&str, JsUnknown)],
+  ) -> Result<()> {
+    let mut js_code = ptr::null_mut();
+    if let Some(code) = code {
+      check_status!(unsafe {
+        sys::napi_create_string_utf8(self.0, code.as_ptr().cast(), code.len(), &mut js_code)
+      })?;
+    }
+    let mut js_msg = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_create_string_utf8(self.0, msg.as_ptr().cast(), msg.len(), &mut js_msg)
+    })?;
+    let mut js_error = ptr::null_mut();
+    check_status!(unsafe { sys::napi_create_error(self.0, js_code, js_msg, &mut js_error) })?;
+    for (key, value) in props {
+      let key = CString::new(*key)?;
+      check_status!(unsafe {
+        sys::napi_set_named_property(self.0, js_error, key.as_ptr(), value.raw())
+      })?;
+    }
+    check_status!(unsafe { sys::napi_throw(self.0, js_error) })
+  }
+
   /// This API throws a JavaScript RangeError with the text provided.
   pub fn throw_range_error(&self, msg: &str, code: Option<&str>) -> Result<()> {
     let code = code.and_then(|s| CString::new(s).ok());
@@ -1045,7 +1370,10 @@ impl Env {
       } else {
         Err(Error::new(
           Status::InvalidArg,
-          "T on get_value_external is not the type of wrapped object".to_owned(),
+          format!(
+            "{} on get_value_external is not the type of wrapped object",
+            type_name::<T>()
+          ),
         ))
       }
     }
@@ -1061,6 +1389,57 @@ impl Env {
     Ok(unsafe { JsObject::from_raw_unchecked(self.0, result) })
   }
 
+  /// Wrap a caught JavaScript error into an `Error` with extra context, e.g. to rethrow it from Rust.
+  ///
+  /// The original error object is thrown again, so its `stack` is kept,
+  /// while `reason` is prepended right away to its `message` as `{reason}: {message}`, and to the first line of its `stack`.
+  /// Thrown values which aren't objects, and errors which can't be modified, e.g. frozen ones, are thrown as is.
+  pub fn create_error_from_js_error_value<R: Into<String>>(
+    &self,
+    value: JsUnknown,
+    reason: R,
+  ) -> Error {
+    let reason = reason.into();
+    unsafe { crate::error::prepend_reason(self.0, value.0.value, &reason) };
+    let mut err = Error::from(value);
+    err.reason = reason;
+    err
+  }
+
+  /// Create a `Promise` already resolved with `value`, same as `Promise.resolve(value)` in JavaScript
+  pub fn resolved_promise<T: ToNapiValue>(&self, value: T) -> Result<JsObject> {
+    let value = unsafe { T::to_napi_value(self.0, value) }?;
+    self.call_promise_static_method("resolve", value)
+  }
+
+  /// Create a `Promise` already rejected with `err`, same as `Promise.reject(err)` in JavaScript
+  pub fn rejected_promise<E: Into<Error>>(&self, err: E) -> Result<JsObject> {
+    let err = unsafe { Error::to_napi_value(self.0, err.into()) }?;
+    self.call_promise_static_method("reject", err)
+  }
+
+  /// Wait for all of the `promises`, same as `Promise.all(promises)` in JavaScript
+  pub fn promise_all(&self, promises: Vec<JsObject>) -> Result<JsObject> {
+    let promises = unsafe { Vec::<JsObject>::to_napi_value(self.0, promises) }?;
+    self.call_promise_static_method("all", promises)
+  }
+
+  /// Settle with the first settled of the `promises`, same as `Promise.race(promises)` in JavaScript
+  pub fn promise_race(&self, promises: Vec<JsObject>) -> Result<JsObject> {
+    let promises = unsafe { Vec::<JsObject>::to_napi_value(self.0, promises) }?;
+    self.call_promise_static_method("race", promises)
+  }
+
+  fn call_promise_static_method(&self, method: &str, value: sys::napi_value) -> Result<JsObject> {
+    let promise = self
+      .get_global()?
+      .get_named_property_unchecked::<JsObject>("Promise")?;
+    let method = promise.get_named_property::<JsFunction>(method)?;
+    let value = unsafe { JsUnknown::from_raw_unchecked(self.0, value) };
+    let result = method.call(Some(&promise), &[value])?;
+    Ok(unsafe { result.cast() })
+  }
+
   /// Run [Task](./trait.Task.html) in libuv thread pool, return [AsyncWorkPromise](./struct.AsyncWorkPromise.html)
   pub fn spawn<T: 'static + Task>(&self, task: T) -> Result<AsyncWorkPromise> {
     async_work::run(self.0, task, None)
@@ -1091,17 +1470,122 @@ impl Env {
     unsafe { V::from_napi_value(self.0, raw_value) }
   }
 
-  /// `process.versions.napi`
+  /// Compile a script once, so it can be run repeatedly with [`CompiledScript::run`] without being parsed again.
+  ///
+  /// Node-API doesn't expose a way to compile a script without running it, so the code is compiled with the global `Function` constructor.
+  /// Unlike [`Env::run_script`], the code is the body of a function:
+  /// - Use `return` to produce the result, the value of the last expression is discarded.
+  /// - Function and `var` declarations are local to the script, they will not be added to the global object.
+  /// - The value of this is [global](https://nodejs.org/api/globals.html) within the script.
+  ///
+  /// Syntax errors are thrown at compile time and returned as `Err`.
+  pub fn compile_script<S: AsRef<str>>(&self, script: S) -> Result<CompiledScript> {
+    let function_constructor = self
+      .get_global()?
+      .get_named_property::<JsFunction>("Function")?;
+    let source = self.create_string(script.as_ref())?;
+    let compiled = function_constructor.call(None, &[source])?;
+    CompiledScript::new(self, unsafe { compiled.cast::<JsFunction>() })
+  }
+
+  /// Get the highest Node-API version supported by the running Node.js, same as `process.versions.napi`
+  ///
+  /// <https://nodejs.org/api/n-api.html#napi_get_version>
   pub fn get_napi_version(&self) -> Result<u32> {
-    let global = self.get_global()?;
-    let process: JsObject = global.get_named_property("process")?;
-    let versions: JsObject = process.get_named_property("versions")?;
-    let napi_version: JsString = versions.get_named_property("napi")?;
-    napi_version
-      .into_utf8()?
-      .as_str()?
-      .parse()
-      .map_err(|e| Error::new(Status::InvalidArg, format!("{}", e)))
+    let mut result = 0u32;
+    check_status!(unsafe { sys::napi_get_version(self.0, &mut result) })?;
+    Ok(result)
+  }
+
+  /// Emit a Node.js process warning through `process.emitWarning`, e.g. for deprecated APIs.
+  ///
+  /// `kind` defaults to `"Warning"`, pass `Some("DeprecationWarning")` to honour `--no-deprecation` and `--throw-deprecation`.
+  pub fn emit_warning(&self, msg: &str, kind: Option<&str>, code: Option<&str>) -> Result<()> {
+    let mut args = vec![self.create_string(msg)?.into_unknown()];
+    for arg in [kind, code] {
+      args.push(match arg {
+        Some(arg) => self.create_string(arg)?.into_unknown(),
+        None => self.get_undefined()?.into_unknown(),
+      });
+    }
+    self.call_process_method("emitWarning", &args)?;
+    Ok(())
+  }
+
+  /// Call `process[method]` with `process` as `this`, Node-API has no equivalent for these shims
+  fn call_process_method(&self, method: &str, args: &[JsUnknown]) -> Result<JsUnknown> {
+    let process = self
+      .get_global()?
+      .get_named_property::<JsObject>("process")?;
+    let func = process.get_named_property::<JsUnknown>(method)?;
+    if func.get_type()? != ValueType::Function {
+      return Err(Error::new(
+        Status::FunctionExpected,
+        format!("process.{} is not a function", method),
+      ));
+    }
+    let func: JsFunction = unsafe { func.cast() };
+    func.call(Some(&process), args)
+  }
+
+  /// Call `callback` with the reason and the promise whenever a promise is rejected without a handler,
+  /// by adding an `unhandledRejection` listener to `process`.
+  ///
+  /// Every call adds another listener, pass the returned handle to
+  /// [`Env::remove_promise_rejection_callback`] to remove it again.
+  /// Like any `unhandledRejection` listener, registering it replaces the default behavior of Node.js,
+  /// the process won't crash or warn on unhandled rejections anymore.
+  /// An `Err` returned from `callback` is thrown as an uncaught exception.
+  #[cfg(feature = "napi5")]
+  pub fn add_promise_rejection_callback<F>(&self, callback: F) -> Result<PromiseRejectionCallback>
+  where
+    F: 'static + Fn(Env, JsUnknown, JsObject) -> Result<()>,
+  {
+    let listener = self.create_function_from_closure("unhandledRejection", move |ctx| {
+      let reason = ctx.get::<JsUnknown>(0)?;
+      let promise = ctx.get::<JsObject>(1)?;
+      callback(*ctx.env, reason, promise)?;
+      ctx.env.get_undefined()
+    })?;
+    // weak, `process` keeps the listener alive until it's removed
+    let mut listener_ref = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_create_reference(self.0, listener.0.value, 0, &mut listener_ref)
+    })?;
+    if let Err(e) = self.call_process_method(
+      "on",
+      &[
+        self.create_string("unhandledRejection")?.into_unknown(),
+        listener.into_unknown(),
+      ],
+    ) {
+      unsafe { sys::napi_delete_reference(self.0, listener_ref) };
+      return Err(e);
+    }
+    Ok(PromiseRejectionCallback(listener_ref))
+  }
+
+  /// Remove the `unhandledRejection` listener added by [`Env::add_promise_rejection_callback`]
+  #[cfg(feature = "napi5")]
+  pub fn remove_promise_rejection_callback(
+    &self,
+    callback: PromiseRejectionCallback,
+  ) -> Result<()> {
+    let mut listener = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_reference_value(self.0, callback.0, &mut listener) })?;
+    check_status!(unsafe { sys::napi_delete_reference(self.0, callback.0) })?;
+    // only collected if something else already removed it from `process`
+    if listener.is_null() {
+      return Ok(());
+    }
+    self.call_process_method(
+      "removeListener",
+      &[
+        self.create_string("unhandledRejection")?.into_unknown(),
+        unsafe { JsUnknown::from_raw_unchecked(self.0, listener) },
+      ],
+    )?;
+    Ok(())
   }
 
   #[cfg(feature = "napi2")]
@@ -1160,6 +1644,47 @@ impl Env {
     ThreadsafeFunction::create(self.0, func.0.value, max_queue_size, callback)
   }
 
+  /// Like [`Env::create_threadsafe_function`], but when the JavaScript callback throws,
+  /// `on_error` is called on the JavaScript thread with the exception instead of raising
+  /// an uncaught exception.
+  #[cfg(feature = "napi4")]
+  pub fn create_threadsafe_function_with_error_handler<
+    T: Send,
+    V: ToNapiValue,
+    R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<Vec<V>>,
+    E: 'static + Send + Fn(Error),
+  >(
+    &self,
+    func: &JsFunction,
+    max_queue_size: usize,
+    callback: R,
+    on_error: E,
+  ) -> Result<ThreadsafeFunction<T>> {
+    ThreadsafeFunction::create_with_error_handler(
+      self.0,
+      func.0.value,
+      max_queue_size,
+      callback,
+      Some(Box::new(on_error)),
+    )
+  }
+
+  /// Like [`Env::create_threadsafe_function`], but `callback` decides the `this` of the JavaScript
+  /// callback along with its arguments, `None` calls it with `undefined` as `this`.
+  #[cfg(feature = "napi4")]
+  pub fn create_threadsafe_function_with_this<
+    T: Send,
+    V: ToNapiValue,
+    R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<(Option<JsObject>, Vec<V>)>,
+  >(
+    &self,
+    func: &JsFunction,
+    max_queue_size: usize,
+    callback: R,
+  ) -> Result<ThreadsafeFunction<T>> {
+    ThreadsafeFunction::create_with_receiver(self.0, func.0.value, max_queue_size, callback, None)
+  }
+
   #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
   pub fn execute_tokio_future<
     T: 'static + Send,
@@ -1180,6 +1705,11 @@ impl Env {
     Ok(unsafe { JsObject::from_raw_unchecked(self.0, promise) })
   }
 
+  /// Drives the `Future` on the tokio runtime napi-rs is configured with, the same way a `#[napi] async fn` does,
+  /// and returns a `Promise` which is resolved with the output converted into a JavaScript value,
+  /// or rejected with the `Error` if the future fails.
+  ///
+  /// Use [`Env::execute_tokio_future`] to customize the conversion of the output.
   #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
   pub fn spawn_future<
     T: 'static + Send + ToNapiValue,
@@ -1395,6 +1925,22 @@ impl Env {
     T::deserialize(&mut de)
   }
 
+  /// Get the bytes viewed by a `Buffer`, or any other `TypedArray` or `DataView`.
+  ///
+  /// Returns an error if the value is not an `ArrayBufferView`.
+  pub fn get_buffer_info<'a>(&self, value: &'a JsUnknown) -> Result<&'a [u8]> {
+    let mut data = ptr::null_mut();
+    let mut len: usize = 0;
+    check_status!(
+      unsafe { sys::napi_get_buffer_info(self.0, value.0.value, &mut data, &mut len) },
+      "Failed to get buffer info, the value is not an ArrayBufferView"
+    )?;
+    if data.is_null() || len == 0 {
+      return Ok(&[]);
+    }
+    Ok(unsafe { std::slice::from_raw_parts(data as *const u8, len) })
+  }
+
   /// This API represents the invocation of the Strict Equality algorithm as defined in [Section 7.2.14](https://tc39.es/ecma262/#sec-strict-equality-comparison) of the ECMAScript Language Specification.
   pub fn strict_equals<A: NapiRaw, B: NapiRaw>(&self, a: A, b: B) -> Result<bool> {
     let mut result = false;
@@ -1415,6 +1961,40 @@ impl Env {
   }
 }
 
+/// `[Symbol.iterator]()` of `make_iterable`, the `next` function is bound as the first argument
+unsafe extern "C" fn make_iterator(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let create_iterator = || -> Result<sys::napi_value> {
+    let mut next = ptr::null_mut();
+    let mut argc = 1;
+    check_status!(
+      unsafe {
+        sys::napi_get_cb_info(
+          env,
+          info,
+          &mut argc,
+          &mut next,
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Failed to get callback info of [Symbol.iterator]"
+    )?;
+    let mut iterator = Env::from(env).create_object()?;
+    iterator.set_named_property("next", unsafe { JsFunction::from_raw(env, next)? })?;
+    Ok(iterator.0.value)
+  };
+  match create_iterator() {
+    Ok(iterator) => iterator,
+    Err(e) => {
+      unsafe { crate::JsError::from(e).throw_into(env) };
+      ptr::null_mut()
+    }
+  }
+}
+
 /// This function could be used for `create_buffer_with_borrowed_data` and want do noting when Buffer finalized.
 pub fn noop_finalize<Hint>(_hint: Hint, _env: Env) {}
 
@@ -1428,6 +2008,30 @@ unsafe extern "C" fn drop_buffer(
   mem::drop(unsafe { Vec::from_raw_parts(finalize_data as *mut u8, length, cap) });
 }
 
+unsafe extern "C" fn drop_boxed_slice(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  hint: *mut c_void,
+) {
+  let length = hint as usize;
+  mem::drop(unsafe {
+    Box::from_raw(ptr::slice_from_raw_parts_mut(
+      finalize_data as *mut u8,
+      length,
+    ))
+  });
+}
+
+unsafe extern "C" fn drop_typed_buffer<T>(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  hint: *mut c_void,
+) {
+  let length_ptr = hint as *mut (usize, usize);
+  let (length, cap) = unsafe { *Box::from_raw(length_ptr) };
+  mem::drop(unsafe { Vec::from_raw_parts(finalize_data as *mut T, length, cap) });
+}
+
 pub(crate) unsafe extern "C" fn raw_finalize<T>(
   env: sys::napi_env,
   finalize_data: *mut c_void,
@@ -1,3 +1,5 @@
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
 use std::convert::{From, TryFrom};
 use std::error;
 use std::ffi::{CStr, CString};
@@ -6,6 +8,7 @@ use std::fmt;
 use std::fmt::Display;
 use std::os::raw::{c_char, c_void};
 use std::ptr;
+use std::sync::Arc;
 
 #[cfg(feature = "serde-json")]
 use serde::{de, ser};
@@ -13,7 +16,7 @@ use serde::{de, ser};
 use serde_json::Error as SerdeJSONError;
 
 use crate::bindgen_runtime::ToNapiValue;
-use crate::{check_status, sys, Env, JsUnknown, NapiValue, Status};
+use crate::{check_status, sys, type_of, Env, JsObject, JsUnknown, NapiValue, Status, ValueType};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -27,6 +30,31 @@ pub struct Error {
   // Convert raw `JsError` into Error
   maybe_raw: sys::napi_ref,
   maybe_env: sys::napi_env,
+  // Captured only if `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` is set
+  #[cfg(feature = "backtrace")]
+  #[clippy::msrv = "1.65"]
+  backtrace: Option<Arc<Backtrace>>,
+  // The original error if this `Error` was converted from `anyhow::Error`
+  #[cfg(feature = "anyhow")]
+  anyhow_error: Option<Arc<anyhow::Error>>,
+  // Set on the JavaScript error object when it's created
+  properties: Vec<ErrorProperty>,
+}
+
+type ErrorPropertyValue = Arc<dyn Fn(sys::napi_env) -> Result<sys::napi_value> + Send + Sync>;
+
+#[derive(Clone)]
+struct ErrorProperty {
+  key: String,
+  value: ErrorPropertyValue,
+}
+
+impl fmt::Debug for ErrorProperty {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ErrorProperty")
+      .field("key", &self.key)
+      .finish()
+  }
 }
 
 impl ToNapiValue for Error {
@@ -88,6 +116,11 @@ impl From<JsUnknown> for Error {
       reason: "".to_string(),
       maybe_raw: result,
       maybe_env: value.0.env,
+      #[cfg(feature = "backtrace")]
+      backtrace: None,
+      #[cfg(feature = "anyhow")]
+      anyhow_error: None,
+      properties: Vec::new(),
     }
   }
 }
@@ -95,7 +128,9 @@ impl From<JsUnknown> for Error {
 #[cfg(feature = "anyhow")]
 impl From<anyhow::Error> for Error {
   fn from(value: anyhow::Error) -> Self {
-    Error::new(Status::GenericFailure, format!("{}", value))
+    let mut error = Error::new(Status::GenericFailure, format!("{}", value));
+    error.anyhow_error = Some(Arc::new(value));
+    error
   }
 }
 
@@ -116,47 +151,142 @@ impl Error {
       reason,
       maybe_raw: ptr::null_mut(),
       maybe_env: ptr::null_mut(),
+      #[cfg(feature = "backtrace")]
+      backtrace: capture_backtrace(),
+      #[cfg(feature = "anyhow")]
+      anyhow_error: None,
+      properties: Vec::new(),
     }
   }
 
   pub fn from_status(status: Status) -> Self {
-    Error {
-      status,
-      reason: "".to_owned(),
-      maybe_raw: ptr::null_mut(),
-      maybe_env: ptr::null_mut(),
-    }
+    Error::new(status, "".to_owned())
   }
 
   pub fn from_reason<T: Into<String>>(reason: T) -> Self {
-    Error {
-      status: Status::GenericFailure,
-      reason: reason.into(),
-      maybe_raw: ptr::null_mut(),
-      maybe_env: ptr::null_mut(),
+    Error::new(Status::GenericFailure, reason.into())
+  }
+
+  /// Whether a JavaScript exception is already pending for this `Error`, e.g. thrown by a called JavaScript function.
+  ///
+  /// Such an `Error` is not thrown again by `throw`/`throw_into`, the pending exception propagates as is.
+  pub fn is_pending_exception(&self) -> bool {
+    self.status == Status::PendingException
+  }
+
+  /// Fill in an empty reason from `napi_get_last_error_info`, if the last Node-API error has the same status.
+  ///
+  /// `#[napi]` functions call it before throwing, so an `Error` propagated from a bare `check_status!`
+  /// isn't thrown with an empty message. Reasons set by the user are never replaced.
+  #[doc(hidden)]
+  pub unsafe fn with_last_error_info(mut self, env: sys::napi_env) -> Self {
+    if !self.reason.is_empty() || !self.maybe_raw.is_null() || self.is_pending_exception() {
+      return self;
     }
+    let mut error_info = ptr::null();
+    let status = unsafe { sys::napi_get_last_error_info(env, &mut error_info) };
+    if status == sys::Status::napi_ok && !error_info.is_null() {
+      let error_info = unsafe { &*error_info };
+      if Status::from(error_info.error_code) == self.status && !error_info.error_message.is_null() {
+        self.reason = unsafe { CStr::from_ptr(error_info.error_message) }
+          .to_string_lossy()
+          .into_owned();
+      }
+    }
+    self
+  }
+
+  /// Append a `key=value` pair to the reason, it shows up in the `message` of the thrown JavaScript error.
+  ///
+  /// Pairs are separated from the reason and from each other by `, `,
+  /// e.g. `Failed to read config, path=config.toml, line=3`.
+  pub fn context(mut self, key: &str, value: impl fmt::Display) -> Self {
+    if !self.reason.is_empty() {
+      self.reason.push_str(", ");
+    }
+    self.reason.push_str(&format!("{}={}", key, value));
+    self
+  }
+
+  /// Set a property on the JavaScript error object, e.g. `err.retryable` or `err.statusCode`.
+  ///
+  /// The value is converted when the JavaScript error is created, it isn't set on errors
+  /// converted from JavaScript values, which are thrown as is.
+  pub fn with_property<V>(mut self, key: impl Into<String>, value: V) -> Self
+  where
+    V: 'static + ToNapiValue + Clone + Send + Sync,
+  {
+    self.properties.push(ErrorProperty {
+      key: key.into(),
+      value: Arc::new(move |env| unsafe { V::to_napi_value(env, value.clone()) }),
+    });
+    self
+  }
+
+  /// The Rust backtrace captured while creating this `Error`.
+  ///
+  /// It's only captured if `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` is set.
+  #[cfg(feature = "backtrace")]
+  #[clippy::msrv = "1.65"]
+  pub fn backtrace(&self) -> Option<&Backtrace> {
+    self.backtrace.as_deref()
+  }
+
+  /// Downcast the original `anyhow::Error` this `Error` was converted from, see [`anyhow::Error::downcast_ref`].
+  ///
+  /// Returns `None` if this `Error` wasn't created from an `anyhow::Error` or the type doesn't match.
+  #[cfg(feature = "anyhow")]
+  pub fn downcast_ref<E>(&self) -> Option<&E>
+  where
+    E: fmt::Display + fmt::Debug + Send + Sync + 'static,
+  {
+    self.anyhow_error.as_ref()?.downcast_ref::<E>()
+  }
+
+  /// Iterate the cause chain of the original `anyhow::Error`, see [`anyhow::Error::chain`].
+  ///
+  /// It's empty if this `Error` wasn't created from an `anyhow::Error`.
+  #[cfg(feature = "anyhow")]
+  pub fn chain(&self) -> impl Iterator<Item = &(dyn error::Error + 'static)> {
+    self.anyhow_error.iter().flat_map(|e| e.chain())
   }
 }
 
+// `std::ffi::NulError` is stable since 1.0, clippy checks the 1.64 `alloc::ffi` item it re-exports now
+#[allow(clippy::incompatible_msrv)]
 impl From<std::ffi::NulError> for Error {
   fn from(error: std::ffi::NulError) -> Self {
-    Error {
-      status: Status::GenericFailure,
-      reason: format!("{}", error),
-      maybe_raw: ptr::null_mut(),
-      maybe_env: ptr::null_mut(),
-    }
+    Error::new(Status::GenericFailure, format!("{}", error))
   }
 }
 
 impl From<std::io::Error> for Error {
   fn from(error: std::io::Error) -> Self {
-    Error {
-      status: Status::GenericFailure,
-      reason: format!("{}", error),
-      maybe_raw: ptr::null_mut(),
-      maybe_env: ptr::null_mut(),
-    }
+    Error::new(Status::GenericFailure, format!("{}", error))
+  }
+}
+
+impl From<std::num::ParseIntError> for Error {
+  fn from(error: std::num::ParseIntError) -> Self {
+    Error::new(Status::InvalidArg, format!("{}", error))
+  }
+}
+
+impl From<std::num::ParseFloatError> for Error {
+  fn from(error: std::num::ParseFloatError) -> Self {
+    Error::new(Status::InvalidArg, format!("{}", error))
+  }
+}
+
+impl From<std::str::Utf8Error> for Error {
+  fn from(error: std::str::Utf8Error) -> Self {
+    Error::new(Status::InvalidArg, format!("{}", error))
+  }
+}
+
+impl From<std::string::FromUtf8Error> for Error {
+  fn from(error: std::string::FromUtf8Error) -> Self {
+    Error::new(Status::InvalidArg, format!("{}", error))
   }
 }
 
@@ -201,6 +331,112 @@ impl TryFrom<sys::napi_extended_error_info> for ExtendedErrorInfo {
   }
 }
 
+#[cfg(feature = "backtrace")]
+#[clippy::msrv = "1.65"]
+fn capture_backtrace() -> Option<Arc<Backtrace>> {
+  let backtrace = Backtrace::capture();
+  if backtrace.status() == BacktraceStatus::Captured {
+    Some(Arc::new(backtrace))
+  } else {
+    None
+  }
+}
+
+/// Attach the captured Rust backtrace as `rustStack` property of the JavaScript error
+#[cfg(feature = "backtrace")]
+unsafe fn set_rust_stack(env: sys::napi_env, js_error: sys::napi_value, err: &Error) {
+  if let Some(backtrace) = err.backtrace() {
+    let rust_stack = backtrace.to_string();
+    let mut rust_stack_value = ptr::null_mut();
+    let create_stack_status = unsafe {
+      sys::napi_create_string_utf8(
+        env,
+        rust_stack.as_ptr() as *const c_char,
+        rust_stack.len(),
+        &mut rust_stack_value,
+      )
+    };
+    debug_assert!(create_stack_status == sys::Status::napi_ok);
+    let set_stack_status = unsafe {
+      sys::napi_set_named_property(
+        env,
+        js_error,
+        "rustStack\0".as_ptr() as *const c_char,
+        rust_stack_value,
+      )
+    };
+    debug_assert!(set_stack_status == sys::Status::napi_ok);
+  }
+}
+
+/// Set the properties added with `Error::with_property` on the JavaScript error
+unsafe fn set_properties(env: sys::napi_env, js_error: sys::napi_value, err: &Error) -> Result<()> {
+  for property in err.properties.iter() {
+    let key = CString::new(property.key.as_str())?;
+    let value = (property.value)(env)?;
+    check_status!(
+      unsafe { sys::napi_set_named_property(env, js_error, key.as_ptr(), value) },
+      "Failed to set property `{}` on Error",
+      property.key
+    )?;
+  }
+  Ok(())
+}
+
+/// Prepend `reason` to the `message` of a JavaScript error, and to the first line of its `stack`
+/// if that's still `{name}: {message}`.
+///
+/// It's best effort, the error is left as is if it's frozen or its properties can't be read.
+pub(crate) unsafe fn prepend_reason(env: sys::napi_env, js_error: sys::napi_value, reason: &str) {
+  if unsafe { try_prepend_reason(env, js_error, reason) }.is_err() {
+    // e.g. thrown by a getter, the original error is rethrown instead
+    let mut exception = ptr::null_mut();
+    unsafe { sys::napi_get_and_clear_last_exception(env, &mut exception) };
+  }
+}
+
+unsafe fn try_prepend_reason(
+  env: sys::napi_env,
+  js_error: sys::napi_value,
+  reason: &str,
+) -> Result<()> {
+  if reason.is_empty() || type_of!(env, js_error)? != ValueType::Object {
+    return Ok(());
+  }
+  let env = Env::from(env);
+  let mut error = unsafe { JsObject::from_raw_unchecked(env.0, js_error) };
+  let read_string = |error: &JsObject, key: &str| -> Result<String> {
+    error
+      .get_named_property_unchecked::<JsUnknown>(key)?
+      .coerce_to_string()?
+      .into_utf8()?
+      .into_owned()
+  };
+  let name = read_string(&error, "name")?;
+  let message = read_string(&error, "message")?;
+  // read before changing `message`, V8 formats the `stack` lazily on its first access
+  let stack = error.get_named_property_unchecked::<JsUnknown>("stack")?;
+  let stack = if stack.get_type()? == ValueType::String {
+    Some(stack.coerce_to_string()?.into_utf8()?.into_owned()?)
+  } else {
+    None
+  };
+  let new_message = format!("{}: {}", reason, message);
+  error.set_named_property("message", env.create_string_from_std(new_message.clone())?)?;
+  if let Some(stack) = stack {
+    let header = if message.is_empty() {
+      name.clone()
+    } else {
+      format!("{}: {}", name, message)
+    };
+    if let Some(rest) = stack.strip_prefix(&header) {
+      let stack = format!("{}: {}{}", name, new_message, rest);
+      error.set_named_property("stack", env.create_string_from_std(stack)?)?;
+    }
+  }
+  Ok(())
+}
+
 pub struct JsError(Error);
 
 #[cfg(feature = "anyhow")]
@@ -253,6 +489,15 @@ macro_rules! impl_object_methods {
         debug_assert!(create_reason_status == sys::Status::napi_ok);
         let create_error_status = unsafe { $kind(env, error_code, reason_string, &mut js_error) };
         debug_assert!(create_error_status == sys::Status::napi_ok);
+        #[cfg(feature = "backtrace")]
+        unsafe {
+          set_rust_stack(env, js_error, &self.0)
+        };
+        let set_properties_status = unsafe { set_properties(env, js_error, &self.0) };
+        debug_assert!(
+          set_properties_status.is_ok(),
+          "Set properties on Error failed"
+        );
         js_error
       }
 
@@ -268,7 +513,7 @@ macro_rules! impl_object_methods {
         #[cfg(debug_assertions)]
         let reason = self.0.reason.clone();
         let status = self.0.status;
-        if status == Status::PendingException {
+        if self.0.is_pending_exception() {
           return;
         }
         let js_error = unsafe { self.into_value(env) };
@@ -287,6 +532,9 @@ macro_rules! impl_object_methods {
 
       #[allow(clippy::not_unsafe_ptr_arg_deref)]
       pub fn throw(&self, env: sys::napi_env) -> Result<()> {
+        if self.0.is_pending_exception() {
+          return Ok(());
+        }
         let error_status = format!("{:?}\0", self.0.status);
         let status_len = error_status.len();
         let error_code_string =
@@ -304,6 +552,11 @@ macro_rules! impl_object_methods {
           sys::napi_create_string_utf8(env, reason.as_ptr(), reason_len, &mut reason_string)
         })?;
         check_status!(unsafe { $kind(env, error_code, reason_string, &mut js_error) })?;
+        #[cfg(feature = "backtrace")]
+        unsafe {
+          set_rust_stack(env, js_error, &self.0)
+        };
+        unsafe { set_properties(env, js_error, &self.0)? };
         check_status!(unsafe { sys::napi_throw(env, js_error) })
       }
     }
@@ -122,6 +122,55 @@ impl From<sys::napi_typedarray_type> for TypedArrayType {
   }
 }
 
+impl TypedArrayType {
+  fn element_size(&self) -> Option<usize> {
+    match self {
+      Self::Int8 | Self::Uint8 | Self::Uint8Clamped => Some(1),
+      Self::Int16 | Self::Uint16 => Some(2),
+      Self::Int32 | Self::Uint32 | Self::Float32 => Some(4),
+      Self::Float64 => Some(8),
+      #[cfg(feature = "napi6")]
+      Self::BigInt64 | Self::BigUint64 => Some(8),
+      Self::Unknown => None,
+    }
+  }
+}
+
+/// Rust element types which a `TypedArray` can view without conversion, see [`Env::create_external_arraybuffer`](crate::Env::create_external_arraybuffer).
+pub trait TypedArrayElement: __sealed::TypedArrayElement + Copy + 'static {
+  const TYPED_ARRAY_TYPE: TypedArrayType;
+}
+
+mod __sealed {
+  pub trait TypedArrayElement {}
+}
+
+macro_rules! impl_typed_array_element {
+  ($($rust_type:ident => $typed_array_type:ident),* $(,)?) => {
+    $(
+      impl __sealed::TypedArrayElement for $rust_type {}
+
+      impl TypedArrayElement for $rust_type {
+        const TYPED_ARRAY_TYPE: TypedArrayType = TypedArrayType::$typed_array_type;
+      }
+    )*
+  };
+}
+
+impl_typed_array_element!(
+  i8 => Int8,
+  u8 => Uint8,
+  i16 => Int16,
+  u16 => Uint16,
+  i32 => Int32,
+  u32 => Uint32,
+  f32 => Float32,
+  f64 => Float64,
+);
+
+#[cfg(feature = "napi6")]
+impl_typed_array_element!(i64 => BigInt64, u64 => BigUint64);
+
 impl From<TypedArrayType> for sys::napi_typedarray_type {
   fn from(value: TypedArrayType) -> sys::napi_typedarray_type {
     value as i32
@@ -143,7 +192,18 @@ impl JsArrayBuffer {
     Ok(is_detached)
   }
 
+  /// get ArrayBuffer info
+  /// <https://nodejs.org/api/n-api.html#n_api_napi_get_arraybuffer_info>
+  ///
+  /// Returns an error if the `ArrayBuffer` has been detached.
   pub fn into_value(self) -> Result<JsArrayBufferValue> {
+    #[cfg(feature = "napi7")]
+    if self.is_detached()? {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "ArrayBuffer is detached".to_owned(),
+      ));
+    }
     let mut data = ptr::null_mut();
     let mut len: usize = 0;
     check_status!(unsafe {
@@ -215,17 +275,47 @@ impl JsArrayBufferValue {
   pub fn into_unknown(self) -> JsUnknown {
     unsafe { JsUnknown::from_raw_unchecked(self.value.0.env, self.value.0.value) }
   }
+
+  /// Raw pointer to the underlying data buffer of the `ArrayBuffer`.
+  ///
+  /// ***Warning***: the buffer is managed by the VM, the pointer may be null for an empty `ArrayBuffer`.
+  pub fn data(&self) -> *mut u8 {
+    self.data as *mut u8
+  }
+
+  /// Length in bytes of the underlying data buffer.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub fn as_slice(&self) -> &[u8] {
+    if self.data.is_null() {
+      return &[];
+    }
+    unsafe { slice::from_raw_parts(self.data as *const u8, self.len) }
+  }
+
+  pub fn as_mut_slice(&mut self) -> &mut [u8] {
+    if self.data.is_null() {
+      return &mut [];
+    }
+    unsafe { slice::from_raw_parts_mut(self.data as *mut u8, self.len) }
+  }
 }
 
 impl AsRef<[u8]> for JsArrayBufferValue {
   fn as_ref(&self) -> &[u8] {
-    unsafe { slice::from_raw_parts(self.data as *const u8, self.len) }
+    self.as_slice()
   }
 }
 
 impl AsMut<[u8]> for JsArrayBufferValue {
   fn as_mut(&mut self) -> &mut [u8] {
-    unsafe { slice::from_raw_parts_mut(self.data as *mut u8, self.len) }
+    self.as_mut_slice()
   }
 }
 
@@ -243,37 +333,83 @@ impl DerefMut for JsArrayBufferValue {
   }
 }
 
+struct TypedArrayInfo {
+  typedarray_type: TypedArrayType,
+  length: usize,
+  data: *mut c_void,
+  arraybuffer: sys::napi_value,
+  byte_offset: usize,
+}
+
 impl JsTypedArray {
-  /// get TypeArray info
-  /// <https://nodejs.org/api/n-api.html#n_api_napi_get_typedarray_info>
-  ///
-  /// ***Warning***: Use caution while using this API since the underlying data buffer is managed by the VM.
-  pub fn into_value(self) -> Result<JsTypedArrayValue> {
+  fn info(&self) -> Result<TypedArrayInfo> {
     let mut typedarray_type = 0;
-    let mut len = 0;
+    let mut length = 0;
     let mut data = ptr::null_mut();
-    let mut arraybuffer_value = ptr::null_mut();
+    let mut arraybuffer = ptr::null_mut();
     let mut byte_offset = 0;
     check_status!(unsafe {
       sys::napi_get_typedarray_info(
         self.0.env,
         self.0.value,
         &mut typedarray_type,
-        &mut len,
+        &mut length,
         &mut data,
-        &mut arraybuffer_value,
+        &mut arraybuffer,
         &mut byte_offset,
       )
     })?;
-
-    Ok(JsTypedArrayValue {
+    Ok(TypedArrayInfo {
+      typedarray_type: typedarray_type.into(),
+      length,
       data,
-      length: len,
+      arraybuffer,
       byte_offset,
-      typedarray_type: typedarray_type.into(),
-      arraybuffer: unsafe { JsArrayBuffer::from_raw_unchecked(self.0.env, arraybuffer_value) },
     })
   }
+
+  /// get TypeArray info
+  /// <https://nodejs.org/api/n-api.html#n_api_napi_get_typedarray_info>
+  ///
+  /// ***Warning***: Use caution while using this API since the underlying data buffer is managed by the VM.
+  pub fn into_value(self) -> Result<JsTypedArrayValue> {
+    let info = self.info()?;
+    Ok(JsTypedArrayValue {
+      data: info.data,
+      length: info.length,
+      byte_offset: info.byte_offset,
+      typedarray_type: info.typedarray_type,
+      arraybuffer: unsafe { JsArrayBuffer::from_raw_unchecked(self.0.env, info.arraybuffer) },
+    })
+  }
+
+  /// The offset in bytes of this view from the start of its backing `ArrayBuffer`
+  pub fn byte_offset(&self) -> Result<usize> {
+    Ok(self.info()?.byte_offset)
+  }
+
+  /// The number of elements in this view
+  pub fn length(&self) -> Result<usize> {
+    Ok(self.info()?.length)
+  }
+
+  /// The length in bytes of this view
+  pub fn byte_length(&self) -> Result<usize> {
+    let info = self.info()?;
+    let element_size = info.typedarray_type.element_size().ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        "Unknown TypedArray type, can't compute its byte length".to_owned(),
+      )
+    })?;
+    Ok(info.length * element_size)
+  }
+
+  /// The `ArrayBuffer` backing this view, it may be shared with other views
+  pub fn arraybuffer(&self) -> Result<JsArrayBuffer> {
+    let info = self.info()?;
+    Ok(unsafe { JsArrayBuffer::from_raw_unchecked(self.0.env, info.arraybuffer) })
+  }
 }
 
 impl JsTypedArrayValue {
@@ -90,6 +90,12 @@ impl JsBigInt {
     Ok(result)
   }
 
+  pub fn is_arraybuffer(&self) -> Result<bool> {
+    let mut result = false;
+    check_status!(unsafe { sys::napi_is_arraybuffer(self.raw.env, self.raw.value, &mut result) })?;
+    Ok(result)
+  }
+
   pub fn is_typedarray(&self) -> Result<bool> {
     let mut result = false;
     check_status!(unsafe { sys::napi_is_typedarray(self.raw.env, self.raw.value, &mut result) })?;
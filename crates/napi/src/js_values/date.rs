@@ -34,6 +34,9 @@ impl ValidateNapiValue for JsDate {
 }
 
 impl JsDate {
+  /// The milliseconds since the Unix epoch, same as `Date.prototype.valueOf()`
+  ///
+  /// <https://nodejs.org/api/n-api.html#napi_get_date_value>
   pub fn value_of(&self) -> Result<f64> {
     let mut timestamp: f64 = 0.0;
     check_status!(unsafe { sys::napi_get_date_value(self.0.env, self.0.value, &mut timestamp) })?;
@@ -3,8 +3,8 @@ use std::ffi::CString;
 use std::ptr;
 
 use crate::{
-  bindgen_runtime::{FromNapiValue, TypeName, ValidateNapiValue},
-  check_status, sys, type_of, Callback, Error, Result, Status, ValueType,
+  bindgen_runtime::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue},
+  check_status, sys, type_of, Callback, Env, Error, Result, Status, ValueType,
 };
 
 #[cfg(feature = "serde-json")]
@@ -28,6 +28,7 @@ mod global;
 mod number;
 mod object;
 mod object_property;
+mod script;
 mod string;
 mod tagged_object;
 mod undefined;
@@ -52,6 +53,7 @@ pub use global::*;
 pub use number::JsNumber;
 pub use object::*;
 pub use object_property::*;
+pub use script::CompiledScript;
 #[cfg(feature = "serde-json")]
 pub(crate) use ser::Ser;
 pub use string::*;
@@ -157,6 +159,17 @@ macro_rules! impl_napi_value_trait {
   };
 }
 
+// Coercion can run JavaScript, e.g. `toString` or `valueOf`, Node-API reports its exception with a type mismatch status
+fn check_coerce_status(env: sys::napi_env, status: sys::napi_status) -> Result<()> {
+  if status == sys::Status::napi_ok {
+    return Ok(());
+  }
+  if let Some(exception) = unsafe { Env::from_raw(env) }.get_and_clear_last_exception()? {
+    return Err(Error::from(exception));
+  }
+  check_status!(status)
+}
+
 macro_rules! impl_js_value_methods {
   ($js_value:ident) => {
     impl $js_value {
@@ -178,7 +191,7 @@ macro_rules! impl_js_value_methods {
 
       pub fn coerce_to_number(self) -> Result<JsNumber> {
         let mut new_raw_value = ptr::null_mut();
-        check_status!(unsafe {
+        check_coerce_status(self.0.env, unsafe {
           sys::napi_coerce_to_number(self.0.env, self.0.value, &mut new_raw_value)
         })?;
         Ok(JsNumber(Value {
@@ -188,9 +201,12 @@ macro_rules! impl_js_value_methods {
         }))
       }
 
+      /// Same as `String(value)` in JavaScript, e.g. objects go through their `toString` method.
+      ///
+      /// Returns the thrown exception as an error for `Symbol`s or if `toString` throws.
       pub fn coerce_to_string(self) -> Result<JsString> {
         let mut new_raw_value = ptr::null_mut();
-        check_status!(unsafe {
+        check_coerce_status(self.0.env, unsafe {
           sys::napi_coerce_to_string(self.0.env, self.0.value, &mut new_raw_value)
         })?;
         Ok(JsString(Value {
@@ -202,7 +218,7 @@ macro_rules! impl_js_value_methods {
 
       pub fn coerce_to_object(self) -> Result<JsObject> {
         let mut new_raw_value = ptr::null_mut();
-        check_status!(unsafe {
+        check_coerce_status(self.0.env, unsafe {
           sys::napi_coerce_to_object(self.0.env, self.0.value, &mut new_raw_value)
         })?;
         Ok(JsObject(Value {
@@ -231,6 +247,12 @@ macro_rules! impl_js_value_methods {
         Ok(result)
       }
 
+      pub fn is_arraybuffer(&self) -> Result<bool> {
+        let mut result = false;
+        check_status!(unsafe { sys::napi_is_arraybuffer(self.0.env, self.0.value, &mut result) })?;
+        Ok(result)
+      }
+
       pub fn is_typedarray(&self) -> Result<bool> {
         let mut result = false;
         check_status!(unsafe { sys::napi_is_typedarray(self.0.env, self.0.value, &mut result) })?;
@@ -249,6 +271,9 @@ macro_rules! impl_js_value_methods {
         Ok(is_array)
       }
 
+      /// Node.js reports every `ArrayBufferView` as a buffer, including plain `Uint8Array`s and `DataView`s.
+      ///
+      /// Use `JsUnknown::is_node_buffer` to check for a Node.js `Buffer` specifically.
       pub fn is_buffer(&self) -> Result<bool> {
         let mut is_buffer = false;
         check_status!(unsafe { sys::napi_is_buffer(self.0.env, self.0.value, &mut is_buffer) })?;
@@ -335,16 +360,39 @@ macro_rules! impl_object_methods {
         })
       }
 
+      /// Get the property `name`, validate and convert it into `T`.
+      ///
+      /// If the validation or the conversion fails, the error tells apart a property that doesn't exist
+      /// from one holding a value of another type, and includes the property name.
+      /// Use [`get_named_property_unchecked`](Self::get_named_property_unchecked) to skip these checks.
       pub fn get_named_property<T>(&self, name: &str) -> Result<T>
       where
-        T: FromNapiValue,
+        T: FromNapiValue + ValidateNapiValue,
       {
         let key = CString::new(name)?;
         let mut raw_value = ptr::null_mut();
         check_status!(unsafe {
           sys::napi_get_named_property(self.0.env, self.0.value, key.as_ptr(), &mut raw_value)
         })?;
-        unsafe { <T as FromNapiValue>::from_napi_value(self.0.env, raw_value) }
+        unsafe { T::validate(self.0.env, raw_value) }
+          .and_then(|_| unsafe { <T as FromNapiValue>::from_napi_value(self.0.env, raw_value) })
+          .map_err(|mut err| {
+            let exists = type_of!(self.0.env, raw_value)
+              .map(|value_type| value_type != ValueType::Undefined)
+              .unwrap_or(true);
+            let context = if !exists && !self.has_named_property(name).unwrap_or(true) {
+              format!("Property `{}` doesn't exist on the object", name)
+            } else {
+              format!("Failed to convert property `{}`", name)
+            };
+            // keep the original error, e.g. the JavaScript error thrown by a getter
+            err.reason = if err.reason.is_empty() {
+              context
+            } else {
+              format!("{}. {}", context, err.reason)
+            };
+            err
+          })
       }
 
       pub fn get_named_property_unchecked<T>(&self, name: &str) -> Result<T>
@@ -450,13 +498,17 @@ macro_rules! impl_object_methods {
 
       /// <https://nodejs.org/api/n-api.html#n_api_napi_get_all_property_names>
       /// return `Array` of property names
+      ///
+      /// Unlike `get_property_names`, inherited, non-enumerable and symbol keys can be included,
+      /// pass a `KeyFilter` or combine them into `KeyFilters` to narrow them down.
       #[cfg(feature = "napi6")]
-      pub fn get_all_property_names(
+      pub fn get_all_property_names<F: Into<KeyFilters>>(
         &self,
         mode: KeyCollectionMode,
-        filter: KeyFilter,
+        filter: F,
         conversion: KeyConversion,
       ) -> Result<JsObject> {
+        let filter: KeyFilters = filter.into();
         let mut properties_value = ptr::null_mut();
         check_status!(unsafe {
           sys::napi_get_all_property_names(
@@ -492,11 +544,10 @@ macro_rules! impl_object_methods {
 
       pub fn set_element<T>(&mut self, index: u32, value: T) -> Result<()>
       where
-        T: NapiRaw,
+        T: ToNapiValue,
       {
-        check_status!(unsafe {
-          sys::napi_set_element(self.0.env, self.0.value, index, value.raw())
-        })
+        let raw_value = unsafe { T::to_napi_value(self.0.env, value)? };
+        check_status!(unsafe { sys::napi_set_element(self.0.env, self.0.value, index, raw_value) })
       }
 
       pub fn has_element(&self, index: u32) -> Result<bool> {
@@ -515,15 +566,19 @@ macro_rules! impl_object_methods {
         Ok(result)
       }
 
+      /// Reading an index without an element returns `undefined`, use `Option<T>` to detect it.
+      ///
+      /// The element is validated before it's converted, an element of another type is an `InvalidArg` error.
       pub fn get_element<T>(&self, index: u32) -> Result<T>
       where
-        T: NapiValue,
+        T: FromNapiValue + ValidateNapiValue,
       {
         let mut raw_value = ptr::null_mut();
         check_status!(unsafe {
           sys::napi_get_element(self.0.env, self.0.value, index, &mut raw_value)
         })?;
-        unsafe { T::from_raw(self.0.env, raw_value) }
+        unsafe { T::validate(self.0.env, raw_value)? };
+        unsafe { T::from_napi_value(self.0.env, raw_value) }
       }
 
       pub fn get_element_unchecked<T>(&self, index: u32) -> Result<T>
@@ -696,4 +751,25 @@ impl JsUnknown {
   {
     unsafe { V::from_raw_unchecked(self.0.env, self.0.value) }
   }
+
+  /// Check whether the value is a Node.js `Buffer`, unlike `is_buffer` this is `false` for plain `Uint8Array`s.
+  pub fn is_node_buffer(&self) -> Result<bool> {
+    Ok(self.is_buffer()? && self.instanceof_named(&Env::from(self.0.env), "Buffer")?)
+  }
+
+  /// Check the value against a global constructor by its name, e.g. `value instanceof Map`.
+  ///
+  /// Returns an error if the global is missing or not a function.
+  pub fn instanceof_named(&self, env: &Env, global_name: &str) -> Result<bool> {
+    let constructor = env
+      .get_global()?
+      .get_named_property::<JsUnknown>(global_name)?;
+    if constructor.get_type()? != ValueType::Function {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("`{}` is not a global constructor", global_name),
+      ));
+    }
+    self.instanceof(constructor)
+  }
 }
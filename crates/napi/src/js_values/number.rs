@@ -44,6 +44,11 @@ impl JsNumber {
     check_status!(unsafe { sys::napi_get_value_double(self.0.env, self.0.value, &mut result) })?;
     Ok(result)
   }
+
+  /// Get the raw IEEE 754 bit pattern of the number, the sign of `-0` and `NaN` payloads included.
+  pub fn to_bits(&self) -> Result<u64> {
+    self.get_double().map(f64::to_bits)
+  }
 }
 
 impl TryFrom<JsNumber> for u32 {
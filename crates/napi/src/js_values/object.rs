@@ -5,6 +5,9 @@ use std::ffi::c_void;
 #[cfg(feature = "napi5")]
 use std::ptr;
 
+#[cfg(feature = "napi6")]
+use bitflags::bitflags;
+
 #[cfg(feature = "napi5")]
 use super::check_status;
 use super::Value;
@@ -163,6 +166,57 @@ impl From<KeyFilter> for sys::napi_key_filter {
   }
 }
 
+#[cfg(feature = "napi6")]
+bitflags! {
+  /// Combined `KeyFilter`s of `get_all_property_names`,
+  /// e.g. `KeyFilter::Enumerable | KeyFilter::SkipSymbols` for the keys `for...in` visits.
+  pub struct KeyFilters: i32 {
+    const AllProperties = sys::KeyFilter::all_properties;
+    const Writable = sys::KeyFilter::writable;
+    const Enumerable = sys::KeyFilter::enumerable;
+    const Configurable = sys::KeyFilter::configurable;
+    const SkipStrings = sys::KeyFilter::skip_strings;
+    const SkipSymbols = sys::KeyFilter::skip_symbols;
+  }
+}
+
+#[cfg(feature = "napi6")]
+impl From<KeyFilter> for KeyFilters {
+  fn from(value: KeyFilter) -> Self {
+    Self::from_bits_truncate(value.into())
+  }
+}
+
+#[cfg(feature = "napi6")]
+impl TryFrom<sys::napi_key_filter> for KeyFilters {
+  type Error = Error;
+
+  fn try_from(value: sys::napi_key_filter) -> Result<Self> {
+    Self::from_bits(value).ok_or_else(|| {
+      Error::new(
+        crate::Status::InvalidArg,
+        format!("Invalid key filters [{}]", value),
+      )
+    })
+  }
+}
+
+#[cfg(feature = "napi6")]
+impl From<KeyFilters> for sys::napi_key_filter {
+  fn from(value: KeyFilters) -> Self {
+    value.bits()
+  }
+}
+
+#[cfg(feature = "napi6")]
+impl<T: Into<KeyFilters>> std::ops::BitOr<T> for KeyFilter {
+  type Output = KeyFilters;
+
+  fn bitor(self, rhs: T) -> KeyFilters {
+    KeyFilters::from(self) | rhs.into()
+  }
+}
+
 #[cfg(feature = "napi6")]
 pub enum KeyConversion {
   KeepNumbers,
@@ -12,7 +12,7 @@ pub struct Property {
   getter: sys::napi_callback,
   setter: sys::napi_callback,
   method: sys::napi_callback,
-  attrs: PropertyAttributes,
+  pub(crate) attrs: PropertyAttributes,
   value: sys::napi_value,
   pub(crate) is_ctor: bool,
 }
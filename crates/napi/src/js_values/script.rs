@@ -0,0 +1,50 @@
+use std::ptr;
+
+use crate::{
+  bindgen_runtime::FromNapiValue, check_pending_exception, sys, Env, JsFunction, NapiRaw, Ref,
+  Result,
+};
+
+/// A script compiled once by [`Env::compile_script`](crate::Env::compile_script), which can be run many times
+/// without paying for parsing and compiling the source again.
+///
+/// The compiled script is kept alive by a reference, which is released when the `CompiledScript` is dropped.
+/// It must be dropped on the JavaScript thread it was compiled on.
+pub struct CompiledScript {
+  env: sys::napi_env,
+  func: Ref<()>,
+}
+
+impl CompiledScript {
+  pub(crate) fn new(env: &Env, func: JsFunction) -> Result<Self> {
+    Ok(Self {
+      env: env.0,
+      func: env.create_reference(func)?,
+    })
+  }
+
+  /// Run the compiled script with the global object as `this`, and convert its return value into `V`
+  pub fn run<V: FromNapiValue>(&self, env: &Env) -> Result<V> {
+    let func = env.get_reference_value_unchecked::<JsFunction>(&self.func)?;
+    let global = env.get_global()?;
+    let mut raw_value = ptr::null_mut();
+    check_pending_exception!(env.0, unsafe {
+      sys::napi_call_function(
+        env.0,
+        global.raw(),
+        func.raw(),
+        0,
+        ptr::null(),
+        &mut raw_value,
+      )
+    })?;
+    unsafe { V::from_napi_value(env.0, raw_value) }
+  }
+}
+
+impl Drop for CompiledScript {
+  fn drop(&mut self) {
+    // `Drop` can't fail, the reference is leaked if it can't be released
+    let _ = self.func.unref(Env::from(self.env));
+  }
+}
@@ -54,6 +54,11 @@ impl JsString {
     Ok(length)
   }
 
+  /// Whether the string is empty, only its length is queried and the string isn't copied
+  pub fn is_empty(&self) -> Result<bool> {
+    Ok(self.utf16_len()? == 0)
+  }
+
   pub fn into_utf8(self) -> Result<JsStringUtf8> {
     let mut written_char_count = 0;
     let len = self.utf8_len()? + 1;
@@ -96,6 +101,29 @@ impl JsString {
     })
   }
 
+  /// Iterate the Unicode scalar values of the string, lone surrogates are yielded as `U+FFFD`.
+  ///
+  /// Node-API can only copy a string from its start, so the UTF-16 code units are copied once
+  /// and decoded lazily, surrogate pairs are combined into a single `char`.
+  /// No `String` is built, which skips the UTF-8 transcoding of `into_utf8`.
+  pub fn chars(&self) -> Result<impl Iterator<Item = char>> {
+    let mut written_char_count = 0usize;
+    let len = self.utf16_len()?;
+    let mut buf = vec![0u16; len + 1];
+    check_status!(unsafe {
+      sys::napi_get_value_string_utf16(
+        self.0.env,
+        self.0.value,
+        buf.as_mut_ptr(),
+        len + 1,
+        &mut written_char_count,
+      )
+    })?;
+    buf.truncate(written_char_count);
+
+    Ok(char::decode_utf16(buf).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)))
+  }
+
   pub fn into_latin1(self) -> Result<JsStringLatin1> {
     let mut written_char_count = 0usize;
     let len = self.latin1_len()? + 1;
@@ -2,7 +2,7 @@ use std::ops::Deref;
 use std::ptr;
 
 use super::{check_status, Value};
-use crate::{sys, Env, Result};
+use crate::{sys, Env, Error, NapiValue, Result, Status};
 
 pub struct Ref<T> {
   pub(crate) raw_ref: sys::napi_ref,
@@ -31,11 +31,13 @@ impl<T> Ref<T> {
   }
 
   pub fn reference(&mut self, env: &Env) -> Result<u32> {
+    self.check_alive()?;
     check_status!(unsafe { sys::napi_reference_ref(env.0, self.raw_ref, &mut self.count) })?;
     Ok(self.count)
   }
 
   pub fn unref(&mut self, env: Env) -> Result<u32> {
+    self.check_alive()?;
     check_status!(unsafe { sys::napi_reference_unref(env.0, self.raw_ref, &mut self.count) })?;
 
     if self.count == 0 {
@@ -43,6 +45,29 @@ impl<T> Ref<T> {
     }
     Ok(self.count)
   }
+
+  /// The reference is deleted once `unref` brings the count down to 0
+  fn check_alive(&self) -> Result<()> {
+    if self.count == 0 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Ref is already deleted, its count was brought down to 0 by `unref`".to_owned(),
+      ));
+    }
+    Ok(())
+  }
+}
+
+impl<T: NapiValue> Ref<T> {
+  /// Get the referenced value, the `Deref`ed value is only valid in the scope the `Ref` was created in.
+  ///
+  /// It's an `InvalidArg` error once `unref` has deleted the reference.
+  pub fn get_value(&self, env: &Env) -> Result<T> {
+    self.check_alive()?;
+    let mut js_value = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_reference_value(env.0, self.raw_ref, &mut js_value) })?;
+    unsafe { T::from_raw(env.0, js_value) }
+  }
 }
 
 impl<T> Deref for Ref<T> {
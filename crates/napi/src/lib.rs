@@ -175,7 +175,8 @@ pub mod bindgen_prelude {
 #[doc(hidden)]
 pub mod __private {
   pub use crate::bindgen_runtime::{
-    get_class_constructor, iterator::create_iterator, register_class, ___CALL_FROM_FACTORY,
+    get_class_constructor, iterator::create_iterator, register_class, register_class_cleanup,
+    register_class_extends, ___CALL_FROM_FACTORY,
   };
 
   use crate::sys;
@@ -1,15 +1,17 @@
 #![allow(clippy::single_component_path_imports)]
 
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::BinaryHeap;
 use std::convert::Into;
 use std::ffi::CString;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 use std::ptr;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 
 use crate::bindgen_runtime::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
-use crate::{check_status, sys, Env, JsError, JsUnknown, Result, Status};
+use crate::{check_status, sys, Env, JsError, JsObject, JsUnknown, NapiRaw, Result, Status};
 
 /// ThreadSafeFunction Context object
 /// the `value` is the value passed to `call` method
@@ -130,6 +132,93 @@ enum ThreadsafeFunctionCallVariant {
   WithCallback,
 }
 
+/// Calls made with `call_with_priority` wait here, `call_js_cb` receives a null `data` for each of them
+/// and takes the one with the highest priority at that time instead.
+struct PriorityQueue {
+  heap: Mutex<BinaryHeap<PrioritizedCall>>,
+  next_order: AtomicU64,
+}
+
+impl PriorityQueue {
+  fn push(&self, priority: u32, data: *mut c_void) -> u64 {
+    let order = self.next_order.fetch_add(1, Ordering::Relaxed);
+    self
+      .heap
+      .lock()
+      .expect("Threadsafe Function priority queue lock failed")
+      .push(PrioritizedCall {
+        priority,
+        order,
+        data,
+      });
+    order
+  }
+
+  fn pop(&self) -> Option<*mut c_void> {
+    self
+      .heap
+      .lock()
+      .expect("Threadsafe Function priority queue lock failed")
+      .pop()
+      .map(|call| call.data)
+  }
+
+  fn remove(&self, order: u64) -> Option<*mut c_void> {
+    let mut heap = self
+      .heap
+      .lock()
+      .expect("Threadsafe Function priority queue lock failed");
+    let (removed, rest): (Vec<_>, Vec<_>) = heap.drain().partition(|call| call.order == order);
+    *heap = BinaryHeap::from(rest);
+    removed.into_iter().next().map(|call| call.data)
+  }
+}
+
+unsafe impl Send for PriorityQueue {}
+unsafe impl Sync for PriorityQueue {}
+
+struct PrioritizedCall {
+  priority: u32,
+  order: u64,
+  data: *mut c_void,
+}
+
+impl PartialEq for PrioritizedCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == CmpOrdering::Equal
+  }
+}
+
+impl Eq for PrioritizedCall {}
+
+impl PartialOrd for PrioritizedCall {
+  fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for PrioritizedCall {
+  // Higher priority first, then first in first out
+  fn cmp(&self, other: &Self) -> CmpOrdering {
+    self
+      .priority
+      .cmp(&other.priority)
+      .then_with(|| other.order.cmp(&self.order))
+  }
+}
+
+/// Called on the JavaScript thread with the exception thrown by the JavaScript callback
+type ErrorHandler = Box<dyn Fn(crate::Error) + Send>;
+
+/// `this` and arguments of the JavaScript callback
+pub(crate) type CallJsArgs<V> = (Option<JsObject>, Vec<V>);
+
+struct ThreadsafeFunctionContext<R> {
+  callback: R,
+  priority_queue: Arc<PriorityQueue>,
+  on_error: Option<ErrorHandler>,
+}
+
 struct ThreadsafeFunctionCallJsBackData<T> {
   data: T,
   call_variant: ThreadsafeFunctionCallVariant,
@@ -187,6 +276,7 @@ struct ThreadsafeFunctionCallJsBackData<T> {
 /// ```
 pub struct ThreadsafeFunction<T: 'static, ES: ErrorStrategy::T = ErrorStrategy::CalleeHandled> {
   handle: Arc<ThreadsafeFunctionHandle>,
+  priority_queue: Arc<PriorityQueue>,
   _phantom: PhantomData<(T, ES)>,
 }
 
@@ -203,6 +293,7 @@ impl<T: 'static, ES: ErrorStrategy::T> Clone for ThreadsafeFunction<T, ES> {
 
     Self {
       handle: self.handle.clone(),
+      priority_queue: self.priority_queue.clone(),
       _phantom: PhantomData,
     }
   }
@@ -225,6 +316,40 @@ impl<T: 'static, ES: ErrorStrategy::T> ThreadsafeFunction<T, ES> {
     func: sys::napi_value,
     max_queue_size: usize,
     callback: R,
+  ) -> Result<Self> {
+    Self::create_with_error_handler(env, func, max_queue_size, callback, None)
+  }
+
+  pub(crate) fn create_with_error_handler<
+    V: ToNapiValue,
+    R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<Vec<V>>,
+  >(
+    env: sys::napi_env,
+    func: sys::napi_value,
+    max_queue_size: usize,
+    mut callback: R,
+    on_error: Option<ErrorHandler>,
+  ) -> Result<Self> {
+    Self::create_with_receiver(
+      env,
+      func,
+      max_queue_size,
+      move |ctx| callback(ctx).map(|args| (None, args)),
+      on_error,
+    )
+  }
+
+  /// The `callback` returns the `this` of the JavaScript function call along with the arguments,
+  /// `None` calls it with `undefined` as `this`.
+  pub(crate) fn create_with_receiver<
+    V: ToNapiValue,
+    R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<CallJsArgs<V>>,
+  >(
+    env: sys::napi_env,
+    func: sys::napi_value,
+    max_queue_size: usize,
+    callback: R,
+    on_error: Option<ErrorHandler>,
   ) -> Result<Self> {
     let mut async_resource_name = ptr::null_mut();
     let s = "napi_rs_threadsafe_function";
@@ -235,7 +360,15 @@ impl<T: 'static, ES: ErrorStrategy::T> ThreadsafeFunction<T, ES> {
     })?;
 
     let mut raw_tsfn = ptr::null_mut();
-    let callback_ptr = Box::into_raw(Box::new(callback));
+    let priority_queue = Arc::new(PriorityQueue {
+      heap: Mutex::new(BinaryHeap::new()),
+      next_order: AtomicU64::new(0),
+    });
+    let callback_ptr = Box::into_raw(Box::new(ThreadsafeFunctionContext {
+      callback,
+      priority_queue: priority_queue.clone(),
+      on_error,
+    }));
     check_status!(unsafe {
       sys::napi_create_threadsafe_function(
         env,
@@ -245,7 +378,7 @@ impl<T: 'static, ES: ErrorStrategy::T> ThreadsafeFunction<T, ES> {
         max_queue_size,
         1,
         ptr::null_mut(),
-        Some(thread_finalize_cb::<T, V, R>),
+        Some(thread_finalize_cb::<T, V, R, ES>),
         callback_ptr.cast(),
         Some(call_js_cb::<T, V, R, ES>),
         &mut raw_tsfn,
@@ -258,6 +391,7 @@ impl<T: 'static, ES: ErrorStrategy::T> ThreadsafeFunction<T, ES> {
         aborted: RwLock::new(false),
         referred: AtomicBool::new(true),
       }),
+      priority_queue,
       _phantom: PhantomData,
     })
   }
@@ -325,6 +459,27 @@ impl<T: 'static, ES: ErrorStrategy::T> ThreadsafeFunction<T, ES> {
   pub fn raw(&self) -> sys::napi_threadsafe_function {
     self.handle.raw
   }
+
+  fn call_prioritized(
+    &self,
+    data: *mut c_void,
+    priority: u32,
+    mode: ThreadsafeFunctionCallMode,
+  ) -> std::result::Result<(), (sys::napi_status, *mut c_void)> {
+    let order = self.priority_queue.push(priority, data);
+    let status =
+      unsafe { sys::napi_call_threadsafe_function(self.handle.raw, ptr::null_mut(), mode.into()) };
+    if status == sys::Status::napi_ok {
+      return Ok(());
+    }
+    // A pending call may have delivered it already, it's then reported as queued so that
+    // retrying on error never delivers a value twice. The value that pending call left in the queue
+    // is taken by the next call or dropped on finalize.
+    match self.priority_queue.remove(order) {
+      Some(data) => Err((status, data)),
+      None => Ok(()),
+    }
+  }
 }
 
 impl<T: 'static> ThreadsafeFunction<T, ErrorStrategy::CalleeHandled> {
@@ -348,6 +503,62 @@ impl<T: 'static> ThreadsafeFunction<T, ErrorStrategy::CalleeHandled> {
     .into()
   }
 
+  /// Call the JavaScript function in `NonBlocking` mode without waiting for the queue.
+  ///
+  /// The value is handed back if the queue is full or the function is closing,
+  /// so that the caller can decide dropping or retrying it.
+  pub fn try_call(&self, value: Result<T>) -> std::result::Result<(), Result<T>> {
+    let data = Box::into_raw(Box::new(value.map(|data| {
+      ThreadsafeFunctionCallJsBackData {
+        data,
+        call_variant: ThreadsafeFunctionCallVariant::Direct,
+        callback: Box::new(|_d: JsUnknown| Ok(())),
+      }
+    })));
+    let status = unsafe {
+      sys::napi_call_threadsafe_function(
+        self.handle.raw,
+        data.cast(),
+        ThreadsafeFunctionCallMode::NonBlocking.into(),
+      )
+    };
+    if status == sys::Status::napi_ok {
+      Ok(())
+    } else {
+      let value = unsafe { Box::from_raw(data) };
+      Err(value.map(|v| v.data))
+    }
+  }
+
+  /// Same as `call`, but values with a higher `priority` are delivered before the queued values with a lower one.
+  ///
+  /// Node-API has no priorities, so this is best-effort:
+  /// the order only applies to calls which are still waiting when the JavaScript thread gets to them,
+  /// and calls made with `call` are not reordered.
+  /// Values with the same priority are delivered in call order.
+  /// If an error status is returned, the value is dropped without being delivered, so it's safe to retry.
+  pub fn call_with_priority(
+    &self,
+    value: Result<T>,
+    priority: u32,
+    mode: ThreadsafeFunctionCallMode,
+  ) -> Status {
+    let data = Box::into_raw(Box::new(value.map(|data| {
+      ThreadsafeFunctionCallJsBackData {
+        data,
+        call_variant: ThreadsafeFunctionCallVariant::Direct,
+        callback: Box::new(|_d: JsUnknown| Ok(())),
+      }
+    })));
+    match self.call_prioritized(data.cast(), priority, mode) {
+      Ok(()) => Status::Ok,
+      Err((status, data)) => {
+        drop(unsafe { Box::<Result<ThreadsafeFunctionCallJsBackData<T>>>::from_raw(data.cast()) });
+        status.into()
+      }
+    }
+  }
+
   pub fn call_with_return_value<D: FromNapiValue, F: 'static + FnOnce(D) -> Result<()>>(
     &self,
     value: Result<T>,
@@ -421,6 +632,57 @@ impl<T: 'static> ThreadsafeFunction<T, ErrorStrategy::Fatal> {
     .into()
   }
 
+  /// Call the JavaScript function in `NonBlocking` mode without waiting for the queue.
+  ///
+  /// The value is handed back if the queue is full or the function is closing,
+  /// so that the caller can decide dropping or retrying it.
+  pub fn try_call(&self, value: T) -> std::result::Result<(), T> {
+    let data = Box::into_raw(Box::new(ThreadsafeFunctionCallJsBackData {
+      data: value,
+      call_variant: ThreadsafeFunctionCallVariant::Direct,
+      callback: Box::new(|_d: JsUnknown| Ok(())),
+    }));
+    let status = unsafe {
+      sys::napi_call_threadsafe_function(
+        self.handle.raw,
+        data.cast(),
+        ThreadsafeFunctionCallMode::NonBlocking.into(),
+      )
+    };
+    if status == sys::Status::napi_ok {
+      Ok(())
+    } else {
+      Err(unsafe { Box::from_raw(data) }.data)
+    }
+  }
+
+  /// Same as `call`, but values with a higher `priority` are delivered before the queued values with a lower one.
+  ///
+  /// Node-API has no priorities, so this is best-effort:
+  /// the order only applies to calls which are still waiting when the JavaScript thread gets to them,
+  /// and calls made with `call` are not reordered.
+  /// Values with the same priority are delivered in call order.
+  /// If an error status is returned, the value is dropped without being delivered, so it's safe to retry.
+  pub fn call_with_priority(
+    &self,
+    value: T,
+    priority: u32,
+    mode: ThreadsafeFunctionCallMode,
+  ) -> Status {
+    let data = Box::into_raw(Box::new(ThreadsafeFunctionCallJsBackData {
+      data: value,
+      call_variant: ThreadsafeFunctionCallVariant::Direct,
+      callback: Box::new(|_d: JsUnknown| Ok(())),
+    }));
+    match self.call_prioritized(data.cast(), priority, mode) {
+      Ok(()) => Status::Ok,
+      Err((status, data)) => {
+        drop(unsafe { Box::<ThreadsafeFunctionCallJsBackData<T>>::from_raw(data.cast()) });
+        status.into()
+      }
+    }
+  }
+
   pub fn call_with_return_value<D: FromNapiValue, F: 'static + FnOnce(D) -> Result<()>>(
     &self,
     value: T,
@@ -472,15 +734,27 @@ impl<T: 'static> ThreadsafeFunction<T, ErrorStrategy::Fatal> {
 }
 
 #[allow(unused_variables)]
-unsafe extern "C" fn thread_finalize_cb<T: 'static, V: ToNapiValue, R>(
+unsafe extern "C" fn thread_finalize_cb<T: 'static, V: ToNapiValue, R, ES>(
   env: sys::napi_env,
   finalize_data: *mut c_void,
   finalize_hint: *mut c_void,
 ) where
-  R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<Vec<V>>,
+  R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<CallJsArgs<V>>,
+  ES: ErrorStrategy::T,
 {
   // cleanup
-  drop(unsafe { Box::<R>::from_raw(finalize_hint.cast()) });
+  let context = unsafe { Box::<ThreadsafeFunctionContext<R>>::from_raw(finalize_hint.cast()) };
+  // prioritized calls which were never delivered
+  while let Some(data) = context.priority_queue.pop() {
+    match ES::VALUE {
+      ErrorStrategy::CalleeHandled::VALUE => {
+        drop(unsafe { Box::<Result<ThreadsafeFunctionCallJsBackData<T>>>::from_raw(data.cast()) })
+      }
+      ErrorStrategy::Fatal::VALUE => {
+        drop(unsafe { Box::<ThreadsafeFunctionCallJsBackData<T>>::from_raw(data.cast()) })
+      }
+    }
+  }
 }
 
 unsafe extern "C" fn call_js_cb<T: 'static, V: ToNapiValue, R, ES>(
@@ -489,7 +763,7 @@ unsafe extern "C" fn call_js_cb<T: 'static, V: ToNapiValue, R, ES>(
   context: *mut c_void,
   data: *mut c_void,
 ) where
-  R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<Vec<V>>,
+  R: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> Result<CallJsArgs<V>>,
   ES: ErrorStrategy::T,
 {
   // env and/or callback can be null when shutting down
@@ -497,7 +771,17 @@ unsafe extern "C" fn call_js_cb<T: 'static, V: ToNapiValue, R, ES>(
     return;
   }
 
-  let ctx: &mut R = unsafe { Box::leak(Box::from_raw(context.cast())) };
+  let context: &mut ThreadsafeFunctionContext<R> =
+    unsafe { Box::leak(Box::from_raw(context.cast())) };
+  let data = if data.is_null() {
+    match context.priority_queue.pop() {
+      Some(data) => data,
+      None => return,
+    }
+  } else {
+    data
+  };
+  let ctx = &mut context.callback;
   let val = unsafe {
     match ES::VALUE {
       ErrorStrategy::CalleeHandled::VALUE => {
@@ -517,14 +801,15 @@ unsafe extern "C" fn call_js_cb<T: 'static, V: ToNapiValue, R, ES>(
       env: unsafe { Env::from_raw(raw_env) },
       value: v.data,
     })
-    .map(|ret| (ret, v.call_variant, v.callback))
+    .map(|(this, ret)| (this, ret, v.call_variant, v.callback))
   });
 
   // Follow async callback conventions: https://nodejs.org/en/knowledge/errors/what-are-the-error-conventions/
   // Check if the Result is okay, if so, pass a null as the first (error) argument automatically.
   // If the Result is an error, pass that as the first argument.
   let status = match ret {
-    Ok((values, call_variant, callback)) => {
+    Ok((this, values, call_variant, callback)) => {
+      let recv = this.map(|this| unsafe { this.raw() }).unwrap_or(recv);
       let values = values
         .into_iter()
         .map(|v| unsafe { ToNapiValue::to_napi_value(raw_env, v) });
@@ -610,6 +895,11 @@ unsafe extern "C" fn call_js_cb<T: 'static, V: ToNapiValue, R, ES>(
       sys::Status::napi_ok
     );
 
+    if let Some(on_error) = &context.on_error {
+      on_error(unsafe { exception_to_error(raw_env, error_result) });
+      return;
+    }
+
     // When shutting down, napi_fatal_exception sometimes returns another exception
     let stat = unsafe { sys::napi_fatal_exception(raw_env, error_result) };
     assert!(stat == sys::Status::napi_ok || stat == sys::Status::napi_pending_exception);
@@ -655,6 +945,35 @@ unsafe extern "C" fn call_js_cb<T: 'static, V: ToNapiValue, R, ES>(
   }
 }
 
+/// Convert a thrown JavaScript value into an `Error` keeping a reference to it,
+/// the reason is the `message` of `Error` objects or the stringified value otherwise.
+unsafe fn exception_to_error(env: sys::napi_env, exception: sys::napi_value) -> crate::Error {
+  let value = || {
+    JsUnknown(crate::Value {
+      env,
+      value: exception,
+      value_type: crate::ValueType::Unknown,
+    })
+  };
+  let reason = (|| {
+    let exception = value();
+    let message = if exception.is_error()? {
+      unsafe { exception.cast::<crate::JsObject>() }
+        .get_named_property::<JsUnknown>("message")?
+        .coerce_to_string()?
+    } else {
+      exception.coerce_to_string()?
+    };
+    message.into_utf8()?.into_owned()
+  })()
+  .unwrap_or_default();
+  // primitives can't be referenced on older Node-API versions, only the reason is kept then
+  let mut error = crate::Error::from(value());
+  error.status = Status::GenericFailure;
+  error.reason = reason;
+  error
+}
+
 /// Helper
 macro_rules! type_level_enum {(
   $( #[doc = $doc:tt] )*
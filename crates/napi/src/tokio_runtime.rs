@@ -3,7 +3,9 @@ use std::future::Future;
 use once_cell::sync::Lazy;
 use tokio::runtime::Runtime;
 
-use crate::{sys, JsDeferred, JsUnknown, NapiValue, Result};
+use std::ptr;
+
+use crate::{sys, Error, JsDeferred, JsError, JsUnknown, NapiValue, Result};
 
 pub(crate) static mut RT: Lazy<Option<Runtime>> = Lazy::new(|| {
   let runtime = tokio::runtime::Runtime::new().expect("Create tokio runtime failed");
@@ -85,3 +87,19 @@ pub fn execute_tokio_future<
 
   Ok(promise.0.value)
 }
+
+#[doc(hidden)]
+/// Used by `async fn` to report errors raised before their future is spawned, e.g. argument conversion errors,
+/// so that callers always get a rejected `Promise` instead of a synchronous exception.
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn reject_promise(env: sys::napi_env, err: Error) -> sys::napi_value {
+  let mut deferred = ptr::null_mut();
+  let mut promise = ptr::null_mut();
+  if unsafe { sys::napi_create_promise(env, &mut deferred, &mut promise) } != sys::Status::napi_ok {
+    unsafe { JsError::from(err).throw_into(env) };
+    return ptr::null_mut();
+  }
+  let reason = unsafe { JsError::from(err).into_value(env) };
+  unsafe { sys::napi_reject_deferred(env, deferred, reason) };
+  promise
+}
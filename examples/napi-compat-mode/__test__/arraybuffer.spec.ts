@@ -1,3 +1,6 @@
+import { setFlagsFromString } from 'v8'
+import { runInNewContext } from 'vm'
+
 import ava from 'ava'
 
 import { napiVersion } from './napi-version'
@@ -6,11 +9,22 @@ const bindings = require('../index.node')
 
 const test = napiVersion >= 6 ? ava : ava.skip
 
+setFlagsFromString('--expose_gc')
+const gc: () => void = runInNewContext('gc')
+
 test('should get arraybuffer length', (t) => {
   const fixture = Buffer.from('wow, hello')
   t.is(bindings.getArraybufferLength(fixture.buffer), fixture.buffer.byteLength)
 })
 
+test('should read raw bytes of arraybuffer', (t) => {
+  const fixture = new Uint16Array([1, 256, 65535])
+  t.deepEqual(
+    bindings.getArraybufferBytes(fixture.buffer),
+    Array.from(new Uint8Array(fixture.buffer)),
+  )
+})
+
 test('should be able to mutate Uint8Array', (t) => {
   const fixture = new Uint8Array([0, 1, 2])
   bindings.mutateUint8Array(fixture)
@@ -24,6 +38,16 @@ test('should be able to mutate Uint8Array in its middle', (t) => {
   t.is(fixture[1], 42)
 })
 
+test('should be able to get the geometry of a TypedArray view', (t) => {
+  const buffer = new ArrayBuffer(32)
+  const fixture = new Uint16Array(buffer).subarray(3, 7)
+  const geometry = bindings.getTypedarrayGeometry(fixture)
+  t.is(geometry.byteOffset, 6)
+  t.is(geometry.byteLength, 8)
+  t.is(geometry.length, 4)
+  t.is(geometry.buffer, buffer)
+})
+
 test('should be able to mutate Uint16Array', (t) => {
   const fixture = new Uint16Array([0, 1, 2])
   bindings.mutateUint16Array(fixture)
@@ -53,3 +77,49 @@ test('should be able to mutate BigInt64Array', (t) => {
   bindings.mutateI64Array(fixture)
   t.deepEqual(fixture[0], BigInt('9223372036854775807'))
 })
+
+test('should be able to create external Float64Array from Vec<f64>', (t) => {
+  const fixture = bindings.createExternalFloat64Array(4)
+  t.true(fixture instanceof Float64Array)
+  t.deepEqual(Array.from(fixture), [0, 0.5, 1, 1.5])
+  t.is(fixture.buffer.byteLength, 32)
+  const empty = bindings.createExternalFloat64Array(0)
+  t.true(empty instanceof Float64Array)
+  t.is(empty.length, 0)
+})
+
+test('should be able to create TypedArray by copying a slice', (t) => {
+  const int32 = bindings.createInt32ArrayCopy(4)
+  t.true(int32 instanceof Int32Array)
+  t.deepEqual(Array.from(int32), [-2, -1, 0, 1])
+  t.is(int32.buffer.byteLength, 16)
+  const float64 = bindings.createFloat64ArrayCopy(3)
+  t.true(float64 instanceof Float64Array)
+  t.deepEqual(Array.from(float64), [0, 0.5, 1])
+  const uint8 = bindings.createUint8ArrayCopy()
+  t.true(uint8 instanceof Uint8Array)
+  t.deepEqual(Array.from(uint8), [0, 127, 255])
+  const empty = bindings.createInt32ArrayCopy(0)
+  t.true(empty instanceof Int32Array)
+  t.is(empty.length, 0)
+})
+
+test('should be able to create external ArrayBuffer from Box<[u8]>', (t) => {
+  const fixture = bindings.createArraybufferFromBoxedSlice(5)
+  t.true(fixture instanceof ArrayBuffer)
+  t.deepEqual(Array.from(new Uint8Array(fixture)), [0, 1, 2, 3, 4])
+  const empty = bindings.createArraybufferFromBoxedSlice(0)
+  t.true(empty instanceof ArrayBuffer)
+  t.is(empty.byteLength, 0)
+})
+
+test('should free the Box<[u8]> of a garbage collected external ArrayBuffer', async (t) => {
+  const before = bindings.freedBoxedSlices()
+  t.is(bindings.createTrackedArraybufferFromBoxedSlice().byteLength, 40961)
+  // finalizers may run after the GC pass itself
+  for (let i = 0; i < 10 && bindings.freedBoxedSlices() === before; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.true(bindings.freedBoxedSlices() > before)
+})
@@ -13,3 +13,12 @@ test('should create external with size hint', (t) => {
   const externalObject = bindings.createExternalWithHint(42)
   t.is(bindings.getExternalCount(externalObject), fixture)
 })
+
+test('should not get back an external wrapping another type', (t) => {
+  const externalObject = bindings.createExternalString('42')
+  t.throws(() => bindings.getExternalCount(externalObject), {
+    code: 'InvalidArg',
+    message:
+      'napi_compat_mode_examples::external::NativeObject on get_value_external is not the type of wrapped object',
+  })
+})
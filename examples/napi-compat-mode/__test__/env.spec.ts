@@ -27,3 +27,153 @@ test('should be able to coerceToBool', (t) => {
   t.false(bindings.coerceToBool(null))
   t.false(bindings.coerceToBool(NaN))
 })
+
+test('should be able to coerceToString', (t) => {
+  t.is(bindings.coerceToString(1.5), '1.5')
+  t.is(bindings.coerceToString({}), '[object Object]')
+  t.is(
+    bindings.coerceToString({
+      toString() {
+        return 'custom'
+      },
+    }),
+    'custom',
+  )
+  t.is(bindings.coerceToString(null), 'null')
+  t.throws(() => bindings.coerceToString(Symbol()), { instanceOf: TypeError })
+  t.throws(
+    () =>
+      bindings.coerceToString({
+        toString() {
+          throw new RangeError('Not printable')
+        },
+      }),
+    { instanceOf: RangeError, message: 'Not printable' },
+  )
+})
+
+test('should be able to create resolved promise', async (t) => {
+  const promise = bindings.resolvedPromise(42)
+  t.true(promise instanceof Promise)
+  t.is(await promise, 42)
+})
+
+test('should be able to create rejected promise', async (t) => {
+  const promise = bindings.rejectedPromise('Rejected')
+  t.true(promise instanceof Promise)
+  await t.throwsAsync(() => promise, { message: 'Rejected' })
+})
+
+test('should be able to emit process warning', async (t) => {
+  const warning = new Promise<any>((resolve) => {
+    process.once('warning', resolve)
+  })
+  bindings.emitWarning('foo() is deprecated', 'DeprecationWarning', 'DEP_FOO')
+  const { name, message, code } = await warning
+  t.is(name, 'DeprecationWarning')
+  t.is(message, 'foo() is deprecated')
+  t.is(code, 'DEP_FOO')
+})
+
+test('should be able to wait for all promises', async (t) => {
+  const promise = bindings.resolveAll(1, 2)
+  t.true(promise instanceof Promise)
+  t.deepEqual(await promise, [1, 2])
+})
+
+test('should be able to race promises', async (t) => {
+  const never = new Promise(() => {})
+  t.is(await bindings.race([never, Promise.resolve('fast')]), 'fast')
+  await t.throwsAsync(
+    () => bindings.race([never, Promise.reject(new Error('boom'))]),
+    { message: 'boom' },
+  )
+})
+
+test('should be able to catch, inspect and rethrow exception', (t) => {
+  const error = new Error('Thrown from getter')
+  const fixture = {
+    get value() {
+      throw error
+    },
+  }
+  const rethrown = t.throws(() => bindings.catchAndRethrow(fixture))
+  t.is(rethrown, error)
+  t.true((error as any).inspectedByNative)
+  t.is(bindings.catchAndRethrow({ value: 1 }), 1)
+})
+
+test('should be able to propagate pending exception', (t) => {
+  const error = new TypeError('Thrown from getter')
+  const fixture = {
+    get value() {
+      throw error
+    },
+  }
+  const propagated = t.throws(() => bindings.readAndPropagate(fixture))
+  t.is(propagated, error)
+  t.is(bindings.readAndPropagate({ value: 1 }), 1)
+})
+
+test('should propagate pending exception after a throwing call', (t) => {
+  const error = new RangeError('Thrown from setter')
+  const fixture = {
+    set value(_: boolean) {
+      throw error
+    },
+  }
+  const propagated = t.throws(() => bindings.writeAndPropagate(fixture))
+  t.is(propagated, error)
+  const writable = { value: false }
+  t.is(bindings.writeAndPropagate(writable), undefined)
+  t.true(writable.value)
+})
+
+test('should wrap caught error with context and keep its stack', (t) => {
+  function failingCallback() {
+    throw new TypeError('Original failure')
+  }
+  const error = t.throws(
+    () => bindings.callWithContext(failingCallback, 'Failed to run callback'),
+    { instanceOf: TypeError },
+  )
+  t.is(error.message, 'Failed to run callback: Original failure')
+  t.true(
+    error.stack!.startsWith('TypeError: Failed to run callback: Original failure\n'),
+  )
+  t.true(error.stack!.includes('failingCallback'))
+  t.is(bindings.callWithContext(() => 1, 'Failed to run callback'), 1)
+})
+
+test('should rethrow a frozen caught error as is', (t) => {
+  const frozen = Object.freeze(new TypeError('Original failure'))
+  const error = t.throws(() =>
+    bindings.callWithContext(() => {
+      throw frozen
+    }, 'Failed to run callback'),
+  )
+  t.is(error, frozen)
+  t.is(error.message, 'Original failure')
+})
+
+test('should be able to compile script once and run it repeatedly', (t) => {
+  const g = global as any
+  g.compiledScriptCounter = 0
+  t.deepEqual(
+    bindings.runCompiledScript('return ++this.compiledScriptCounter', 3),
+    [1, 2, 3],
+  )
+  t.deepEqual(bindings.runCompiledScript('var local = 1; return local', 1), [1])
+  t.false('local' in g)
+  delete g.compiledScriptCounter
+  t.throws(() => bindings.runCompiledScript('return (', 1), {
+    instanceOf: SyntaxError,
+  })
+})
+
+test('should release the compiled script if it throws', (t) => {
+  t.throws(() => bindings.runCompiledScript('throw new Error("boom")', 2), {
+    message: 'boom',
+  })
+  t.deepEqual(bindings.runCompiledScript('return 1', 1), [1])
+})
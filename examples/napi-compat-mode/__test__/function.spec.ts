@@ -31,3 +31,21 @@ test('should handle errors', (t) => {
     },
   )
 })
+
+test('should get all arguments beyond arg_len', (t) => {
+  t.deepEqual(
+    bindings.testGetAllArgsTypes(1, 'str', true, null, { a: 1 }),
+    ['Number', 'String', 'Boolean', 'Null', 'Object'],
+  )
+})
+
+test('should keep a created function alive through a reference', async (t) => {
+  bindings.testStoreFunction()
+  await new Promise((resolve) => setTimeout(resolve))
+  t.is(bindings.testCallStoredFunction(1), 2)
+  t.is(bindings.testCallStoredFunction(41), 42)
+  bindings.testReleaseStoredFunction()
+  t.throws(() => bindings.testCallStoredFunction(1), {
+    message: 'No function stored',
+  })
+})
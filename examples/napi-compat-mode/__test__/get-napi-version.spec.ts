@@ -5,5 +5,6 @@ const bindings = require('../index.node')
 test('should get napi version', (t) => {
   const napiVersion = bindings.getNapiVersion()
   t.true(typeof napiVersion === 'number')
+  t.true(napiVersion >= 1)
   t.is(`${napiVersion}`, process.versions.napi!)
 })
@@ -9,6 +9,22 @@ test('instanceof', (t) => {
   t.false(bindings.instanceof(1, Date))
 })
 
+test('instanceof named global', (t) => {
+  t.true(bindings.instanceofNamed(new Map(), 'Map'))
+  t.false(bindings.instanceofNamed(new Set(), 'Map'))
+  t.false(bindings.instanceofNamed({}, 'Map'))
+  t.true(bindings.instanceofNamed(/napi/, 'RegExp'))
+  t.true(bindings.instanceofNamed(new RegExp('napi'), 'RegExp'))
+  t.false(bindings.instanceofNamed('/napi/', 'RegExp'))
+  t.true(bindings.instanceofNamed(/napi/, 'Object'))
+  t.throws(() => bindings.instanceofNamed({}, 'NotExisted'), {
+    message: '`NotExisted` is not a global constructor',
+  })
+  t.throws(() => bindings.instanceofNamed({}, 'Math'), {
+    message: '`Math` is not a global constructor',
+  })
+})
+
 test('is_error', (t) => {
   t.true(bindings.isError(new Error()))
   t.true(bindings.isError(new TypeError()))
@@ -18,6 +34,14 @@ test('is_error', (t) => {
   t.false(bindings.isError(Symbol()))
 })
 
+test('is_arraybuffer', (t) => {
+  t.true(bindings.isArraybuffer(new ArrayBuffer(8)))
+  t.true(bindings.isArraybuffer(Buffer.from('123').buffer))
+  t.false(bindings.isArraybuffer(new Uint8Array(8)))
+  t.false(bindings.isArraybuffer(new DataView(new ArrayBuffer(8))))
+  t.false(bindings.isArraybuffer({ byteLength: 8 }))
+})
+
 test('is_typedarray', (t) => {
   t.true(bindings.isTypedarray(new Uint8Array()))
   t.true(bindings.isTypedarray(new Uint16Array()))
@@ -28,12 +52,49 @@ test('is_typedarray', (t) => {
   t.true(bindings.isTypedarray(Buffer.from('123')))
   t.false(bindings.isTypedarray(Buffer.from('123').buffer))
   t.false(bindings.isTypedarray([]))
+  t.false(bindings.isTypedarray(new DataView(new ArrayBuffer(8))))
+})
+
+test('is_buffer', (t) => {
+  // Node.js reports every ArrayBufferView as a buffer
+  t.true(bindings.isBuffer(Buffer.from('123')))
+  t.true(bindings.isBuffer(new Uint8Array(4)))
+  t.true(bindings.isBuffer(new DataView(new ArrayBuffer(8))))
+  t.false(bindings.isBuffer(new ArrayBuffer(8)))
+  t.false(bindings.isBuffer('123'))
+})
+
+test('is_node_buffer', (t) => {
+  t.true(bindings.isNodeBuffer(Buffer.from('123')))
+  t.true(bindings.isNodeBuffer(Buffer.alloc(0)))
+  t.false(bindings.isNodeBuffer(new Uint8Array(4)))
+  t.false(bindings.isNodeBuffer(new Uint8Array(Buffer.from('123'))))
+  t.false(bindings.isNodeBuffer(new DataView(new ArrayBuffer(8))))
+  t.false(bindings.isNodeBuffer(new ArrayBuffer(8)))
+  t.false(bindings.isNodeBuffer({}))
+})
+
+test('get buffer bytes', (t) => {
+  t.deepEqual(bindings.getBufferBytes(Buffer.from('ab')), [97, 98])
+  t.deepEqual(bindings.getBufferBytes(Buffer.alloc(0)), [])
+  t.deepEqual(bindings.getBufferBytes(new Uint8Array([1, 2, 3])), [1, 2, 3])
+  const source = new Uint8Array([5, 6, 7, 8])
+  t.deepEqual(bindings.getBufferBytes(new Uint8Array(source.buffer, 1, 2)), [
+    6, 7,
+  ])
+  t.deepEqual(bindings.getBufferBytes(new DataView(source.buffer, 2)), [7, 8])
+  t.is(bindings.getBufferBytes(new Uint16Array([1, 2])).length, 4)
+  t.throws(() => bindings.getBufferBytes(new ArrayBuffer(8)), {
+    message: 'Failed to get buffer info, the value is not an ArrayBufferView',
+  })
 })
 
 test('is_dataview', (t) => {
   const data = new Uint8Array(100)
   t.true(bindings.isDataview(new DataView(data.buffer)))
   t.false(bindings.isDataview(Buffer.from('123')))
+  t.false(bindings.isDataview(data.buffer))
+  t.false(bindings.isDataview({}))
 })
 
 test('strict_equals', (t) => {
@@ -35,6 +35,21 @@ test('should get js function called from a thread', async (t) => {
   })
 })
 
+test('should call js function with the customized this', async (t) => {
+  if (napiVersion < 4) {
+    t.is(bindings.testTsfnWithThis, undefined)
+    return
+  }
+
+  const [receiver, args] = await new Promise<[any, any[]]>((resolve) => {
+    bindings.testTsfnWithThis(function (this: any, ...args: any[]) {
+      resolve([this, args])
+    })
+  })
+  t.deepEqual(receiver, { source: 'tsfn' })
+  t.deepEqual(args, [null, 42])
+})
+
 test('should be able to abort tsfn', (t) => {
   if (napiVersion < 4) {
     t.is(bindings.testAbortThreadsafeFunction, undefined)
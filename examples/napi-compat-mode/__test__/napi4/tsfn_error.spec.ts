@@ -21,3 +21,21 @@ test('should call callback with the first arguments as an Error', async (t) => {
     })
   })
 })
+
+test('should pass exceptions thrown by the callback to the error handler', async (t) => {
+  if (napiVersion < 4) {
+    t.is(bindings.testTsfnErrorHandler, undefined)
+    return
+  }
+  const value = await new Promise((resolve) => {
+    bindings.testTsfnErrorHandler((err: Error | null, value: number) => {
+      setTimeout(() => resolve(value))
+      throw new TypeError('Thrown from callback')
+    })
+  })
+  t.is(value, 1)
+  t.deepEqual(bindings.takeHandledTsfnErrors(), [
+    'GenericFailure: Thrown from callback',
+  ])
+  t.deepEqual(bindings.takeHandledTsfnErrors(), [])
+})
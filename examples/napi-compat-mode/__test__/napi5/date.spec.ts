@@ -40,3 +40,13 @@ test('should get date value', (t) => {
     t.is(bindings.testObjectIsDate, undefined)
   }
 })
+
+test('should roundtrip date value', (t) => {
+  if (napiVersion >= 5) {
+    for (const timestamp of [0, -1, Date.UTC(1969, 6, 20), Date.now()]) {
+      t.is(bindings.testDateRoundtrip(timestamp), timestamp)
+    }
+  } else {
+    t.is(bindings.testDateRoundtrip, undefined)
+  }
+})
@@ -0,0 +1,18 @@
+import test from 'ava'
+
+import { napiVersion } from '../napi-version'
+
+const bindings = require('../../index.node')
+
+test('should share native data between functions', (t) => {
+  if (napiVersion >= 5) {
+    const { increment, get } = bindings.testCreateFunctionsWithSharedData()
+    t.is(increment.name, 'increment')
+    t.is(get(), 0)
+    increment()
+    increment()
+    t.is(get(), 2)
+  } else {
+    t.is(bindings.testCreateFunctionsWithSharedData, undefined)
+  }
+})
@@ -0,0 +1,20 @@
+import test from 'ava'
+
+import { napiVersion } from '../napi-version'
+
+const bindings = require('../../index.node')
+
+test('should make object iterable with native next', (t) => {
+  if (napiVersion >= 5) {
+    const iterable = bindings.testCreateRangeIterable(3)
+    t.is(typeof iterable[Symbol.iterator], 'function')
+    t.deepEqual(Object.keys(iterable), [])
+    t.deepEqual(Object.getOwnPropertyNames(iterable), [])
+    t.deepEqual([...iterable], [0, 1, 2])
+    // the iteration state lives in the native `next`
+    t.deepEqual([...iterable], [])
+    t.deepEqual([...bindings.testCreateRangeIterable(0)], [])
+  } else {
+    t.is(bindings.testCreateRangeIterable, undefined)
+  }
+})
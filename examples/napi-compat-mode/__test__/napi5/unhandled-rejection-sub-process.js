@@ -0,0 +1,13 @@
+const bindings = require('../../index.node')
+
+bindings.testTrackUnhandledRejections()
+// the second callback is removed again, so the rejection is only tracked once
+bindings.testTrackUnhandledRejections()
+bindings.testUntrackUnhandledRejections()
+
+Promise.reject(new Error('Forgotten rejection'))
+Promise.reject(new Error('Handled rejection')).catch(() => {})
+
+setTimeout(() => {
+  console.log(JSON.stringify(bindings.testGetUnhandledRejections()))
+}, 10)
@@ -0,0 +1,15 @@
+import { execSync } from 'child_process'
+import { join } from 'path'
+
+import ava from 'ava'
+
+import { napiVersion } from '../napi-version'
+
+const test = napiVersion >= 5 ? ava : ava.skip
+
+test('should call rust callback on unhandled rejection', (t) => {
+  const output = execSync(
+    `node ${join(__dirname, 'unhandled-rejection-sub-process.js')}`,
+  ).toString()
+  t.deepEqual(JSON.parse(output), ['Error: Forgotten rejection'])
+})
@@ -37,3 +37,18 @@ test('should get integers from bigints', (t) => {
     t.is(bindings.testGetBigintI64, undefined)
   }
 })
+
+test('should create BigInt array from i64 values', (t) => {
+  if (napiVersion >= 6) {
+    const fixture = [
+      BigInt('9223372036854775807'),
+      BigInt('-9223372036854775808'),
+      BigInt(Number.MAX_SAFE_INTEGER) + BigInt(1),
+      BigInt(0),
+    ]
+    t.deepEqual(bindings.testCreateBigintArrayI64(fixture), fixture)
+    t.deepEqual(bindings.testCreateBigintArrayI64([]), [])
+  } else {
+    t.is(bindings.testCreateBigintArrayI64, undefined)
+  }
+})
@@ -0,0 +1,42 @@
+import test from 'ava'
+
+import { napiVersion } from '../napi-version'
+
+const bindings = require('../../index.node')
+
+const symbol = Symbol('hidden')
+
+function createFixture() {
+  const proto = { inherited: 1 }
+  const obj = Object.create(proto)
+  obj.own = 2
+  obj[symbol] = 3
+  Object.defineProperty(obj, 'hidden', { value: 4, enumerable: false })
+  return obj
+}
+
+test('should get own property names', (t) => {
+  if (napiVersion >= 6) {
+    const obj = createFixture()
+    t.deepEqual(bindings.testGetAllPropertyNames(obj, false, false), [
+      'own',
+      'hidden',
+      symbol,
+    ])
+    t.deepEqual(bindings.testGetAllPropertyNames(obj, false, true), ['own'])
+  } else {
+    t.is(bindings.testGetAllPropertyNames, undefined)
+  }
+})
+
+test('should get property names including prototypes', (t) => {
+  if (napiVersion >= 6) {
+    const obj = createFixture()
+    t.deepEqual(bindings.testGetAllPropertyNames(obj, true, true), [
+      'own',
+      'inherited',
+    ])
+  } else {
+    t.is(bindings.testGetAllPropertyNames, undefined)
+  }
+})
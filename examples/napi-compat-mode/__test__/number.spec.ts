@@ -0,0 +1,40 @@
+import test from 'ava'
+
+const bindings = require('../index.node')
+
+test('should get the bits of number', (t) => {
+  t.is(bindings.numberToBits(0), '0000000000000000')
+  t.is(bindings.numberToBits(-0), '8000000000000000')
+  t.is(bindings.numberToBits(1), '3ff0000000000000')
+  t.is(bindings.numberToBits(-1.5), 'bff8000000000000')
+  t.is(bindings.numberToBits(Infinity), '7ff0000000000000')
+  t.is(bindings.numberToBits(-Infinity), 'fff0000000000000')
+  t.is(bindings.numberToBits(Number.MIN_VALUE), '0000000000000001')
+  t.is(bindings.numberToBits(NaN), '7ff8000000000000')
+})
+
+test('should create number from bits', (t) => {
+  t.true(Object.is(bindings.createDoubleFromBits('8000000000000000'), -0))
+  t.true(Object.is(bindings.createDoubleFromBits('0000000000000000'), 0))
+  t.is(bindings.createDoubleFromBits('3ff0000000000000'), 1)
+  t.is(bindings.createDoubleFromBits('0000000000000001'), Number.MIN_VALUE)
+  t.is(bindings.createDoubleFromBits('fff0000000000000'), -Infinity)
+  for (const bits of [
+    'fff0000000000000',
+    '8000000000000000',
+    '000fffffffffffff',
+    '7fefffffffffffff',
+  ]) {
+    t.is(bindings.numberToBits(bindings.createDoubleFromBits(bits)), bits)
+  }
+})
+
+test('should create NaN from bits with payload', (t) => {
+  for (const bits of [
+    '7ff8000000000001',
+    'fff8000000000000',
+    '7ff0000000000001',
+  ]) {
+    t.true(Number.isNaN(bindings.createDoubleFromBits(bits)))
+  }
+})
@@ -36,6 +36,29 @@ test('testGetNamedProperty', (t) => {
   t.is(bindings.testGetNamedProperty(obj), obj.p)
 })
 
+test('testGetNamedPropertyU32', (t) => {
+  t.is(bindings.testGetNamedPropertyU32({ count: 42 }, 'count'), 42)
+  t.throws(() => bindings.testGetNamedPropertyU32({}, 'count'), {
+    code: 'InvalidArg',
+    message:
+      'Property `count` doesn\'t exist on the object. Expect value to be Number, but received Undefined',
+  })
+  t.throws(() => bindings.testGetNamedPropertyU32({ count: 'many' }, 'count'), {
+    code: 'InvalidArg',
+    message:
+      'Failed to convert property `count`. Expect value to be Number, but received String',
+  })
+})
+
+test('testGetNamedPropertyString', (t) => {
+  t.is(bindings.testGetNamedPropertyString({ name: 'foo' }, 'name'), 'foo')
+  t.throws(() => bindings.testGetNamedPropertyString({ name: 42 }, 'name'), {
+    code: 'InvalidArg',
+    message:
+      'Failed to convert property `name`. Expect value to be String, but received Number',
+  })
+})
+
 test('testHasNamedProperty', (t) => {
   const obj = {
     a: 1,
@@ -204,6 +227,28 @@ test('testGetElement', (t) => {
   const arr = [Symbol(), Symbol()]
   t.is(bindings.testGetElement(arr, 0), arr[0])
   t.is(bindings.testGetElement(arr, 1), arr[1])
+  t.is(bindings.testGetElement(arr, 2), undefined)
+})
+
+test('testGetStringElement', (t) => {
+  t.is(bindings.testGetStringElement(['foo', 1], 0), 'foo')
+  t.throws(() => bindings.testGetStringElement(['foo', 1], 1), {
+    code: 'InvalidArg',
+    message: 'Expect value to be String, but received Number',
+  })
+})
+
+test('testSumElements', (t) => {
+  t.is(bindings.testSumElements([]), 0)
+  t.is(bindings.testSumElements([1, 2, 3]), 6)
+  t.is(bindings.testSumElements([1, 2, undefined, 4]), 3)
+  t.throws(() => bindings.testSumElements(['1']))
+})
+
+test('testFillElements', (t) => {
+  const arr: number[] = []
+  bindings.testFillElements(arr, 4)
+  t.deepEqual(arr, [0, 2, 4, 6])
 })
 
 test('testDeleteElement', (t) => {
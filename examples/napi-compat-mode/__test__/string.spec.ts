@@ -20,3 +20,20 @@ test('should be able to concat latin1 string', (t) => {
 test('should be able to crate latin1 string', (t) => {
   t.snapshot(bindings.createLatin1())
 })
+
+test('should be able to iterate code points', (t) => {
+  const fixture = 'a🌳你😀𝒳'
+  t.deepEqual(
+    bindings.codePoints(fixture),
+    Array.from(fixture).map((c) => c.codePointAt(0)),
+  )
+  t.deepEqual(bindings.codePoints('\ud800x'), [0xfffd, 0x78])
+  t.deepEqual(bindings.codePoints(''), [])
+})
+
+test('should be able to check whether string is empty', (t) => {
+  t.true(bindings.isEmptyString(''))
+  t.false(bindings.isEmptyString(' '))
+  t.false(bindings.isEmptyString('🌳'))
+  t.false(bindings.isEmptyString('\0'))
+})
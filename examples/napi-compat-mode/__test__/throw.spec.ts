@@ -1,5 +1,7 @@
 import test from 'ava'
 
+process.env.RUST_BACKTRACE = '1'
+
 const bindings = require('../index.node')
 
 test('should be able to throw error from native', (t) => {
@@ -11,6 +13,34 @@ test('should be able to throw error from native with reason', (t) => {
   t.throws(() => bindings.testThrowWithReason(reason), void 0, reason)
 })
 
+const backtraceTest = bindings.isBacktraceEnabled() ? test : test.skip
+
+backtraceTest('should attach rust backtrace to error if RUST_BACKTRACE is set', (t) => {
+  const err = t.throws(() => bindings.testThrowWithReason('backtrace'))
+  t.is(typeof (err as any).rustStack, 'string')
+})
+
+test('should not throw again if exception is pending', (t) => {
+  const error = new TypeError('Thrown before returning')
+  t.is(
+    t.throws(() => bindings.testReturnPendingException(error)),
+    error,
+  )
+  t.is(
+    t.throws(() => bindings.testThrowPendingException(error)),
+    error,
+  )
+})
+
+test('should be able to throw error with properties', (t) => {
+  const err = t.throws(() => bindings.testThrowWithProps(503), {
+    code: 'E_REQUEST',
+    message: 'Request failed',
+  })
+  t.true((err as any).retryable)
+  t.is((err as any).statusCode, 503)
+})
+
 test('should throw if argument type is not match', (t) => {
   t.throws(() => bindings.testThrowWithReason(2))
 })
@@ -1,5 +1,7 @@
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::f64::consts::PI;
 use std::str;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 use napi::{
   bindgen_prelude::Uint8Array, CallContext, JsArrayBuffer, JsNumber, JsObject, JsTypedArray,
@@ -12,6 +14,17 @@ pub fn get_arraybuffer_length(ctx: CallContext) -> Result<JsNumber> {
   ctx.env.create_uint32(buffer.len() as u32)
 }
 
+#[js_function(1)]
+pub fn get_arraybuffer_bytes(ctx: CallContext) -> Result<JsObject> {
+  let buffer = ctx.get::<JsArrayBuffer>(0)?.into_value()?;
+  let bytes = buffer.as_slice();
+  let mut arr = ctx.env.create_array_with_length(bytes.len())?;
+  for (index, byte) in bytes.iter().enumerate() {
+    arr.set_element(index as u32, ctx.env.create_uint32(*byte as u32)?)?;
+  }
+  Ok(arr)
+}
+
 #[js_function(1)]
 pub fn mutate_uint8_array(ctx: CallContext) -> Result<JsUndefined> {
   let mut buffer = ctx.get::<Uint8Array>(0)?;
@@ -61,8 +74,119 @@ pub fn mutate_i64_array(ctx: CallContext) -> Result<JsUndefined> {
   ctx.env.get_undefined()
 }
 
+#[js_function(1)]
+pub fn get_typedarray_geometry(ctx: CallContext) -> Result<JsObject> {
+  let typedarray = ctx.get::<JsTypedArray>(0)?;
+  let mut geometry = ctx.env.create_object()?;
+  geometry.set_named_property(
+    "byteOffset",
+    ctx.env.create_uint32(typedarray.byte_offset()? as u32)?,
+  )?;
+  geometry.set_named_property(
+    "byteLength",
+    ctx.env.create_uint32(typedarray.byte_length()? as u32)?,
+  )?;
+  geometry.set_named_property(
+    "length",
+    ctx.env.create_uint32(typedarray.length()? as u32)?,
+  )?;
+  geometry.set_named_property("buffer", typedarray.arraybuffer()?)?;
+  Ok(geometry)
+}
+
+#[js_function(1)]
+pub fn create_external_float64_array(ctx: CallContext) -> Result<JsTypedArray> {
+  let length = ctx.get::<JsNumber>(0)?.get_uint32()?;
+  let data = (0..length).map(|i| i as f64 * 0.5).collect::<Vec<f64>>();
+  ctx.env.create_external_arraybuffer(data)
+}
+
+#[js_function(1)]
+pub fn create_int32_array_copy(ctx: CallContext) -> Result<JsTypedArray> {
+  let length = ctx.get::<JsNumber>(0)?.get_uint32()?;
+  let data = (0..length as i32).map(|i| i - 2).collect::<Vec<i32>>();
+  ctx.env.create_int32_array(&data)
+}
+
+#[js_function(1)]
+pub fn create_float64_array_copy(ctx: CallContext) -> Result<JsTypedArray> {
+  let length = ctx.get::<JsNumber>(0)?.get_uint32()?;
+  let data = (0..length).map(|i| i as f64 * 0.5).collect::<Vec<f64>>();
+  ctx.env.create_float64_array(&data)
+}
+
+#[js_function]
+pub fn create_uint8_array_copy(ctx: CallContext) -> Result<JsTypedArray> {
+  ctx.env.create_uint8_array(&[0, 127, 255])
+}
+
+/// Length of the boxed slices counted by `FREED_BOXED_SLICES`, no other allocation of the addon has it
+const TRACKED_BOXED_SLICE_LEN: usize = 40_961;
+
+static FREED_BOXED_SLICES: AtomicU32 = AtomicU32::new(0);
+
+/// Counts the freed `TRACKED_BOXED_SLICE_LEN` bytes allocations, to observe the boxed slices dropped by the `ArrayBuffer` finalizer
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    if layout.size() == TRACKED_BOXED_SLICE_LEN && layout.align() == 1 {
+      FREED_BOXED_SLICES.fetch_add(1, Ordering::Relaxed);
+    }
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+#[js_function(1)]
+pub fn create_arraybuffer_from_boxed_slice(ctx: CallContext) -> Result<JsArrayBuffer> {
+  let length = ctx.get::<JsNumber>(0)?.get_uint32()?;
+  let data = (0..length).map(|i| i as u8).collect::<Box<[u8]>>();
+  ctx
+    .env
+    .create_arraybuffer_from_boxed_slice(data)
+    .map(|value| value.into_raw())
+}
+
+#[js_function]
+pub fn create_tracked_arraybuffer_from_boxed_slice(ctx: CallContext) -> Result<JsArrayBuffer> {
+  let data = vec![1; TRACKED_BOXED_SLICE_LEN].into_boxed_slice();
+  ctx
+    .env
+    .create_arraybuffer_from_boxed_slice(data)
+    .map(|value| value.into_raw())
+}
+
+#[js_function]
+pub fn freed_boxed_slices(ctx: CallContext) -> Result<JsNumber> {
+  ctx
+    .env
+    .create_uint32(FREED_BOXED_SLICES.load(Ordering::Relaxed))
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("getArraybufferLength", get_arraybuffer_length)?;
+  exports.create_named_method("getArraybufferBytes", get_arraybuffer_bytes)?;
+  exports.create_named_method("getTypedarrayGeometry", get_typedarray_geometry)?;
+  exports.create_named_method("createExternalFloat64Array", create_external_float64_array)?;
+  exports.create_named_method("createInt32ArrayCopy", create_int32_array_copy)?;
+  exports.create_named_method("createFloat64ArrayCopy", create_float64_array_copy)?;
+  exports.create_named_method("createUint8ArrayCopy", create_uint8_array_copy)?;
+  exports.create_named_method(
+    "createArraybufferFromBoxedSlice",
+    create_arraybuffer_from_boxed_slice,
+  )?;
+  exports.create_named_method(
+    "createTrackedArraybufferFromBoxedSlice",
+    create_tracked_arraybuffer_from_boxed_slice,
+  )?;
+  exports.create_named_method("freedBoxedSlices", freed_boxed_slices)?;
   exports.create_named_method("mutateUint8Array", mutate_uint8_array)?;
   exports.create_named_method("mutateUint16Array", mutate_uint16_array)?;
   exports.create_named_method("mutateInt16Array", mutate_int16_array)?;
@@ -1,6 +1,6 @@
 use napi::{
-  CallContext, ContextlessResult, Env, JsBoolean, JsFunction, JsObject, JsString, JsUndefined,
-  JsUnknown, Result,
+  CallContext, ContextlessResult, Env, Error, JsBoolean, JsError, JsFunction, JsObject, JsString,
+  JsUndefined, JsUnknown, Result,
 };
 
 #[js_function(2)]
@@ -10,12 +10,50 @@ pub fn instanceof(ctx: CallContext) -> Result<JsBoolean> {
   ctx.env.get_boolean(object.instanceof(constructor)?)
 }
 
+#[js_function(2)]
+pub fn instanceof_named(ctx: CallContext) -> Result<JsBoolean> {
+  let object = ctx.get::<JsUnknown>(0)?;
+  let global_name = ctx.get::<JsString>(1)?.into_utf8()?;
+  ctx
+    .env
+    .get_boolean(object.instanceof_named(ctx.env, global_name.as_str()?)?)
+}
+
+#[js_function(1)]
+pub fn is_arraybuffer(ctx: CallContext) -> Result<JsBoolean> {
+  let js_value = ctx.get::<JsUnknown>(0)?;
+  ctx.env.get_boolean(js_value.is_arraybuffer()?)
+}
+
 #[js_function(1)]
 pub fn is_typedarray(ctx: CallContext) -> Result<JsBoolean> {
   let js_value = ctx.get::<JsUnknown>(0)?;
   ctx.env.get_boolean(js_value.is_typedarray()?)
 }
 
+#[js_function(1)]
+pub fn is_buffer(ctx: CallContext) -> Result<JsBoolean> {
+  let js_value = ctx.get::<JsUnknown>(0)?;
+  ctx.env.get_boolean(js_value.is_buffer()?)
+}
+
+#[js_function(1)]
+pub fn is_node_buffer(ctx: CallContext) -> Result<JsBoolean> {
+  let js_value = ctx.get::<JsUnknown>(0)?;
+  ctx.env.get_boolean(js_value.is_node_buffer()?)
+}
+
+#[js_function(1)]
+pub fn get_buffer_bytes(ctx: CallContext) -> Result<JsObject> {
+  let js_value = ctx.get::<JsUnknown>(0)?;
+  let bytes = ctx.env.get_buffer_info(&js_value)?;
+  let mut array = ctx.env.create_array_with_length(bytes.len())?;
+  for (i, byte) in bytes.iter().enumerate() {
+    array.set_element(i as u32, ctx.env.create_uint32(*byte as u32)?)?;
+  }
+  Ok(array)
+}
+
 #[js_function(1)]
 pub fn is_dataview(ctx: CallContext) -> Result<JsBoolean> {
   let js_value = ctx.get::<JsUnknown>(0)?;
@@ -59,14 +97,133 @@ fn coerce_to_bool(ctx: CallContext) -> Result<JsBoolean> {
   arg.coerce_to_bool()
 }
 
+#[js_function(1)]
+fn coerce_to_string(ctx: CallContext) -> Result<JsString> {
+  let arg: JsUnknown = ctx.get(0)?;
+  arg.coerce_to_string()
+}
+
+#[js_function(3)]
+fn emit_warning(ctx: CallContext) -> Result<JsUndefined> {
+  let msg = ctx.get::<JsString>(0)?.into_utf8()?.into_owned()?;
+  let kind = ctx.get::<Option<String>>(1)?;
+  let code = ctx.get::<Option<String>>(2)?;
+  ctx
+    .env
+    .emit_warning(&msg, kind.as_deref(), code.as_deref())?;
+  ctx.env.get_undefined()
+}
+
+#[js_function(1)]
+fn resolved_promise(ctx: CallContext) -> Result<JsObject> {
+  let value: u32 = ctx.get(0)?;
+  ctx.env.resolved_promise(value)
+}
+
+#[js_function(1)]
+fn rejected_promise(ctx: CallContext) -> Result<JsObject> {
+  let reason: String = ctx.get::<JsString>(0)?.into_utf8()?.into_owned()?;
+  ctx.env.rejected_promise(Error::from_reason(reason))
+}
+
+#[js_function(2)]
+fn resolve_all(ctx: CallContext) -> Result<JsObject> {
+  let a: u32 = ctx.get(0)?;
+  let b: u32 = ctx.get(1)?;
+  let promises = vec![ctx.env.resolved_promise(a)?, ctx.env.resolved_promise(b)?];
+  ctx.env.promise_all(promises)
+}
+
+#[js_function(1)]
+fn race(ctx: CallContext) -> Result<JsObject> {
+  let promises: Vec<JsObject> = ctx.get(0)?;
+  ctx.env.promise_race(promises)
+}
+
+#[js_function(1)]
+fn catch_and_rethrow(ctx: CallContext) -> Result<JsUnknown> {
+  let obj: JsObject = ctx.get(0)?;
+  match obj.get_named_property::<JsUnknown>("value") {
+    Ok(value) => Ok(value),
+    Err(_) => {
+      if let Some(exception) = ctx.env.get_and_clear_last_exception()? {
+        let mut error = exception.coerce_to_object()?;
+        error.set_named_property("inspectedByNative", ctx.env.get_boolean(true)?)?;
+        ctx.env.throw(error)?;
+      }
+      ctx.env.get_undefined().map(|v| v.into_unknown())
+    }
+  }
+}
+
+#[js_function(1)]
+fn read_and_propagate(ctx: CallContext) -> Result<JsUnknown> {
+  let obj: JsObject = ctx.get(0)?;
+  match obj.get_named_property::<JsUnknown>("value") {
+    Ok(value) => Ok(value),
+    Err(_) => {
+      ctx.env.propagate_pending_exception()?;
+      ctx.env.get_undefined().map(|v| v.into_unknown())
+    }
+  }
+}
+
+#[js_function(1)]
+fn write_and_propagate(ctx: CallContext) -> Result<JsUndefined> {
+  let mut obj: JsObject = ctx.get(0)?;
+  if let Err(err) = obj.set_named_property("value", ctx.env.get_boolean(true)?) {
+    ctx.env.propagate_pending_exception()?;
+    return Err(err);
+  }
+  ctx.env.get_undefined()
+}
+
+#[js_function(2)]
+fn call_with_context(ctx: CallContext) -> Result<JsUnknown> {
+  let callback = ctx.get::<JsFunction>(0)?;
+  let context = ctx.get::<JsString>(1)?.into_utf8()?.into_owned()?;
+  callback.call_without_args(None).map_err(|err| {
+    let exception = JsError::from(err).into_unknown(*ctx.env);
+    ctx.env.create_error_from_js_error_value(exception, context)
+  })
+}
+
+#[js_function(2)]
+fn run_compiled_script(ctx: CallContext) -> Result<JsObject> {
+  let code = ctx.get::<JsString>(0)?.into_utf8()?;
+  let times: u32 = ctx.get(1)?;
+  let script = ctx.env.compile_script(code.as_str()?)?;
+  let mut results = ctx.env.create_array_with_length(times as usize)?;
+  for i in 0..times {
+    results.set_element(i, script.run::<JsUnknown>(ctx.env)?)?;
+  }
+  Ok(results)
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("instanceof", instanceof)?;
+  exports.create_named_method("instanceofNamed", instanceof_named)?;
+  exports.create_named_method("isArraybuffer", is_arraybuffer)?;
   exports.create_named_method("isTypedarray", is_typedarray)?;
+  exports.create_named_method("isBuffer", is_buffer)?;
+  exports.create_named_method("isNodeBuffer", is_node_buffer)?;
+  exports.create_named_method("getBufferBytes", get_buffer_bytes)?;
   exports.create_named_method("isDataview", is_dataview)?;
   exports.create_named_method("strictEquals", strict_equals)?;
   exports.create_named_method("castUnknown", cast_unknown)?;
   exports.create_named_method("getEnvVariable", get_env_variable)?;
   exports.create_named_method("throwSyntaxError", throw_syntax_error)?;
   exports.create_named_method("coerceToBool", coerce_to_bool)?;
+  exports.create_named_method("coerceToString", coerce_to_string)?;
+  exports.create_named_method("emitWarning", emit_warning)?;
+  exports.create_named_method("resolvedPromise", resolved_promise)?;
+  exports.create_named_method("rejectedPromise", rejected_promise)?;
+  exports.create_named_method("resolveAll", resolve_all)?;
+  exports.create_named_method("race", race)?;
+  exports.create_named_method("catchAndRethrow", catch_and_rethrow)?;
+  exports.create_named_method("readAndPropagate", read_and_propagate)?;
+  exports.create_named_method("writeAndPropagate", write_and_propagate)?;
+  exports.create_named_method("callWithContext", call_with_context)?;
+  exports.create_named_method("runCompiledScript", run_compiled_script)?;
   Ok(())
 }
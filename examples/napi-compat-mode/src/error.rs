@@ -1,4 +1,6 @@
-use napi::{CallContext, Error, JsBoolean, JsObject, JsString, JsUnknown, Result, Status};
+use napi::{
+  CallContext, Error, JsBoolean, JsError, JsNumber, JsObject, JsString, JsUnknown, Result, Status,
+};
 
 #[js_function]
 fn test_throw(_ctx: CallContext) -> Result<JsUnknown> {
@@ -14,6 +16,11 @@ fn test_throw_with_reason(ctx: CallContext) -> Result<JsUnknown> {
   ))
 }
 
+#[js_function]
+fn is_backtrace_enabled(ctx: CallContext) -> Result<JsBoolean> {
+  ctx.env.get_boolean(cfg!(feature = "backtrace"))
+}
+
 #[js_function]
 pub fn test_throw_with_panic(_ctx: CallContext) -> Result<JsUnknown> {
   panic!("don't panic.");
@@ -25,9 +32,47 @@ pub fn is_error(ctx: CallContext) -> Result<JsBoolean> {
   ctx.env.get_boolean(js_value.is_error()?)
 }
 
+#[js_function(1)]
+fn test_return_pending_exception(ctx: CallContext) -> Result<JsUnknown> {
+  ctx.env.throw(ctx.get::<JsUnknown>(0)?)?;
+  Err(Error::new(
+    Status::PendingException,
+    "Should not be thrown".to_owned(),
+  ))
+}
+
+#[js_function(1)]
+fn test_throw_pending_exception(ctx: CallContext) -> Result<JsUnknown> {
+  ctx.env.throw(ctx.get::<JsUnknown>(0)?)?;
+  JsError::from(Error::new(
+    Status::PendingException,
+    "Should not be thrown".to_owned(),
+  ))
+  .throw(ctx.env.raw())?;
+  ctx.env.get_undefined().map(|v| v.into_unknown())
+}
+
+#[js_function(1)]
+fn test_throw_with_props(ctx: CallContext) -> Result<JsUnknown> {
+  let status_code = ctx.get::<JsNumber>(0)?;
+  ctx.env.throw_error_with_props(
+    "Request failed",
+    Some("E_REQUEST"),
+    &[
+      ("retryable", ctx.env.get_boolean(true)?.into_unknown()),
+      ("statusCode", status_code.into_unknown()),
+    ],
+  )?;
+  ctx.env.get_undefined().map(|v| v.into_unknown())
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testThrow", test_throw)?;
   exports.create_named_method("testThrowWithReason", test_throw_with_reason)?;
+  exports.create_named_method("isBacktraceEnabled", is_backtrace_enabled)?;
   exports.create_named_method("isError", is_error)?;
+  exports.create_named_method("testReturnPendingException", test_return_pending_exception)?;
+  exports.create_named_method("testThrowPendingException", test_throw_pending_exception)?;
+  exports.create_named_method("testThrowWithProps", test_throw_with_props)?;
   Ok(())
 }
@@ -1,6 +1,6 @@
 use std::convert::TryInto;
 
-use napi::{CallContext, JsExternal, JsNumber, JsObject, Result};
+use napi::{CallContext, JsExternal, JsNumber, JsObject, JsString, Result};
 
 struct NativeObject {
   count: i32,
@@ -20,6 +20,12 @@ pub fn create_external_with_hint(ctx: CallContext) -> Result<JsExternal> {
   ctx.env.create_external(native, Some(5))
 }
 
+#[js_function(1)]
+pub fn create_external_string(ctx: CallContext) -> Result<JsExternal> {
+  let content = ctx.get::<JsString>(0)?.into_utf8()?.into_owned()?;
+  ctx.env.create_external(content, None)
+}
+
 #[js_function(1)]
 pub fn get_external_count(ctx: CallContext) -> Result<JsNumber> {
   let attached_obj = ctx.get::<JsExternal>(0)?;
@@ -30,6 +36,7 @@ pub fn get_external_count(ctx: CallContext) -> Result<JsNumber> {
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("createExternal", create_external)?;
   exports.create_named_method("createExternalWithHint", create_external_with_hint)?;
+  exports.create_named_method("createExternalString", create_external_string)?;
   exports.create_named_method("getExternalCount", get_external_count)?;
   Ok(())
 }
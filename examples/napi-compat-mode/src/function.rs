@@ -1,4 +1,12 @@
-use napi::{CallContext, JsError, JsFunction, JsNull, JsObject, JsUnknown, Result};
+use std::cell::RefCell;
+
+use napi::{
+  CallContext, JsError, JsFunction, JsNull, JsNumber, JsObject, JsUndefined, JsUnknown, Ref, Result,
+};
+
+thread_local! {
+  static STORED_FUNCTION: RefCell<Option<Ref<JsFunction>>> = RefCell::new(None);
+}
 
 #[js_function(1)]
 pub fn call_function(ctx: CallContext) -> Result<JsNull> {
@@ -43,6 +51,50 @@ pub fn call_function_error(ctx: CallContext) -> Result<JsUnknown> {
   }
 }
 
+#[js_function]
+pub fn get_all_args_types(ctx: CallContext) -> Result<JsObject> {
+  let args = ctx.get_all_args()?;
+  let mut types = ctx.env.create_array_with_length(args.len())?;
+  for (index, arg) in args.iter().enumerate() {
+    let value_type = ctx.env.create_string(&arg.get_type()?.to_string())?;
+    types.set_element(index as u32, value_type)?;
+  }
+  Ok(types)
+}
+
+#[js_function(1)]
+fn add_one(ctx: CallContext) -> Result<JsNumber> {
+  let n: u32 = ctx.get::<JsNumber>(0)?.get_uint32()?;
+  ctx.env.create_uint32(n + 1)
+}
+
+#[js_function(0)]
+pub fn store_function(ctx: CallContext) -> Result<JsUndefined> {
+  let reference = ctx.env.create_function_reference("addOne", add_one)?;
+  if let Some(mut previous) = STORED_FUNCTION.with(|f| f.borrow_mut().replace(reference)) {
+    previous.unref(*ctx.env)?;
+  }
+  ctx.env.get_undefined()
+}
+
+#[js_function(1)]
+pub fn call_stored_function(ctx: CallContext) -> Result<JsUnknown> {
+  let arg = ctx.get::<JsNumber>(0)?;
+  let func = STORED_FUNCTION.with(|f| match f.borrow().as_ref() {
+    Some(reference) => reference.get_value(ctx.env),
+    None => Err(napi::Error::from_reason("No function stored")),
+  })?;
+  func.call(None, &[arg])
+}
+
+#[js_function(0)]
+pub fn release_stored_function(ctx: CallContext) -> Result<JsUndefined> {
+  if let Some(mut reference) = STORED_FUNCTION.with(|f| f.borrow_mut().take()) {
+    reference.unref(*ctx.env)?;
+  }
+  ctx.env.get_undefined()
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testCallFunction", call_function)?;
   exports.create_named_method(
@@ -51,5 +103,9 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
   )?;
   exports.create_named_method("testCallFunctionWithThis", call_function_with_this)?;
   exports.create_named_method("testCallFunctionError", call_function_error)?;
+  exports.create_named_method("testGetAllArgsTypes", get_all_args_types)?;
+  exports.create_named_method("testStoreFunction", store_function)?;
+  exports.create_named_method("testCallStoredFunction", call_stored_function)?;
+  exports.create_named_method("testReleaseStoredFunction", release_stored_function)?;
   Ok(())
 }
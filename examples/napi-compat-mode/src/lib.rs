@@ -33,6 +33,7 @@ mod external;
 mod function;
 mod global;
 mod napi_version;
+mod number;
 mod object;
 mod serde;
 mod string;
@@ -58,6 +59,7 @@ fn init(mut exports: JsObject, env: Env) -> Result<()> {
   class::register_js(&mut exports)?;
   env::register_js(&mut exports)?;
   object::register_js(&mut exports)?;
+  number::register_js(&mut exports)?;
   global::register_js(&mut exports)?;
   cleanup_env::register_js(&mut exports)?;
   #[cfg(feature = "latest")]
@@ -10,6 +10,9 @@ use tsfn_dua_instance::*;
 pub fn register_js(exports: &mut JsObject, env: &Env) -> Result<()> {
   exports.create_named_method("testThreadsafeFunction", test_threadsafe_function)?;
   exports.create_named_method("testTsfnError", test_tsfn_error)?;
+  exports.create_named_method("testTsfnErrorHandler", test_tsfn_error_handler)?;
+  exports.create_named_method("takeHandledTsfnErrors", take_handled_tsfn_errors)?;
+  exports.create_named_method("testTsfnWithThis", test_tsfn_with_this)?;
   exports.create_named_method("testTokioReadfile", test_tokio_readfile)?;
   exports.create_named_method(
     "testAbortThreadsafeFunction",
@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::path::Path;
 use std::thread;
 
@@ -117,6 +118,61 @@ pub fn test_tsfn_error(ctx: CallContext) -> Result<JsUndefined> {
   ctx.env.get_undefined()
 }
 
+#[js_function(1)]
+pub fn test_tsfn_with_this(ctx: CallContext) -> Result<JsUndefined> {
+  let func = ctx.get::<JsFunction>(0)?;
+  let tsfn =
+    ctx
+      .env
+      .create_threadsafe_function_with_this(&func, 0, |ctx: ThreadSafeCallContext<u32>| {
+        let mut this = ctx.env.create_object()?;
+        this.set_named_property("source", ctx.env.create_string("tsfn")?)?;
+        Ok((Some(this), vec![ctx.env.create_uint32(ctx.value)?]))
+      })?;
+  thread::spawn(move || {
+    tsfn.call(Ok(42), ThreadsafeFunctionCallMode::Blocking);
+  });
+
+  ctx.env.get_undefined()
+}
+
+thread_local! {
+  // the error handler is called on the JavaScript thread
+  static HANDLED_TSFN_ERRORS: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+#[js_function(1)]
+pub fn test_tsfn_error_handler(ctx: CallContext) -> Result<JsUndefined> {
+  let func = ctx.get::<JsFunction>(0)?;
+  let tsfn = ctx.env.create_threadsafe_function_with_error_handler(
+    &func,
+    0,
+    |ctx: ThreadSafeCallContext<u32>| ctx.env.create_uint32(ctx.value).map(|v| vec![v]),
+    |err| {
+      HANDLED_TSFN_ERRORS.with(|errors| {
+        errors
+          .borrow_mut()
+          .push(format!("{}: {}", err.status, err.reason))
+      })
+    },
+  )?;
+  thread::spawn(move || {
+    tsfn.call(Ok(1), ThreadsafeFunctionCallMode::Blocking);
+  });
+
+  ctx.env.get_undefined()
+}
+
+#[js_function(0)]
+pub fn take_handled_tsfn_errors(ctx: CallContext) -> Result<JsObject> {
+  let errors = HANDLED_TSFN_ERRORS.with(|errors| errors.take());
+  let mut arr = ctx.env.create_array_with_length(errors.len())?;
+  for (i, error) in errors.into_iter().enumerate() {
+    arr.set_element(i as u32, ctx.env.create_string_from_std(error)?)?;
+  }
+  Ok(arr)
+}
+
 async fn read_file_content(filepath: &Path) -> Result<Vec<u8>> {
   tokio::fs::read(filepath)
     .await
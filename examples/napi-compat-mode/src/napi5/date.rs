@@ -19,3 +19,10 @@ pub fn test_get_date_value(ctx: CallContext) -> Result<JsNumber> {
   let date = ctx.get::<JsDate>(0)?;
   ctx.env.create_double(date.value_of()?)
 }
+
+#[js_function(1)]
+pub fn test_date_roundtrip(ctx: CallContext) -> Result<JsNumber> {
+  let timestamp: f64 = ctx.get::<JsNumber>(0)?.try_into()?;
+  let date = ctx.env.create_date(timestamp)?;
+  ctx.env.create_double(date.value_of()?)
+}
@@ -0,0 +1,26 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use napi::{CallContext, JsObject, Result};
+
+#[js_function(0)]
+pub fn test_create_functions_with_shared_data(ctx: CallContext) -> Result<JsObject> {
+  let counter = Rc::new(Cell::new(0u32));
+  let increment = ctx.env.create_function_with_data(
+    "increment",
+    counter.clone(),
+    |ctx, counter: &Rc<Cell<u32>>| {
+      counter.set(counter.get() + 1);
+      ctx.env.get_undefined()
+    },
+  )?;
+  let get = ctx
+    .env
+    .create_function_with_data("get", counter, |ctx, counter: &Rc<Cell<u32>>| {
+      ctx.env.create_uint32(counter.get())
+    })?;
+  let mut functions = ctx.env.create_object()?;
+  functions.set_named_property("increment", increment)?;
+  functions.set_named_property("get", get)?;
+  Ok(functions)
+}
@@ -0,0 +1,24 @@
+use std::cell::Cell;
+
+use napi::{CallContext, JsObject, Result};
+
+#[js_function(1)]
+pub fn test_create_range_iterable(ctx: CallContext) -> Result<JsObject> {
+  let end: u32 = ctx.get(0)?;
+  let current = Cell::new(0u32);
+  let next = ctx.env.create_function_from_closure("next", move |ctx| {
+    let mut result = ctx.env.create_object()?;
+    let value = current.get();
+    if value < end {
+      current.set(value + 1);
+      result.set_named_property("done", ctx.env.get_boolean(false)?)?;
+      result.set_named_property("value", ctx.env.create_uint32(value)?)?;
+    } else {
+      result.set_named_property("done", ctx.env.get_boolean(true)?)?;
+    }
+    Ok(result)
+  })?;
+  let mut iterable = ctx.env.create_object()?;
+  ctx.env.make_iterable(&mut iterable, next)?;
+  Ok(iterable)
+}
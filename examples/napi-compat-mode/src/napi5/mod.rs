@@ -1,10 +1,34 @@
 use napi::{JsObject, Result};
 
 mod date;
+mod function_with_data;
+mod iterable;
+mod unhandled_rejection;
 
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testObjectIsDate", date::test_object_is_date)?;
   exports.create_named_method("testCreateDate", date::test_create_date)?;
   exports.create_named_method("testGetDateValue", date::test_get_date_value)?;
+  exports.create_named_method("testDateRoundtrip", date::test_date_roundtrip)?;
+  exports.create_named_method(
+    "testCreateFunctionsWithSharedData",
+    function_with_data::test_create_functions_with_shared_data,
+  )?;
+  exports.create_named_method(
+    "testCreateRangeIterable",
+    iterable::test_create_range_iterable,
+  )?;
+  exports.create_named_method(
+    "testTrackUnhandledRejections",
+    unhandled_rejection::test_track_unhandled_rejections,
+  )?;
+  exports.create_named_method(
+    "testUntrackUnhandledRejections",
+    unhandled_rejection::test_untrack_unhandled_rejections,
+  )?;
+  exports.create_named_method(
+    "testGetUnhandledRejections",
+    unhandled_rejection::test_get_unhandled_rejections,
+  )?;
   Ok(())
 }
@@ -0,0 +1,41 @@
+use std::cell::RefCell;
+
+use napi::{CallContext, JsObject, JsUndefined, PromiseRejectionCallback, Result};
+
+thread_local! {
+  static UNHANDLED_REJECTIONS: RefCell<Vec<String>> = RefCell::new(Vec::new());
+  static REJECTION_CALLBACKS: RefCell<Vec<PromiseRejectionCallback>> = RefCell::new(Vec::new());
+}
+
+#[js_function(0)]
+pub fn test_track_unhandled_rejections(ctx: CallContext) -> Result<JsUndefined> {
+  let callback = ctx
+    .env
+    .add_promise_rejection_callback(|_env, reason, _promise| {
+      let reason = reason.coerce_to_string()?.into_utf8()?.into_owned()?;
+      UNHANDLED_REJECTIONS.with(|rejections| rejections.borrow_mut().push(reason));
+      Ok(())
+    })?;
+  REJECTION_CALLBACKS.with(|callbacks| callbacks.borrow_mut().push(callback));
+  ctx.env.get_undefined()
+}
+
+#[js_function(0)]
+pub fn test_untrack_unhandled_rejections(ctx: CallContext) -> Result<JsUndefined> {
+  if let Some(callback) = REJECTION_CALLBACKS.with(|callbacks| callbacks.borrow_mut().pop()) {
+    ctx.env.remove_promise_rejection_callback(callback)?;
+  }
+  ctx.env.get_undefined()
+}
+
+#[js_function(0)]
+pub fn test_get_unhandled_rejections(ctx: CallContext) -> Result<JsObject> {
+  UNHANDLED_REJECTIONS.with(|rejections| {
+    let rejections = rejections.borrow();
+    let mut array = ctx.env.create_array_with_length(rejections.len())?;
+    for (i, reason) in rejections.iter().enumerate() {
+      array.set_element(i as u32, ctx.env.create_string(reason)?)?;
+    }
+    Ok(array)
+  })
+}
@@ -53,3 +53,12 @@ pub fn test_get_bigint_words(ctx: CallContext) -> Result<JsObject> {
   js_arr.set_element(1, ctx.env.create_bigint_from_u64(words[1])?)?;
   Ok(js_arr)
 }
+
+#[js_function(1)]
+pub fn test_create_bigint_array_i64(ctx: CallContext) -> Result<JsObject> {
+  let js_arr = ctx.get::<JsObject>(0)?;
+  let values = (0..js_arr.get_array_length()?)
+    .map(|i| i64::try_from(js_arr.get_element::<JsBigInt>(i)?))
+    .collect::<Result<Vec<i64>>>()?;
+  ctx.env.create_bigint_array_i64(&values)
+}
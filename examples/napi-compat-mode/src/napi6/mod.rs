@@ -2,9 +2,11 @@ use napi::{JsObject, Result};
 
 mod bigint;
 mod instance;
+mod property_names;
 
 use bigint::*;
 use instance::*;
+use property_names::*;
 
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testCreateBigintFromI64", test_create_bigint_from_i64)?;
@@ -15,9 +17,12 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testGetBigintI64", test_get_bigint_i64)?;
   exports.create_named_method("testGetBigintU64", test_get_bigint_u64)?;
   exports.create_named_method("testGetBigintWords", test_get_bigint_words)?;
+  exports.create_named_method("testCreateBigintArrayI64", test_create_bigint_array_i64)?;
 
   exports.create_named_method("setInstanceData", set_instance_data)?;
   exports.create_named_method("getInstanceData", get_instance_data)?;
   exports.create_named_method("getWrongTypeInstanceData", get_wrong_type_instance_data)?;
+
+  exports.create_named_method("testGetAllPropertyNames", test_get_all_property_names)?;
   Ok(())
 }
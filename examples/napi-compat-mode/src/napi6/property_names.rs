@@ -0,0 +1,19 @@
+use napi::*;
+
+#[js_function(3)]
+pub fn test_get_all_property_names(ctx: CallContext) -> Result<JsObject> {
+  let obj = ctx.get::<JsObject>(0)?;
+  let include_prototypes = ctx.get::<JsBoolean>(1)?.get_value()?;
+  let enumerable_strings_only = ctx.get::<JsBoolean>(2)?.get_value()?;
+  let mode = if include_prototypes {
+    KeyCollectionMode::IncludePrototypes
+  } else {
+    KeyCollectionMode::OwnOnly
+  };
+  let filter = if enumerable_strings_only {
+    KeyFilter::Enumerable | KeyFilter::SkipSymbols
+  } else {
+    KeyFilter::AllProperties.into()
+  };
+  obj.get_all_property_names(mode, filter, KeyConversion::NumbersToStrings)
+}
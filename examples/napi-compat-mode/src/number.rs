@@ -0,0 +1,23 @@
+use napi::{CallContext, JsNumber, JsObject, JsString, Result};
+
+#[js_function(1)]
+pub fn number_to_bits(ctx: CallContext) -> Result<JsString> {
+  let number = ctx.get::<JsNumber>(0)?;
+  ctx
+    .env
+    .create_string_from_std(format!("{:016x}", number.to_bits()?))
+}
+
+#[js_function(1)]
+pub fn create_double_from_bits(ctx: CallContext) -> Result<JsNumber> {
+  let bits = ctx.get::<JsString>(0)?.into_utf8()?;
+  let bits = u64::from_str_radix(bits.as_str()?, 16)
+    .map_err(|e| napi::Error::from_reason(format!("Invalid bits: {}", e)))?;
+  ctx.env.create_double_from_bits(bits)
+}
+
+pub fn register_js(exports: &mut JsObject) -> Result<()> {
+  exports.create_named_method("numberToBits", number_to_bits)?;
+  exports.create_named_method("createDoubleFromBits", create_double_from_bits)?;
+  Ok(())
+}
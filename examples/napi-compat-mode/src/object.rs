@@ -26,6 +26,22 @@ fn test_get_named_property(ctx: CallContext) -> Result<JsUnknown> {
   obj.get_named_property("p")
 }
 
+#[js_function(2)]
+fn test_get_named_property_u32(ctx: CallContext) -> Result<JsNumber> {
+  let obj = ctx.get::<JsObject>(0)?;
+  let key = ctx.get::<JsString>(1)?.into_utf8()?;
+  ctx
+    .env
+    .create_uint32(obj.get_named_property::<u32>(key.as_str()?)?)
+}
+
+#[js_function(2)]
+fn test_get_named_property_string(ctx: CallContext) -> Result<JsString> {
+  let obj = ctx.get::<JsObject>(0)?;
+  let key = ctx.get::<JsString>(1)?.into_utf8()?;
+  obj.get_named_property::<JsString>(key.as_str()?)
+}
+
 #[js_function(2)]
 fn test_has_named_property(ctx: CallContext) -> Result<JsBoolean> {
   let obj = ctx.get::<JsObject>(0)?;
@@ -125,6 +141,35 @@ fn test_get_element(ctx: CallContext) -> Result<JsUnknown> {
   obj.get_element(index.try_into()?)
 }
 
+#[js_function(2)]
+fn test_get_string_element(ctx: CallContext) -> Result<JsString> {
+  let obj = ctx.get::<JsObject>(0)?;
+  let index = ctx.get::<JsNumber>(1)?;
+  obj.get_element::<JsString>(index.try_into()?)
+}
+
+#[js_function(1)]
+fn test_sum_elements(ctx: CallContext) -> Result<JsNumber> {
+  let obj = ctx.get::<JsObject>(0)?;
+  let mut sum = 0;
+  let mut index = 0;
+  while let Some(n) = obj.get_element::<Option<u32>>(index)? {
+    sum += n;
+    index += 1;
+  }
+  ctx.env.create_uint32(sum)
+}
+
+#[js_function(2)]
+fn test_fill_elements(ctx: CallContext) -> Result<JsUndefined> {
+  let mut obj = ctx.get::<JsObject>(0)?;
+  let len: u32 = ctx.get(1)?;
+  for i in 0..len {
+    obj.set_element(i, i * 2)?;
+  }
+  ctx.env.get_undefined()
+}
+
 #[js_function(2)]
 fn test_delete_element(ctx: CallContext) -> Result<JsBoolean> {
   let mut obj: JsObject = ctx.get(0)?;
@@ -169,6 +214,8 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
 
   exports.create_named_method("testSetNamedProperty", test_set_named_property)?;
   exports.create_named_method("testGetNamedProperty", test_get_named_property)?;
+  exports.create_named_method("testGetNamedPropertyU32", test_get_named_property_u32)?;
+  exports.create_named_method("testGetNamedPropertyString", test_get_named_property_string)?;
   exports.create_named_method("testHasNamedProperty", test_has_named_property)?;
 
   exports.create_named_method("testHasOwnProperty", test_has_own_property)?;
@@ -182,6 +229,9 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testSetElement", test_set_element)?;
   exports.create_named_method("testHasElement", test_has_element)?;
   exports.create_named_method("testGetElement", test_get_element)?;
+  exports.create_named_method("testGetStringElement", test_get_string_element)?;
+  exports.create_named_method("testSumElements", test_sum_elements)?;
+  exports.create_named_method("testFillElements", test_fill_elements)?;
   exports.create_named_method("testDeleteElement", test_delete_element)?;
   exports.create_named_method("testDefineProperties", test_define_properties)?;
 
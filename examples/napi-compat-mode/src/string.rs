@@ -1,4 +1,4 @@
-use napi::{CallContext, JsObject, JsString, Result};
+use napi::{CallContext, JsBoolean, JsObject, JsString, Result};
 
 #[js_function(1)]
 fn concat_string(ctx: CallContext) -> Result<JsString> {
@@ -30,10 +30,29 @@ fn create_latin1(ctx: CallContext) -> Result<JsString> {
   ctx.env.create_string_latin1(bytes.as_slice())
 }
 
+#[js_function(1)]
+fn code_points(ctx: CallContext) -> Result<JsObject> {
+  let in_string = ctx.get::<JsString>(0)?;
+  let code_points = in_string.chars()?.collect::<Vec<char>>();
+  let mut arr = ctx.env.create_array_with_length(code_points.len())?;
+  for (index, c) in code_points.into_iter().enumerate() {
+    arr.set_element(index as u32, ctx.env.create_uint32(c as u32)?)?;
+  }
+  Ok(arr)
+}
+
+#[js_function(1)]
+fn is_empty_string(ctx: CallContext) -> Result<JsBoolean> {
+  let in_string = ctx.get::<JsString>(0)?;
+  ctx.env.get_boolean(in_string.is_empty()?)
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("concatString", concat_string)?;
   exports.create_named_method("concatUTF16String", concat_utf16_string)?;
   exports.create_named_method("concatLatin1String", concat_latin1_string)?;
   exports.create_named_method("createLatin1", create_latin1)?;
+  exports.create_named_method("codePoints", code_points)?;
+  exports.create_named_method("isEmptyString", is_empty_string)?;
   Ok(())
 }
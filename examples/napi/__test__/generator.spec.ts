@@ -1,6 +1,25 @@
+import { setFlagsFromString } from 'v8'
+import { runInNewContext } from 'vm'
+
 import test from 'ava'
 
-import { Fib, Fib2, Fib3 } from '../index'
+import {
+  Fib,
+  Fib2,
+  Fib3,
+  NumberCollection,
+  countTo,
+  pulledItems,
+  failAt,
+  countToAsync,
+  droppedStreams,
+  failAtAsync,
+  splitLinesAsync,
+  Playlist,
+} from '../index'
+
+setFlagsFromString('--expose_gc')
+const gc: () => void = runInNewContext('gc')
 
 for (const [index, factory] of [
   () => new Fib(),
@@ -55,3 +74,121 @@ for (const [index, factory] of [
     })
   })
 }
+
+test('should iterate a Rust backed collection class with for...of', (t) => {
+  const collection = new NumberCollection([1, 2, 3])
+  const values: number[] = []
+  for (const value of collection) {
+    values.push(value)
+  }
+  t.deepEqual(values, [1, 2, 3])
+  collection.push(4)
+  t.deepEqual([...collection], [1, 2, 3, 4])
+  for (const value of collection) {
+    if (value === 2) {
+      break
+    }
+  }
+  t.deepEqual(Array.from(collection), [1, 2, 3, 4])
+  t.deepEqual([...new NumberCollection([])], [])
+})
+
+test('should pull items lazily from a returned iterator', (t) => {
+  const before = pulledItems()
+  const iterator = countTo(3)
+  t.is(pulledItems(), before)
+  for (const value of iterator) {
+    t.is(value, 1)
+    break
+  }
+  t.is(pulledItems(), before + 1)
+  t.is(iterator[Symbol.iterator](), iterator)
+  t.deepEqual(Object.getOwnPropertyNames(iterator), ['next'])
+  const rest: number[] = []
+  for (const value of iterator) {
+    rest.push(value)
+  }
+  t.deepEqual(rest, [2, 3])
+  t.is(pulledItems(), before + 3)
+  t.deepEqual(iterator.next(), { done: true, value: undefined })
+  t.deepEqual([...countTo(4)], [1, 2, 3, 4])
+  // `next` keeps its own iterator regardless of `this`
+  const { next } = countTo(2)
+  t.deepEqual(next.call({}), { done: false, value: 1 })
+  t.deepEqual(next.call(undefined), { done: false, value: 2 })
+})
+
+test('should throw the error items of a returned iterator', (t) => {
+  const iterator = failAt(1)
+  t.deepEqual(iterator.next(), { done: false, value: 0 })
+  t.throws(() => iterator.next(), { message: 'Failed at 1' })
+  t.deepEqual(iterator.next(), { done: false, value: 2 })
+})
+
+test('should consume a returned stream with for await', async (t) => {
+  const before = droppedStreams()
+  const values: number[] = []
+  for await (const value of countToAsync(3, 1)) {
+    values.push(value)
+  }
+  t.deepEqual(values, [1, 2, 3])
+  t.is(droppedStreams(), before + 1)
+
+  const iterator = countToAsync(100, 1)
+  t.is(iterator[Symbol.asyncIterator](), iterator)
+  t.deepEqual(Object.getOwnPropertyNames(iterator), ['next', 'return'])
+  const head: number[] = []
+  for await (const value of iterator) {
+    head.push(value)
+    if (value === 2) {
+      break
+    }
+  }
+  t.deepEqual(head, [1, 2])
+  // `break` calls `return()`, which drops the stream
+  t.is(droppedStreams(), before + 2)
+  t.deepEqual(await iterator.next(), { done: true, value: undefined })
+})
+
+test('should reject the error items of a returned stream', async (t) => {
+  const iterator = failAtAsync(1)
+  t.deepEqual(await iterator.next(), { done: false, value: 0 })
+  await t.throwsAsync(() => iterator.next(), { message: 'Failed at 1' })
+  t.deepEqual(await iterator.next(), { done: false, value: 2 })
+})
+
+test('should resolve a stream from async fn', async (t) => {
+  const lines: string[] = []
+  for await (const line of splitLinesAsync('foo\nbar\nbaz')) {
+    lines.push(line)
+  }
+  t.deepEqual(lines, ['foo', 'bar', 'baz'])
+
+  const iterator = splitLinesAsync('')
+  await t.throwsAsync(() => iterator.next(), { message: 'Nothing to split' })
+  t.deepEqual(await iterator.next(), { done: true, value: undefined })
+})
+
+test('should cancel the pending next() of a stream on return()', async (t) => {
+  const before = droppedStreams()
+  const iterator = countToAsync(1, 60000)
+  const pending = iterator.next()
+  t.deepEqual(await iterator.return!(), { done: true, value: undefined })
+  t.deepEqual(await pending, { done: true, value: undefined })
+  t.is(droppedStreams(), before + 1)
+})
+
+test('should keep `this` of an async fn alive while its stream is iterated', async (t) => {
+  // the `Playlist` itself is only referenced by the stream
+  const iterator = new Playlist(['foo', 'bar', 'baz']).play(1)
+  const songs: string[] = []
+  for (;;) {
+    gc()
+    const { done, value } = await iterator.next()
+    if (done) {
+      break
+    }
+    songs.push(value)
+  }
+  t.deepEqual(songs, ['foo', 'bar', 'baz'])
+})
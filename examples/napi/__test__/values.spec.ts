@@ -18,6 +18,7 @@ import {
   sumNums,
   getMapping,
   sumMapping,
+  doubleKindCounts,
   getCwd,
   Animal,
   Kind,
@@ -26,6 +27,8 @@ import {
   CustomNumEnum,
   Context,
   enumToI32,
+  Ordering,
+  reverseSortOrder,
   listObjKeys,
   createObj,
   mapOption,
@@ -49,6 +52,8 @@ import {
   createBigIntI64,
   bigintGetU64AsString,
   callThreadsafeFunction,
+  callThreadsafeFunctionWithPriority,
+  callThreadsafeFunctionWithPriorityQueueFull,
   threadsafeFunctionThrowError,
   threadsafeFunctionClosureCapture,
   tsfnCallWithCallback,
@@ -101,6 +106,7 @@ import {
   asyncReduceBuffer,
   callbackReturnPromise,
   returnEitherClass,
+  returnEitherClassInstance,
   eitherFromOption,
   eitherFromObjects,
   overrideIndividualArgOnFunction,
@@ -113,6 +119,7 @@ import {
   getNestedNumArr,
   CustomFinalize,
   plusOne,
+  greetThis,
   Width,
   captureErrorInCallback,
   bigintFromI128,
@@ -121,6 +128,91 @@ import {
   acceptThreadsafeFunctionFatal,
   promiseInEither,
   runScript,
+  createAdder,
+  createConcat,
+  debugObject,
+  scaleShape,
+  tsfnTryCall,
+  getTuple,
+  rotateQueue,
+  downcastAnyhowError,
+  halfF32,
+  halfCheckedF32,
+  Thermostat,
+  MathUtils,
+  loginPascalCaseObject,
+  loginKebabCaseObject,
+  Counter,
+  primesOrDefault,
+  sumRows,
+  createBird,
+  renameBird,
+  throwErrorWithContext,
+  throwErrorWithProperties,
+  reverseSmallVec,
+  smallVecSpilled,
+  refCellSnapshot,
+  refCellBorrowConflict,
+  sharedRefCellName,
+  mutexSnapshot,
+  sharedRwLockName,
+  poisonedMutex,
+  poisonedRwLock,
+  smartIntI64AddOne,
+  smartIntU64AddOne,
+  Greeter,
+  optionalVecLen,
+  spawnFutureMultiTwo,
+  reverseAttachment,
+  SharedResource,
+  createCounterInstance,
+  getNestedMapping,
+  getMappingWithHasher,
+  squareMapValues,
+  copyBuffer,
+  copyBufferThenClearSource,
+  countDown,
+  roundTripControlFlow,
+  controlFlowIsBreak,
+  nilValuesAreSingletons,
+  stringifyWithInjectedJson,
+  globalSettingName,
+  localGlobalSettingName,
+  cowStrOrDefault,
+  isCowStrOwned,
+  Vehicle,
+  Car,
+  countWheels,
+  compareNumbers,
+  reverseOrdering,
+  createBirds,
+  createCounters,
+  countJsonObjectKeys,
+  pickProperty,
+  parsePort,
+  parsePorts,
+  portRange,
+  validatePositive,
+  invertRgb,
+  scaleLabeledPoint,
+  describeRepository,
+  shapeArea,
+  elapsedAfterSleep,
+  renameImmutableUser,
+  sumIntegers,
+  parseFloatValue,
+  decodeUtf8,
+  cstringByteLength,
+  cstringFromBytes,
+  staticCstr,
+  getStringFromObjectRef,
+  jsStringRefLen,
+  urlHost,
+  normalizeUrl,
+  ByteChunk,
+  sharedStrRoundTrip,
+  sharedBytesRoundTrip,
+  getUint32WithBareStatus,
 } from '../'
 
 test('export const', (t) => {
@@ -139,6 +231,76 @@ test('number', (t) => {
   )
 })
 
+test('f32', (t) => {
+  t.is(halfF32(3), 1.5)
+  t.is(halfF32(1e300), Infinity)
+  t.is(halfCheckedF32(3), 1.5)
+  t.throws(() => halfCheckedF32(1e300), {
+    code: 'InvalidArg',
+    message: 'Value 1e300 is out of the range of f32',
+  })
+})
+
+test('Ordering', (t) => {
+  t.deepEqual([3, 1, 2, 1].sort(compareNumbers), [1, 1, 2, 3])
+  t.is(compareNumbers(1, 2), -1)
+  t.is(compareNumbers(2, 2), 0)
+  t.is(compareNumbers(3, 2), 1)
+  t.is(reverseOrdering(-1), 1)
+  t.is(reverseOrdering(0), 0)
+  t.is(reverseOrdering(1), -1)
+  // @ts-expect-error
+  t.is(reverseOrdering(-42), 1)
+  // @ts-expect-error
+  t.is(reverseOrdering(0.5), -1)
+  // @ts-expect-error
+  t.is(reverseOrdering(NaN), 0)
+})
+
+test('Cow<str>', (t) => {
+  t.is(cowStrOrDefault('JavaScript 🌳 你好'), 'JavaScript 🌳 你好')
+  t.is(cowStrOrDefault(), 'napi 🦀')
+  t.true(isCowStrOwned('ascii'))
+})
+
+test('CString', (t) => {
+  t.is(cstringByteLength('napi 🦀'), 9)
+  t.throws(() => cstringByteLength('na\0pi'), {
+    code: 'InvalidArg',
+    message:
+      'Failed to convert JavaScript string into rust type `CString`, nul byte found in provided data at position: 2',
+  })
+  t.is(cstringFromBytes([104, 105]), 'hi')
+  t.throws(() => cstringFromBytes([0xff]), {
+    code: 'InvalidArg',
+    message:
+      'Failed to convert rust `CStr` into napi `string`, invalid utf-8 sequence of 1 bytes from index 0',
+  })
+  t.is(staticCstr(), 'napi 🦀')
+})
+
+test('Arc<str> and Arc<[u8]>', (t) => {
+  t.is(sharedStrRoundTrip('napi 🦀'), 'napi 🦀')
+  t.is(sharedStrRoundTrip(''), '')
+  const bytes = Buffer.from([1, 2, 3])
+  const copied = sharedBytesRoundTrip(bytes)
+  t.deepEqual(copied, bytes)
+  copied[0] = 42
+  t.is(bytes[0], 1)
+  t.deepEqual(sharedBytesRoundTrip(Buffer.alloc(0)), Buffer.alloc(0))
+})
+
+test('url', (t) => {
+  t.is(urlHost('https://napi.rs/docs'), 'napi.rs')
+  t.is(urlHost(new URL('https://github.com/napi-rs')), 'github.com')
+  t.is(urlHost('file:///tmp/index.js'), null)
+  t.is(normalizeUrl('HTTPS://Napi.RS/docs/../cli'), 'https://napi.rs/cli')
+  t.throws(() => urlHost('not a url'), {
+    code: 'InvalidArg',
+    message: 'Failed to parse `not a url` as URL, relative URL without a base',
+  })
+})
+
 test('string', (t) => {
   t.true(contains('hello', 'ell'))
   t.false(contains('John', 'jn'))
@@ -162,16 +324,98 @@ test('array', (t) => {
   t.is(sumNums([1, 2, 3, 4, 5]), 15)
   t.deepEqual(getNumArr(), [1, 2])
   t.deepEqual(getNestedNumArr(), [[[1]], [[1]]])
+  t.deepEqual(getTuple(2), [3, '2', true])
+  t.deepEqual(rotateQueue(['a', 'b', 'c']), ['b', 'c', 'a'])
+  t.deepEqual(primesOrDefault([13, 17]), [13, 17])
+  t.deepEqual(primesOrDefault(), [2, 3, 5, 7, 11])
+})
+
+test('nested array', (t) => {
+  t.deepEqual(sumRows([[1, 2, 3], [], [4.5]]), [6, 0, 4.5])
+  t.deepEqual(
+    sumRows([
+      new Float64Array([1, 2]),
+      [3],
+      new Float64Array(0),
+      new Float64Array(new ArrayBuffer(32), 8, 2).fill(2),
+    ]),
+    [3, 3, 0, 4],
+  )
+  // @ts-expect-error
+  t.throws(() => sumRows([new Float32Array([1])]))
+  // @ts-expect-error
+  t.throws(() => sumRows([[1, 'a']]))
+})
+
+test('smallvec', (t) => {
+  t.deepEqual(reverseSmallVec([1, 2, 3]), [3, 2, 1])
+  t.false(smallVecSpilled([1, 2, 3, 4]))
+  const long = Array.from({ length: 10 }, (_, i) => i)
+  t.deepEqual(reverseSmallVec(long), [...long].reverse())
+  t.true(smallVecSpilled(long))
 })
 
 test('map', (t) => {
   t.deepEqual(getMapping(), { a: 101, b: 102 })
   t.is(sumMapping({ a: 101, b: 102 }), 203)
+  t.deepEqual(doubleKindCounts({ [Kind.Dog]: 1, [Kind.Duck]: 3 }), {
+    [Kind.Dog]: 2,
+    [Kind.Duck]: 6,
+  })
+  t.throws(() => doubleKindCounts({ Fish: 1 } as any), {
+    message: /Failed to convert property name `Fish` into PropertyMap key/,
+  })
+})
+
+test('nested HashMap and HashMap with hasher', (t) => {
+  t.deepEqual(getNestedMapping(), { point: { x: -1 } })
+  t.deepEqual(getMappingWithHasher(), { primes: [2, 3, 5] })
+})
+
+test('JsMap', (t) => {
+  const squared = squareMapValues(
+    new Map([
+      [2, 3],
+      [10, 4],
+    ]),
+  )
+  t.true(squared instanceof Map)
+  t.deepEqual(
+    [...squared.entries()].sort(([a], [b]) => a - b),
+    [
+      [2, 9],
+      [10, 16],
+    ],
+  )
+  t.deepEqual(squareMapValues(new Map()), new Map())
+  t.throws(() => squareMapValues({ 2: 3 } as any), {
+    code: 'InvalidArg',
+    message: 'Expect value to be a Map',
+  })
 })
 
 test('enum', (t) => {
   t.deepEqual([Kind.Dog, Kind.Cat, Kind.Duck], [0, 1, 2])
   t.is(enumToI32(CustomNumEnum.Eight), 8)
+  t.is(reverseSortOrder(Ordering.Ascending), Ordering.Descending)
+})
+
+test('discriminated union enum', (t) => {
+  t.deepEqual(scaleShape({ type: 'Circle', radius: 1 }, 2), {
+    type: 'Circle',
+    radius: 2,
+  })
+  t.deepEqual(
+    scaleShape({ type: 'Rectangle', width: 1, height: 2, label: 'box' }, 3),
+    { type: 'Rectangle', width: 3, height: 6, label: 'box' },
+  )
+  t.deepEqual(scaleShape({ type: 'Rectangle', width: 1, height: 2 }, 1), {
+    type: 'Rectangle',
+    width: 1,
+    height: 2,
+  })
+  // @ts-expect-error
+  t.throws(() => scaleShape({ type: 'Triangle' }, 1))
 })
 
 test('class', (t) => {
@@ -200,6 +444,7 @@ test('class', (t) => {
   t.is(dog.type, Kind.Dog)
   dog.type = Kind.Cat
   t.is(dog.type, Kind.Cat)
+  t.is(dog.kindName, 'Cat')
   const assets = new Assets()
   t.is(assets.get(1)?.filePath, 1)
   const turtle = NinjaTurtle.newRaph()
@@ -214,6 +459,92 @@ test('class', (t) => {
   })
 })
 
+test('function with typed this', (t) => {
+  t.is(greetThis.call({ name: 'Ada' }, 'Hello'), 'Hello, Ada!')
+  t.throws(
+    () => {
+      // @ts-expect-error
+      greetThis('Hello')
+    },
+    {
+      message: '`greetThis` must be called with an object as `this`, e.g. `greetThis.call(obj)`',
+    },
+  )
+})
+
+test('class with only static methods', (t) => {
+  t.is(MathUtils.clamp(5, 0, 3), 3)
+  t.is(MathUtils.lerp(0, 10, 0.25), 2.5)
+  t.throws(() => new MathUtils(), {
+    message: 'Class contains no `constructor`, can not new it!',
+  })
+})
+
+test('class getter and setter returning Result', (t) => {
+  const thermostat = new Thermostat(20)
+  t.is(thermostat.kelvin, 293.15)
+  t.throws(
+    () => {
+      thermostat.celsius = -300
+    },
+    { code: 'InvalidArg', message: 'Temperature is below absolute zero' },
+  )
+  t.is(thermostat.celsius, 20)
+  thermostat.celsius = 30
+  t.is(thermostat.celsius, 30)
+  const invalid = new Thermostat(-300)
+  t.throws(() => invalid.kelvin, {
+    code: 'GenericFailure',
+    message: 'Temperature is below absolute zero',
+  })
+})
+
+test('class method with &mut self', (t) => {
+  const counter = new Counter()
+  t.is(counter.increment(2), 2)
+  t.is(counter.increment(3), 5)
+  t.is(counter.count, 5)
+})
+
+test('class method consuming self', (t) => {
+  const chunk = new ByteChunk([1, 2, 3])
+  t.is(chunk.byteLength(), 3)
+  t.deepEqual(chunk.intoBytes(), Buffer.from([1, 2, 3]))
+  t.throws(() => chunk.byteLength(), {
+    code: 'InvalidArg',
+    message:
+      'Failed to unwrap exclusive reference of `ByteChunk` type from napi value',
+  })
+  t.throws(() => chunk.intoBytes(), { code: 'InvalidArg' })
+  const other = new ByteChunk([1])
+  t.throws(() => other.concat('2' as any))
+  t.deepEqual(other.concat([2]), Buffer.from([1, 2]))
+})
+
+test('class extends', (t) => {
+  const car = new Car('Tesla')
+  const vehicle = new Vehicle(2)
+  t.true(car instanceof Car)
+  t.true(car instanceof Vehicle)
+  t.false(vehicle instanceof Car)
+  t.is(Object.getPrototypeOf(Car.prototype), Vehicle.prototype)
+  t.is(Object.getPrototypeOf(Car), Vehicle)
+  // inherited from `Vehicle`
+  t.is(car.wheels, 4)
+  t.is(car.honk(), 'Beep')
+  // overridden in `Car`
+  t.is(car.describe(), 'Tesla car with 4 wheels')
+  t.is(vehicle.describe(), 'Vehicle with 2 wheels')
+  t.is(countWheels(car), 4)
+  t.is(countWheels(vehicle), 2)
+  // static methods are inherited, the inherited factory still creates a `Vehicle`
+  t.is(Vehicle.bicycle().wheels, 2)
+  const bicycle = Car.bicycle()
+  t.true(bicycle instanceof Vehicle)
+  t.false(bicycle instanceof Car)
+  t.is(bicycle.describe(), 'Vehicle with 2 wheels')
+})
+
 test('async self in class', async (t) => {
   const b = new Bird('foo')
   t.is(await b.getNameAsync(), 'foo')
@@ -268,6 +599,47 @@ test('class in object field', (t) => {
   t.is(receiveObjectWithClassField(obj), obj.bird)
 })
 
+test('class instance as argument', (t) => {
+  const bird = createBird('Tweety')
+  const renamed = renameBird(bird, ' II')
+  t.is(renamed, bird)
+  t.true(renamed instanceof Bird)
+  t.is(renamed.name, 'Tweety II')
+  t.is(renameBird(new Bird('Polly'), '!').name, 'Polly!')
+  // @ts-expect-error
+  t.throws(() => renameBird(new Dog('Doge'), ''), {
+    code: 'InvalidArg',
+    message: 'Value is not instanceof class `Bird`',
+  })
+})
+
+test('array of class instances', (t) => {
+  const birds = createBirds(['Tweety', 'Polly', 'Zazu'])
+  t.is(birds.length, 3)
+  for (const bird of birds) {
+    t.true(bird instanceof Bird)
+    t.is(bird.getCount(), 1234)
+  }
+  t.deepEqual(
+    birds.map((bird) => bird.name),
+    ['Tweety', 'Polly', 'Zazu'],
+  )
+  const counters = createCounters([1, 2, 3])
+  t.true(counters.every((counter) => counter instanceof Counter))
+  t.deepEqual(
+    counters.map((counter) => counter.increment(10)),
+    [11, 12, 13],
+  )
+})
+
+test('create class instance from Env::new_instance', (t) => {
+  const counter = createCounterInstance(5)
+  t.true(counter instanceof Counter)
+  t.is(Object.getPrototypeOf(counter), Counter.prototype)
+  t.is(counter.increment(2), 7)
+  t.is(counter.count, 7)
+})
+
 test('custom finalize class', (t) => {
   t.notThrows(() => new CustomFinalize(200, 200))
 })
@@ -288,6 +660,18 @@ test('should be able to into_reference', (t) => {
   t.is(anotherStyleSheet.rules, sheet.rules)
 })
 
+test('weak handle detects liveness', (t) => {
+  const resource = new SharedResource('db connection')
+  const handle = resource.weakHandle()
+  t.true(handle.isAlive())
+  // the `Weak` is kept by the native method, not in a property
+  t.deepEqual(Object.getOwnPropertyNames(handle), ['isAlive'])
+  t.true(handle.isAlive.call({}))
+  resource.release()
+  t.false(handle.isAlive())
+  t.false(resource.weakHandle().isAlive())
+})
+
 test('callback', (t) => {
   getCwd((cwd) => {
     t.is(cwd, process.cwd())
@@ -325,6 +709,13 @@ test('return function', (t) => {
   })
 })
 
+test('return closure', (t) => {
+  const add5 = createAdder(5)
+  t.is(add5(1), 6)
+  t.is(add5(10), 15)
+  t.is(createConcat('-')('a', 'b'), 'a-b')
+})
+
 test('function return Promise', async (t) => {
   const cbSpy = spy()
   await callbackReturnPromise<string>(() => '1', spy)
@@ -342,9 +733,60 @@ test('function return Promise', async (t) => {
 
 test('object', (t) => {
   t.deepEqual(listObjKeys({ name: 'John Doe', age: 20 }), ['name', 'age'])
+  const keys = { b: 1, a: 2, [Symbol('c')]: 3, 1: 4 }
+  Object.defineProperty(keys, 'hidden', { value: 5, enumerable: false })
+  t.deepEqual(listObjKeys(keys), ['1', 'b', 'a'])
+  const inherited = Object.assign(Object.create({ inherited: 1 }), { own: 2 })
+  t.deepEqual(listObjKeys(inherited), ['own'])
   t.deepEqual(createObj(), { test: 1 })
 })
 
+test('debug object with js field names', (t) => {
+  t.is(
+    debugObject({ userName: 'napi', loginCount: 2 }),
+    'DebuggableObject { userName: "napi", loginCount: 2 }',
+  )
+})
+
+test('object with js_name_all', (t) => {
+  t.deepEqual(loginPascalCaseObject({ UserName: 'napi', LoginCount: 1 }), {
+    UserName: 'napi',
+    LoginCount: 2,
+  })
+  t.deepEqual(
+    loginKebabCaseObject({ 'user-name': 'napi', 'login-count': 1, id: 7 }),
+    { 'user-name': 'napi', 'login-count': 2, id: 7 },
+  )
+  // @ts-expect-error
+  t.throws(() => loginPascalCaseObject({ userName: 'napi', loginCount: 1 }))
+})
+
+test('readonly object', (t) => {
+  t.deepEqual(renameImmutableUser({ name: 'napi', roles: ['admin'] }, 'rs'), {
+    name: 'rs',
+    roles: ['admin'],
+  })
+})
+
+test('object field as base64 string', (t) => {
+  const content = Buffer.from('hello world').toString('base64')
+  const reversed = reverseAttachment({ name: 'greeting.txt', content })
+  t.deepEqual(reversed, {
+    name: 'greeting.txt',
+    content: Buffer.from('dlrow olleh').toString('base64'),
+  })
+  t.deepEqual(reverseAttachment(reversed).content, content)
+  t.is(
+    reverseAttachment({ name: 'empty', content: '', thumbnail: 'AAEC' })
+      .thumbnail,
+    'AAEC',
+  )
+  t.throws(() => reverseAttachment({ name: 'broken', content: 'not base64!' }), {
+    code: 'InvalidArg',
+    message: /^Invalid base64 string/,
+  })
+})
+
 test('get str from object', (t) => {
   t.notThrows(() => getStrFromObject())
 })
@@ -355,10 +797,24 @@ test('create object from Property', (t) => {
   t.is(obj.getter, 42)
 })
 
+test('borrowed JsValue arguments', (t) => {
+  t.is(getStringFromObjectRef({ name: 'napi' }, 'name'), 'napi')
+  t.is(getStringFromObjectRef({}, 'name'), null)
+  t.is(jsStringRefLen('napi 🦀'), 9)
+})
+
 test('global', (t) => {
   t.is(getGlobal(), global)
 })
 
+test('inject JSON global', (t) => {
+  t.is(stringifyWithInjectedJson({ a: [1, 'b'] }), '{"a":[1,"b"]}')
+  t.is(stringifyWithInjectedJson('napi'), '"napi"')
+  // other types named `Global` are regular arguments
+  t.is(globalSettingName({ name: 'napi' }), 'napi')
+  t.is(localGlobalSettingName({ name: 'napi' }), 'napi')
+})
+
 test('get undefined', (t) => {
   for (const _ of Array.from({ length: 100 })) {
     t.is(getUndefined(), undefined)
@@ -379,6 +835,10 @@ test('return Undefined', (t) => {
   t.is(returnUndefined(), undefined)
 })
 
+test('null and undefined are singletons', (t) => {
+  t.true(nilValuesAreSingletons(null, undefined))
+})
+
 test('pass symbol in', (t) => {
   const sym = Symbol('test')
   const obj = setSymbolInObj(sym)
@@ -394,6 +854,46 @@ test('Option', (t) => {
   t.is(mapOption(3), 4)
 })
 
+test('Option<Vec> distinguishes null from empty array', (t) => {
+  t.is(optionalVecLen(null), null)
+  t.is(optionalVecLen(undefined), null)
+  t.is(optionalVecLen(), null)
+  t.is(optionalVecLen([]), 0)
+  t.is(optionalVecLen([1, 2, 3]), 3)
+})
+
+test('RefCell snapshot', (t) => {
+  t.deepEqual(refCellSnapshot(), [
+    [1, 2],
+    [1, 2, 3],
+  ])
+  t.is(sharedRefCellName(), 'napi')
+  t.throws(() => refCellBorrowConflict(), {
+    code: 'GenericFailure',
+    message: /already mutably borrowed/,
+  })
+})
+
+test('Mutex and RwLock snapshot', (t) => {
+  t.deepEqual(mutexSnapshot(), [
+    [1, 2],
+    [1, 2, 3],
+  ])
+  t.is(sharedRwLockName(), 'napi')
+  if (!process.env.SKIP_UNWIND_TEST) {
+    t.throws(() => poisonedMutex(), {
+      code: 'GenericFailure',
+      message:
+        'Failed to convert `Mutex<u32>` into napi value, the lock is poisoned',
+    })
+    t.throws(() => poisonedRwLock(), {
+      code: 'GenericFailure',
+      message:
+        'Failed to convert `RwLock<u32>` into napi value, the lock is poisoned',
+    })
+  }
+})
+
 test('Result', (t) => {
   t.throws(() => throwError(), void 0, 'Manual Error')
   if (!process.env.SKIP_UNWIND_TEST) {
@@ -401,8 +901,118 @@ test('Result', (t) => {
   }
 })
 
+test('error with context', (t) => {
+  t.throws(() => throwErrorWithContext('config.toml', 3), {
+    code: 'InvalidArg',
+    message: 'Failed to read config, path=config.toml, line=3',
+  })
+})
+
+test('error with properties', (t) => {
+  const err = t.throws(() => throwErrorWithProperties(503), {
+    code: 'GenericFailure',
+    message: 'Service unavailable',
+  })
+  t.true((err as any).retryable)
+  t.is((err as any).statusCode, 503)
+  t.false((t.throws(() => throwErrorWithProperties(404)) as any).retryable)
+})
+
+test('bare status error', (t) => {
+  t.is(getUint32WithBareStatus(42), 42)
+  t.throws(() => getUint32WithBareStatus('42'), {
+    code: 'NumberExpected',
+    message: 'A number was expected',
+  })
+})
+
+test('std parse errors', (t) => {
+  t.is(sumIntegers('1, 2, 3'), 6)
+  t.throws(() => sumIntegers('1, two'), {
+    code: 'InvalidArg',
+    message: 'invalid digit found in string',
+  })
+  t.is(parseFloatValue('1.5'), 1.5)
+  t.throws(() => parseFloatValue('one'), {
+    code: 'InvalidArg',
+    message: 'invalid float literal',
+  })
+  t.is(decodeUtf8([104, 105]), 'hi')
+  t.throws(() => decodeUtf8([0xff]), {
+    code: 'InvalidArg',
+    message: 'invalid utf-8 sequence of 1 bytes from index 0',
+  })
+})
+
+test('return result object', (t) => {
+  t.deepEqual(parsePort('8080'), { ok: true, value: 8080 })
+  const failed = parsePort('http')
+  t.false(failed.ok)
+  if (!failed.ok) {
+    t.true(failed.error instanceof Error)
+    t.is(
+      failed.error.message,
+      'Invalid port `http`: invalid digit found in string',
+    )
+    t.is((failed.error as any).code, 'InvalidArg')
+  }
+  t.false('error' in parsePort('1'))
+  t.false('value' in parsePort('65536'))
+
+  t.deepEqual(validatePositive(1), { ok: true, value: undefined })
+  const negative = validatePositive(-1)
+  t.false(negative.ok)
+  if (!negative.ok) {
+    t.is(negative.error.message, '-1 is not positive')
+  }
+
+  const range = portRange(80, 82)
+  t.true(range.ok)
+  if (range.ok) {
+    t.deepEqual([...range.value], [80, 81, 82])
+  }
+  const invalidRange = portRange(82, 80)
+  t.false(invalidRange.ok)
+  if (!invalidRange.ok) {
+    t.is(invalidRange.error.message, 'Invalid port range 82-80')
+  }
+})
+
+test('settled results', (t) => {
+  const results = parsePorts(['80', 'http', '443'])
+  t.is(results.length, 3)
+  t.deepEqual(results[0], { status: 'fulfilled', value: 80 })
+  t.deepEqual(results[2], { status: 'fulfilled', value: 443 })
+  const rejected = results[1]
+  t.is(rejected.status, 'rejected')
+  if (rejected.status === 'rejected') {
+    t.true(rejected.reason instanceof Error)
+    t.is(
+      rejected.reason.message,
+      'Invalid port `http`: invalid digit found in string',
+    )
+    t.false('value' in rejected)
+  }
+  t.deepEqual(parsePorts([]), [])
+})
+
+test('downcast anyhow error', (t) => {
+  t.deepEqual(downcastAnyhowError(3), {
+    line: 3,
+    isIoError: false,
+    chain: ['Failed to load config', 'Invalid config at line 3'],
+  })
+})
+
 test('function ts type override', (t) => {
   t.deepEqual(tsRename({ foo: 1, bar: 2, baz: 2 }), ['foo', 'bar', 'baz'])
+  t.is(pickProperty({ foo: 1, bar: 'baz' }, 'bar'), 'baz')
+})
+
+test('method overload signatures share one implementation', (t) => {
+  const greeter = new Greeter('Hello')
+  t.is(greeter.greet('Ferris'), 'Hello, Ferris!')
+  t.is(greeter.greet(['Ferris', 'Corro']), 'Hello, Ferris and Corro!')
 })
 
 test('function individual ts arg type override', (t) => {
@@ -455,6 +1065,27 @@ test('serde-json', (t) => {
   t.is(getPackageJsonName(packageJson), 'napi-rs')
 })
 
+test('tuple object', (t) => {
+  t.deepEqual(invertRgb([0, 128, 255]), [255, 127, 0])
+  t.deepEqual(invertRgb(invertRgb([1, 2, 3])), [1, 2, 3])
+  t.deepEqual(scaleLabeledPoint(['a', 1.5, 3], 2), ['a', 3, 6])
+  t.deepEqual(scaleLabeledPoint(['b', 1.5, null], 2), ['b', 3, null])
+  t.deepEqual(scaleLabeledPoint(['c', 1.5, undefined], 2), ['c', 3, null])
+
+  // @ts-expect-error
+  t.throws(() => invertRgb([1, 2]), {
+    code: 'InvalidArg',
+    message: 'Expected `Rgb` to be an array of length 3, got 2',
+  })
+  // @ts-expect-error
+  t.throws(() => invertRgb([1, 2, 3, 4]), {
+    code: 'InvalidArg',
+    message: 'Expected `Rgb` to be an array of length 3, got 4',
+  })
+  // @ts-expect-error
+  t.throws(() => invertRgb({ 0: 1, 1: 2, 2: 3 }))
+})
+
 test('serde-roundtrip', (t) => {
   t.is(testSerdeRoundtrip(1), 1)
   t.is(testSerdeRoundtrip(1.2), 1.2)
@@ -468,6 +1099,17 @@ test('serde-roundtrip', (t) => {
   })
   t.throws(() => testSerdeRoundtrip(NaN))
 
+  const nested = {
+    a: { b: [1, { c: 'd', e: [true, null] }], f: {} },
+    g: [[], [{}], [[2]]],
+    h: null,
+  }
+  t.deepEqual(testSerdeRoundtrip(nested), nested)
+  t.deepEqual(
+    testSerdeRoundtrip({ a: 1, b: undefined, c: { d: undefined } }),
+    { a: 1, c: {} },
+  )
+
   t.is(testSerdeRoundtrip(null), null)
 
   let err = t.throws(() => testSerdeRoundtrip(undefined))
@@ -483,6 +1125,53 @@ test('serde-roundtrip', (t) => {
   t.is(err!.message, 'JS symbols cannot be represented as a serde_json::Value')
 })
 
+test('serde_json::Map', (t) => {
+  t.is(countJsonObjectKeys({}), 0)
+  t.is(
+    countJsonObjectKeys({
+      a: { b: 1, c: [{ d: null }, { e: [{ f: 'g' }] }] },
+      h: null,
+      i: undefined,
+    }),
+    7,
+  )
+  const withSymbol = { a: 1, [Symbol.for('b')]: 2 }
+  Object.defineProperty(withSymbol, 'c', { value: 3, enumerable: false })
+  t.is(countJsonObjectKeys(withSymbol), 1)
+  const inherited = Object.assign(Object.create({ inherited: 1 }), { own: 2 })
+  t.is(countJsonObjectKeys(inherited), 1)
+  t.deepEqual(testSerdeRoundtrip(inherited), { own: 2 })
+  t.throws(() => countJsonObjectKeys({ a: () => {} }), {
+    message: 'JS functions cannot be represented as a serde_json::Value',
+  })
+})
+
+test('from_serde', (t) => {
+  t.is(
+    describeRepository({
+      name: 'napi-rs',
+      owner: { login: 'napi-rs' },
+      stargazersCount: 3,
+      topics: ['rust', 'node'],
+    }),
+    'napi-rs/napi-rs (3 stars) [rust, node]',
+  )
+  t.is(
+    describeRepository({ name: 'napi-rs', owner: { login: 'Brooooooklyn' } }),
+    'Brooooooklyn/napi-rs (0 stars) []',
+  )
+  t.throws(() => describeRepository({ name: 'napi-rs' } as any), {
+    message:
+      'Failed to deserialize napi value into `Repository`. missing field `owner`',
+  })
+  t.is(shapeArea({ kind: 'rect', width: 2, height: 3 }), 6)
+  t.is(shapeArea({ kind: 'circle', radius: 1 }), Math.PI)
+  t.throws(() => shapeArea({ kind: 'triangle' }), {
+    message:
+      'Failed to deserialize napi value into `Shape`. unknown variant `triangle`, expected `circle` or `rect`',
+  })
+})
+
 test('buffer', (t) => {
   let buf = getBuffer()
   t.is(buf.toString('utf-8'), 'Hello world')
@@ -495,6 +1184,20 @@ test('buffer', (t) => {
   t.is(b.toString(), '')
 })
 
+test('copied buffer is independent of the source', (t) => {
+  t.is(copyBufferThenClearSource().toString(), 'copied')
+
+  const source = Buffer.from('hello')
+  const copied = copyBuffer(source)
+  t.deepEqual(copied, source)
+  t.not(copied.buffer, source.buffer)
+  source.fill(0)
+  t.is(copied.toString(), 'hello')
+  copied.write('J')
+  t.is(source[0], 0)
+  t.is(copyBuffer(Buffer.alloc(0)).length, 0)
+})
+
 test('reset empty buffer', (t) => {
   const empty = getEmptyBuffer()
 
@@ -541,6 +1244,32 @@ test('async move', async (t) => {
   t.is(await asyncMultiTwo(2), 4)
 })
 
+test('spawn future from a sync fn', async (t) => {
+  const promise = spawnFutureMultiTwo(21)
+  t.true(promise instanceof Promise)
+  t.is(await promise, 42)
+  await t.throwsAsync(spawnFutureMultiTwo(0), {
+    code: 'InvalidArg',
+    message: 'arg must not be zero',
+  })
+})
+
+test('async fn rejects instead of throwing', async (t) => {
+  let promise: Promise<number> | undefined
+  t.notThrows(() => {
+    // @ts-expect-error
+    promise = asyncMultiTwo('2')
+  })
+  t.true(promise instanceof Promise)
+  await t.throwsAsync(promise!, {
+    code: 'NumberExpected',
+    message: 'Failed to convert napi value String into rust type `u32`',
+  })
+  const bodyError = readFileAsync('some_nonexist_path.file')
+  t.true(bodyError instanceof Promise)
+  await t.throwsAsync(bodyError, { message: /^failed to read file/ })
+})
+
 test('buffer passthrough', async (t) => {
   const fixture = Buffer.from('hello world')
   const ret = await bufferPassThrough(fixture)
@@ -591,6 +1320,15 @@ test('return either class', (t) => {
   t.true(returnEitherClass(-1) instanceof JsClassForEither)
 })
 
+test('return either class instance', (t) => {
+  const instance = returnEitherClassInstance(false)
+  t.true(instance instanceof JsClassForEither)
+  t.false(instance instanceof AnotherClassForEither)
+  const another = returnEitherClassInstance(true)
+  t.true(another instanceof AnotherClassForEither)
+  t.false(another instanceof JsClassForEither)
+})
+
 test('either from option', (t) => {
   t.true(eitherFromOption() instanceof JsClassForEither)
 })
@@ -628,9 +1366,10 @@ test('external', (t) => {
   const ext2 = createExternalString('wtf')
   // @ts-expect-error
   const e = t.throws(() => getExternal(ext2))
+  t.is((e as any).code, 'InvalidArg')
   t.is(
     e!.message,
-    'T on `get_value_external` is not the type of wrapped object',
+    'u32 on `get_value_external` is not the type of wrapped object',
   )
 })
 
@@ -699,6 +1438,44 @@ BigIntTest('from i128 i64', (t) => {
   t.is(bigintFromI128(), BigInt('-100'))
 })
 
+BigIntTest('SmartInt switches to BigInt outside the safe integer range', (t) => {
+  t.is(smartIntI64AddOne(1), 2)
+  t.is(smartIntI64AddOne(Number.MAX_SAFE_INTEGER - 1), Number.MAX_SAFE_INTEGER)
+  t.is(
+    smartIntI64AddOne(Number.MAX_SAFE_INTEGER),
+    BigInt(Number.MAX_SAFE_INTEGER) + BigInt(1),
+  )
+  t.is(smartIntI64AddOne(Number.MIN_SAFE_INTEGER), Number.MIN_SAFE_INTEGER + 1)
+  t.is(
+    smartIntI64AddOne(BigInt(Number.MIN_SAFE_INTEGER) - BigInt(2)),
+    BigInt(Number.MIN_SAFE_INTEGER) - BigInt(1),
+  )
+  t.is(smartIntI64AddOne(BigInt(-10)), -9)
+  t.is(
+    smartIntI64AddOne(BigInt('-9223372036854775808')),
+    BigInt('-9223372036854775807'),
+  )
+  t.is(smartIntU64AddOne(Number.MAX_SAFE_INTEGER - 1), Number.MAX_SAFE_INTEGER)
+  t.is(
+    smartIntU64AddOne(BigInt('18446744073709551614')),
+    BigInt('18446744073709551615'),
+  )
+  t.throws(() => smartIntI64AddOne(1.5), {
+    code: 'InvalidArg',
+  })
+  t.throws(() => smartIntI64AddOne(Number.MAX_SAFE_INTEGER + 1), {
+    code: 'InvalidArg',
+  })
+  t.throws(() => smartIntI64AddOne(Number.MIN_SAFE_INTEGER - 1), {
+    code: 'InvalidArg',
+  })
+  t.throws(() => smartIntU64AddOne(-1), { code: 'InvalidArg' })
+  t.throws(() => smartIntU64AddOne(BigInt(-1)), { code: 'InvalidArg' })
+  t.throws(() => smartIntI64AddOne(BigInt('9223372036854775808')), {
+    code: 'InvalidArg',
+  })
+})
+
 const Napi4Test = Number(process.versions.napi) >= 4 ? test : test.skip
 
 Napi4Test('call thread safe function', (t) => {
@@ -720,6 +1497,35 @@ Napi4Test('call thread safe function', (t) => {
   })
 })
 
+Napi4Test('call ThreadsafeFunction with priority', async (t) => {
+  const values: number[] = []
+  await new Promise<void>((resolve) => {
+    callThreadsafeFunctionWithPriority((v: number) => {
+      values.push(v)
+      if (values.length === 4) {
+        resolve()
+      }
+    })
+  })
+  t.deepEqual(values, [2, 4, 3, 1])
+})
+
+Napi4Test(
+  'call ThreadsafeFunction with priority on a full queue',
+  async (t) => {
+    const values: number[] = []
+    let statuses: boolean[] = []
+    await new Promise<void>((resolve) => {
+      statuses = callThreadsafeFunctionWithPriorityQueueFull((v: number) => {
+        values.push(v)
+        setTimeout(resolve, 50)
+      })
+    })
+    t.deepEqual(statuses, [true, false, false])
+    t.deepEqual(values, [1])
+  },
+)
+
 Napi4Test('throw error from thread safe function', async (t) => {
   const throwPromise = new Promise((_, reject) => {
     threadsafeFunctionThrowError(reject)
@@ -744,6 +1550,13 @@ Napi4Test('resolve value from thread safe function fatal mode', async (t) => {
   t.true(await tsfnFatalMode)
 })
 
+Napi4Test('try call ThreadsafeFunction with full queue', async (t) => {
+  const called = new Promise<number>((resolve) => {
+    t.deepEqual(tsfnTryCall(resolve), [1, 2, 3, 4])
+  })
+  t.is(await called, 0)
+})
+
 Napi4Test('throw error from thread safe function fatal mode', (t) => {
   const p = exec('node ./tsfn-error.js', {
     cwd: __dirname,
@@ -867,3 +1680,31 @@ Napi5Test('Date to chrono test', (t) => {
     new Date(fixture.getTime() + 60 * 1000),
   )
 })
+
+test('Elapsed', (t) => {
+  const elapsed = elapsedAfterSleep(20)
+  t.is(typeof elapsed, 'number')
+  t.true(elapsed >= 0.02)
+  t.true(elapsed < 10)
+})
+
+test('ControlFlow', (t) => {
+  t.deepEqual(countDown(2), { done: false, value: 1 })
+  t.deepEqual(countDown(0), { done: true, value: 'liftoff' })
+  t.deepEqual(roundTripControlFlow({ done: true, value: 'stop' }), {
+    done: true,
+    value: 'stop',
+  })
+  t.deepEqual(roundTripControlFlow({ done: false, value: 3 }), {
+    done: false,
+    value: 3,
+  })
+  t.true(controlFlowIsBreak({ done: true, value: 1 }))
+  t.false(controlFlowIsBreak({ done: false, value: undefined }))
+  t.throws(() => roundTripControlFlow({ value: 1 } as any), {
+    message: 'Missing `done` field in ControlFlow object',
+  })
+  t.throws(() => roundTripControlFlow({ done: false, value: 'a' } as any), {
+    message: /ControlFlow::Continue/,
+  })
+})
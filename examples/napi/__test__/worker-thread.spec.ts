@@ -3,7 +3,12 @@ import { Worker } from 'worker_threads'
 
 import test from 'ava'
 
-import { Animal, Kind, DEFAULT_COST } from '../index'
+import {
+  Animal,
+  Kind,
+  DEFAULT_COST,
+  getThreadPoolCleanupCount,
+} from '../index'
 
 const t =
   process.arch === 'arm64' && process.platform === 'linux' ? test.skip : test
@@ -28,3 +33,13 @@ t('should be able to require in worker thread', async (t) => {
     }),
   )
 })
+
+t('should run the cleanup hook of class when the worker exits', async (t) => {
+  const before = getThreadPoolCleanupCount()
+  const w = new Worker(join(__dirname, 'worker.js'))
+  await new Promise<void>((resolve, reject) => {
+    w.on('exit', () => resolve())
+    w.on('error', (err) => reject(err))
+  })
+  t.true(getThreadPoolCleanupCount() > before)
+})
@@ -18,14 +18,24 @@ export function sumNums(nums: Array<number>): number
 export function toJsObj(): object
 export function getNumArr(): number[]
 export function getNestedNumArr(): number[][][]
+export function getTuple(base: number): [number, string, boolean]
+export function rotateQueue(queue: Array<string>): Array<string>
+export function primesOrDefault(primes?: Array<number> | undefined | null): Array<number>
+export function sumRows(rows: Array<Array<number> | Float64Array>): Array<number>
+export function reverseSmallVec(nums: number[]): number[]
+/** Whether `nums` didn't fit in the inline storage and was moved to the heap */
+export function smallVecSpilled(nums: number[]): boolean
 export function readFileAsync(path: string): Promise<Buffer>
 export function asyncMultiTwo(arg: number): Promise<number>
+export function spawnFutureMultiTwo(arg: number): Promise<number>
 export function bigintAdd(a: bigint, b: bigint): bigint
 export function createBigInt(): bigint
 export function createBigIntI64(): bigint
 export function bigintGetU64AsString(bi: bigint): string
 export function bigintFromI64(): bigint
 export function bigintFromI128(): bigint
+export function smartIntI64AddOne(value: number | bigint): number | bigint
+export function smartIntU64AddOne(value: number | bigint): number | bigint
 export function getCwd(callback: (arg0: string) => void): void
 export function optionEnd(callback: (arg0: string, arg1?: string | undefined | null) => void): void
 export function optionStart(callback: (arg0: string | undefined | null, arg1: string) => void): void
@@ -36,15 +46,35 @@ export function readFile(callback: (arg0: Error | undefined, arg1?: string | und
 export function returnJsFunction(): (...args: any[]) => any
 export function callbackReturnPromise<T>(functionInput: () => T | Promise<T>, callback: (err: Error | null, result: T) => void): T | Promise<T>
 export function captureErrorInCallback(cb1: () => void, cb2: (arg0: Error) => void): void
+export function createAdder(base: number): (arg0: number) => number
+export function createConcat(separator: string): (arg0: string, arg1: string) => string
+export function refCellSnapshot(): Array<unknown>
+export function refCellBorrowConflict(): void
+export function sharedRefCellName(): string
+export function mutexSnapshot(): Array<unknown>
+export function sharedRwLockName(): string
+export function poisonedMutex(): void
+export function poisonedRwLock(): void
 export interface ObjectFieldClassInstance {
   bird: Bird
 }
 export function createObjectWithClassField(): ObjectFieldClassInstance
 export function receiveObjectWithClassField(object: ObjectFieldClassInstance): Bird
+export function createBird(name: string): Bird
+export function renameBird(bird: Bird, suffix: string): Bird
+export function createBirds(names: Array<string>): Array<Bird>
+export function createCounters(counts: Array<number>): Array<Counter>
 export function plusOne(this: Width): number
+export function createCounterInstance(count: number): Counter
+export function countWheels(vehicle: Vehicle): number
+export function getThreadPoolCleanupCount(): number
+export function countDown(from: number): { done: true, value: string } | { done: false, value: number }
+export function roundTripControlFlow(step: { done: true, value: string } | { done: false, value: number }): { done: true, value: string } | { done: false, value: number }
+export function controlFlowIsBreak(step: { done: true, value: number } | { done: false, value: undefined }): boolean
 export function dateToNumber(input: Date): number
 export function chronoDateToMillis(input: Date): number
 export function chronoDateAdd1Minute(input: Date): Date
+export function elapsedAfterSleep(ms: number): number
 export interface Dates {
   start: Date
   end?: Date
@@ -60,6 +90,7 @@ export function receiveClassOrNumber(either: number | JsClassForEither): number
 export function receiveMutClassOrNumber(either: number | JsClassForEither): number
 export function receiveDifferentClass(either: JsClassForEither | AnotherClassForEither): number
 export function returnEitherClass(input: number): number | JsClassForEither
+export function returnEitherClassInstance(another: boolean): JsClassForEither | AnotherClassForEither
 export function eitherFromOption(): JsClassForEither | undefined
 export interface A {
   foo: number
@@ -97,9 +128,33 @@ export const enum CustomNumEnum {
   Ten = 10
 }
 export function enumToI32(e: CustomNumEnum): number
+/** Shares its name with `std::cmp::Ordering`, which is typed as `-1 | 0 | 1` */
+export const enum Ordering {
+  Ascending = 0,
+  Descending = 1
+}
+export function reverseSortOrder(order: Ordering): Ordering
+export type Shape = { type: 'Circle', radius: number } | { type: 'Rectangle', width: number, height: number, label?: string }
+export function scaleShape(shape: Shape, factor: number): Shape
 export function throwError(): void
+export function throwErrorWithContext(path: string, line: number): void
+export function throwErrorWithProperties(statusCode: number): void
+export function getUint32WithBareStatus(value: unknown): number
+export function sumIntegers(input: string): number
+export function parseFloatValue(input: string): number
+export function decodeUtf8(bytes: Array<number>): string
 export function panic(): void
 export function receiveString(s: string): string
+export interface AnyhowErrorInfo {
+  line?: number
+  isIoError: boolean
+  chain: Array<string>
+}
+export function downcastAnyhowError(line: number): AnyhowErrorInfo
+export function parsePort(input: string): { ok: true, value: number } | { ok: false, error: Error }
+export function validatePositive(n: number): { ok: true, value: undefined } | { ok: false, error: Error }
+export function portRange(start: number, end: number): { ok: true, value: IterableIterator<number> } | { ok: false, error: Error }
+export function parsePorts(inputs: Array<string>): Array<{ status: 'fulfilled', value: number } | { status: 'rejected', reason: Error }>
 export function createExternal(size: number): ExternalObject<number>
 export function createExternalString(content: string): ExternalObject<string>
 export function getExternal(external: ExternalObject<number>): number
@@ -126,17 +181,43 @@ export function returnUndefinedIfInvalidPromise(input: Promise<boolean>): Promis
 export function tsRename(a: { foo: number }): string[]
 export function overrideIndividualArgOnFunction(notOverridden: string, f: () => string, notOverridden2: number): string
 export function overrideIndividualArgOnFunctionWithCbArg(callback: (town: string, name?: string | undefined | null) => string, notOverridden: number): object
+export function pickProperty<T extends object, K extends keyof T>(obj: T, key: K): T[K]
+export function countTo(n: number): IterableIterator<number>
+export function pulledItems(): number
+export function failAt(n: number): IterableIterator<number>
+export function countToAsync(n: number, delayMs: number): AsyncIterableIterator<number>
+export function droppedStreams(): number
+export function failAtAsync(n: number): AsyncIterableIterator<number>
+export function splitLinesAsync(input: string): AsyncIterableIterator<string>
 export function xxh64Alias(input: Buffer): bigint
 export function getMapping(): Record<string, number>
 export function sumMapping(nums: Record<string, number>): number
+export function doubleKindCounts(counts: Record<Kind, number>): Record<Kind, number>
+export function getNestedMapping(): Record<string, Record<string, number>>
+export function getMappingWithHasher(): Record<string, Array<number>>
+export function squareMapValues(map: Map<number, number>): Map<number, number>
 export function mapOption(val?: number | undefined | null): number | null
 export function returnNull(): null
 export function returnUndefined(): void
+export function nilValuesAreSingletons(nullValue: null, undefinedValue: undefined): boolean
+export function optionalVecLen(val?: Array<number> | undefined | null): number | null
 export function add(a: number, b: number): number
 export function fibonacci(n: number): number
+export function halfF32(n: number): number
+export function halfCheckedF32(n: number): number
+/** Comparator for `Array.prototype.sort` */
+export function compareNumbers(a: number, b: number): -1 | 0 | 1
+export function reverseOrdering(ordering: -1 | 0 | 1): -1 | 0 | 1
 export function listObjKeys(obj: object): Array<string>
 export function createObj(): object
 export function getGlobal(): typeof global
+export function stringifyWithInjectedJson(value: unknown): string
+/** Only `Global<T>` from `napi::bindgen_prelude` is injected */
+export interface Global {
+  name: string
+}
+export function localGlobalSettingName(setting: Global): string
+export function globalSettingName(setting: Global): string
 export function getUndefined(): void
 export function getNull(): null
 export interface AllOptionalObject {
@@ -162,6 +243,8 @@ export interface TsTypeChanged {
   typeOverride: object
   typeOverrideOptional?: object
 }
+export function getStringFromObjectRef(obj: object, key: string): string | null
+export function jsStringRefLen(s: string): number
 export function createObjWithProperty(): { value: ArrayBuffer, get getter(): number }
 export function getterFromObj(): number
 export interface ObjectOnlyFromJs {
@@ -169,6 +252,48 @@ export interface ObjectOnlyFromJs {
   callback: (err: Error | null, value: number) => any
 }
 export function receiveObjectOnlyFromJs(obj: { count: number, callback: (err: Error | null, count: number) => void }): void
+export interface DebuggableObject {
+  userName: string
+  loginCount: number
+}
+export function debugObject(obj: DebuggableObject): string
+export interface PascalCaseObject {
+  UserName: string
+  LoginCount: number
+}
+export function loginPascalCaseObject(obj: PascalCaseObject): PascalCaseObject
+export interface KebabCaseObject {
+  'user-name': string
+  'login-count': number
+  id: number
+}
+export function loginKebabCaseObject(obj: KebabCaseObject): KebabCaseObject
+/** Every field is `readonly` in TypeScript */
+export interface ImmutableUser {
+  readonly name: string
+  readonly roles: Array<string>
+  readonly nickname?: string
+}
+export function renameImmutableUser(user: ImmutableUser, name: string): ImmutableUser
+export interface Named {
+  name: string
+}
+export function greetThis(this: Named, greeting: string): string
+export interface TsElementTypeChanged {
+  permissions: Array<'read' | 'write'>
+  permissionGroups?: Array<Array<'read' | 'write'>>
+}
+export interface Attachment {
+  name: string
+  content: string
+  thumbnail?: string
+}
+export function reverseAttachment(attachment: Attachment): Attachment
+/** RGB color channels */
+export type Rgb = [number, number, number]
+export function invertRgb(color: Rgb): Rgb
+export type LabeledPoint = [string, number, number | undefined | null]
+export function scaleLabeledPoint(point: LabeledPoint, factor: number): LabeledPoint
 export function asyncPlus100(p: Promise<number>): Promise<number>
 /** This is an interface for package.json */
 export interface PackageJson {
@@ -181,16 +306,35 @@ export interface PackageJson {
 export function readPackageJson(): PackageJson
 export function getPackageJsonName(packageJson: PackageJson): string
 export function testSerdeRoundtrip(data: any): any
+export function countJsonObjectKeys(data: Record<string, any>): number
+/** Deserialized from JavaScript by its `Deserialize` impl */
+export type Repository = { name: string, owner: { login: string }, stargazersCount?: number, topics?: string[] }
+export function describeRepository(repo: Repository): string
+export type Shape = any
+export function shapeArea(shape: Shape): number
 export function contains(source: string, target: string): boolean
 export function concatStr(s: string): string
 export function concatUtf16(s: string): string
 export function concatLatin1(s: string): string
 export function roundtripStr(s: string): string
+export function cowStrOrDefault(s?: string | undefined | null): string
+export function isCowStrOwned(s: string): boolean
+export function cstringByteLength(s: string): number
+export function cstringFromBytes(bytes: Array<number>): string
+export function staticCstr(): string
+export function sharedStrRoundTrip(s: string): string
 export function setSymbolInObj(symbol: symbol): object
 export function createSymbol(): symbol
 export function withoutAbortController(a: number, b: number): Promise<number>
 export function withAbortController(a: number, b: number, signal: AbortSignal): Promise<number>
 export function callThreadsafeFunction(callback: (...args: any[]) => any): void
+/**
+ * Values are queued before the JavaScript thread can deliver any of them,
+ * so they arrive strictly by priority: `2, 4, 3, 1`
+ */
+export function callThreadsafeFunctionWithPriority(callback: (...args: any[]) => any): void
+/** Only the first value fits into the queue, the others are refused and never delivered */
+export function callThreadsafeFunctionWithPriorityQueueFull(callback: (...args: any[]) => any): Array<boolean>
 export function threadsafeFunctionThrowError(cb: (...args: any[]) => any): void
 export function threadsafeFunctionFatalMode(cb: (...args: any[]) => any): void
 export function threadsafeFunctionFatalModeError(cb: (...args: any[]) => any): void
@@ -199,9 +343,12 @@ export function tsfnCallWithCallback(func: (...args: any[]) => any): void
 export function tsfnAsyncCall(func: (...args: any[]) => any): Promise<void>
 export function acceptThreadsafeFunction(func: (err: Error | null, value: number) => any): void
 export function acceptThreadsafeFunctionFatal(func: (value: number) => any): void
+export function tsfnTryCall(func: (...args: any[]) => any): Array<number>
 export function getBuffer(): Buffer
 export function appendBuffer(buf: Buffer): Buffer
 export function getEmptyBuffer(): Buffer
+export function copyBuffer(buf: Buffer): Buffer
+export function copyBufferThenClearSource(): Buffer
 export function convertU32Array(input: Uint32Array): Array<number>
 export function createExternalTypedArray(): Uint32Array
 export function mutateTypedArray(input: Float32Array): void
@@ -209,6 +356,9 @@ export function derefUint8Array(a: Uint8Array, b: Uint8ClampedArray): number
 export function bufferPassThrough(buf: Buffer): Promise<Buffer>
 export function arrayBufferPassThrough(buf: Uint8Array): Promise<Uint8Array>
 export function asyncReduceBuffer(buf: Buffer): Promise<number>
+export function sharedBytesRoundTrip(bytes: Buffer): Buffer
+export function urlHost(url: string | URL): string | null
+export function normalizeUrl(url: string | URL): string
 export function runScript(script: string): unknown
 /**
  * `constructor` option for `struct` requires all fields to be public,
@@ -226,6 +376,7 @@ export class Animal {
   set name(name: string)
   get type(): Kind
   set type(kind: Kind)
+  get kindName(): 'Dog' | 'Cat' | 'Duck'
   /**
    * This is a
    * multi-line comment
@@ -311,6 +462,47 @@ export class Width {
   value: number
   constructor(value: number)
 }
+export class Thermostat {
+  /** The constructor doesn't validate `celsius`, the `kelvin` getter does */
+  constructor(celsius: number)
+  get celsius(): number
+  set celsius(celsius: number)
+  get kelvin(): number
+}
+export class Counter {
+  constructor()
+  /** Mutates the counter in place through `&mut self`, no `RefCell` needed */
+  increment(step: number): number
+  get count(): number
+}
+/** Only static methods, `new MathUtils()` throws */
+export class MathUtils {
+  static clamp(value: number, min: number, max: number): number
+  static lerp(from: number, to: number, t: number): number
+}
+export class Vehicle {
+  constructor(wheels: number)
+  /** Inherited by `Car`, `Car.bicycle()` returns a `Vehicle` too */
+  static bicycle(): Vehicle
+  get wheels(): number
+  describe(): string
+  honk(): string
+}
+/** `Car.prototype` inherits from `Vehicle.prototype` */
+export class Car extends Vehicle {
+  constructor(brand: string)
+  /** Overrides `Vehicle#describe` */
+  describe(): string
+}
+/** Owns a global resource which is released when the env exits */
+export class ThreadPool { }
+export class ByteChunk {
+  constructor(data: Array<number>)
+  byteLength(): number
+  /** Consumes the chunk, it can't be used after the bytes are taken out */
+  intoBytes(): Buffer
+  concat(other: Array<number>): Buffer
+}
 export class ClassWithFactory {
   name: string
   static withName(name: string): ClassWithFactory
@@ -322,6 +514,11 @@ export class JsClassForEither {
 export class AnotherClassForEither {
   constructor()
 }
+export class Greeter {
+  constructor(greeting: string)
+  greet(name: string): string
+  greet(names: Array<string>): string
+}
 export class Fib {
   [Symbol.iterator](): Iterator<number, void, number>
   constructor()
@@ -336,6 +533,17 @@ export class Fib3 {
   constructor(current: number, next: number)
   [Symbol.iterator](): Iterator<number, void, number>
 }
+/** Every `for...of` loop iterates the collection from its first item */
+export class NumberCollection {
+  [Symbol.iterator](): Iterator<number, void, void>
+  constructor(items: Array<number>)
+  push(item: number): void
+}
+export class Playlist {
+  constructor(songs: Array<string>)
+  /** The stream borrows `self` until it's dropped */
+  play(delayMs: number): AsyncIterableIterator<string>
+}
 export class JsRepo {
   constructor(dir: string)
   remote(): JsRemote
@@ -359,6 +567,12 @@ export type AnotherCSSStyleSheet = AnotherCssStyleSheet
 export class AnotherCssStyleSheet {
   get rules(): CssRuleList
 }
+export class SharedResource {
+  constructor(name: string)
+  weakHandle(): { isAlive(): boolean }
+  /** Drops the only strong reference */
+  release(): void
+}
 export namespace xxh3 {
   export const ALIGNMENT: number
   export function xxh3_64(input: Buffer): bigint
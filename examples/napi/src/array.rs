@@ -1,4 +1,8 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
 use napi::{Env, JsObject};
+use smallvec::SmallVec;
 
 #[napi]
 pub fn get_words() -> Vec<&'static str> {
@@ -33,3 +37,46 @@ fn get_num_arr() -> [u32; 2] {
 fn get_nested_num_arr() -> [[[u32; 1]; 1]; 2] {
   [[[1]], [[1]]]
 }
+
+#[napi]
+fn get_tuple(base: u32) -> (u32, String, bool) {
+  (base + 1, base.to_string(), base % 2 == 0)
+}
+
+#[napi]
+fn rotate_queue(mut queue: VecDeque<String>) -> VecDeque<String> {
+  if let Some(front) = queue.pop_front() {
+    queue.push_back(front);
+  }
+  queue
+}
+
+static DEFAULT_PRIMES: [u32; 5] = [2, 3, 5, 7, 11];
+
+#[napi]
+pub fn primes_or_default(primes: Option<Vec<u32>>) -> Cow<'static, [u32]> {
+  match primes {
+    Some(primes) => Cow::Owned(primes),
+    None => Cow::Borrowed(&DEFAULT_PRIMES),
+  }
+}
+
+#[napi(ts_args_type = "rows: Array<Array<number> | Float64Array>")]
+pub fn sum_rows(rows: Vec<Vec<f64>>) -> Vec<f64> {
+  rows
+    .iter()
+    .map(|row| row.iter().fold(0.0, |sum, n| sum + n))
+    .collect()
+}
+
+#[napi]
+fn reverse_small_vec(mut nums: SmallVec<[u32; 4]>) -> SmallVec<[u32; 4]> {
+  nums.reverse();
+  nums
+}
+
+/// Whether `nums` didn't fit in the inline storage and was moved to the heap
+#[napi]
+fn small_vec_spilled(nums: SmallVec<[u32; 4]>) -> bool {
+  nums.spilled()
+}
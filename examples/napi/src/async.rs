@@ -21,3 +21,18 @@ async fn async_multi_two(arg: u32) -> Result<u32> {
     .await
     .unwrap()
 }
+
+#[napi(ts_return_type = "Promise<number>")]
+fn spawn_future_multi_two(env: Env, arg: u32) -> Result<Object> {
+  env.spawn_future(async move {
+    if arg == 0 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "arg must not be zero".to_owned(),
+      ));
+    }
+    tokio::task::spawn(async move { arg * 2 })
+      .await
+      .map_err(|e| Error::new(Status::GenericFailure, format!("{}", e)))
+  })
+}
@@ -32,3 +32,13 @@ pub fn bigint_from_i64() -> BigInt {
 pub fn bigint_from_i128() -> BigInt {
   BigInt::from(-100i128)
 }
+
+#[napi]
+pub fn smart_int_i64_add_one(value: SmartInt<i64>) -> SmartInt<i64> {
+  SmartInt(value.0 + 1)
+}
+
+#[napi]
+pub fn smart_int_u64_add_one(value: SmartInt<u64>) -> SmartInt<u64> {
+  SmartInt(value.0 + 1)
+}
@@ -92,3 +92,13 @@ pub fn capture_error_in_callback<C: Fn() -> Result<()>, E: Fn(Error) -> Result<(
     Ok(())
   }
 }
+
+#[napi]
+pub fn create_adder(base: u32) -> impl Fn(u32) -> Result<u32> {
+  move |n| Ok(base + n)
+}
+
+#[napi]
+pub fn create_concat(separator: String) -> Result<impl Fn(String, String) -> Result<String>> {
+  Ok(move |a: String, b: String| Ok(format!("{}{}{}", a, separator, b)))
+}
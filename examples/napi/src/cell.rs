@@ -0,0 +1,80 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+
+use napi::{bindgen_prelude::*, JsUnknown};
+
+/// Converts `shared` before and after `mutate`, the first value must not see the change
+fn snapshots_around<S: ToNapiValue + Clone>(
+  env: Env,
+  shared: S,
+  mutate: impl FnOnce(&S),
+) -> Result<Vec<JsUnknown>> {
+  let before = unsafe { ToNapiValue::to_napi_value(env.raw(), shared.clone())? };
+  mutate(&shared);
+  let after = unsafe { ToNapiValue::to_napi_value(env.raw(), shared)? };
+  Ok(vec![
+    unsafe { JsUnknown::from_napi_value(env.raw(), before)? },
+    unsafe { JsUnknown::from_napi_value(env.raw(), after)? },
+  ])
+}
+
+/// Runs `panic_while_locked` on another thread, it must panic while holding the lock
+fn poison<L: Send + Sync + 'static>(lock: &Arc<L>, panic_while_locked: fn(&L)) {
+  let poisoner = lock.clone();
+  let _ = std::thread::spawn(move || panic_while_locked(&poisoner)).join();
+}
+
+#[napi]
+fn ref_cell_snapshot(env: Env) -> Result<Vec<JsUnknown>> {
+  snapshots_around(env, Rc::new(RefCell::new(vec![1u32, 2])), |shared| {
+    shared.borrow_mut().push(3)
+  })
+}
+
+#[napi]
+fn ref_cell_borrow_conflict(env: Env) -> Result<()> {
+  let cell = RefCell::new(String::from("busy"));
+  let _guard = cell.borrow_mut();
+  unsafe { <&RefCell<String>>::to_napi_value(env.raw(), &cell)? };
+  Ok(())
+}
+
+#[napi]
+fn shared_ref_cell_name() -> Rc<RefCell<String>> {
+  Rc::new(RefCell::new("napi".to_owned()))
+}
+
+#[napi]
+fn mutex_snapshot(env: Env) -> Result<Vec<JsUnknown>> {
+  snapshots_around(env, Arc::new(Mutex::new(vec![1u32, 2])), |shared| {
+    shared.lock().unwrap().push(3)
+  })
+}
+
+#[napi]
+fn shared_rw_lock_name() -> Arc<RwLock<String>> {
+  Arc::new(RwLock::new("napi".to_owned()))
+}
+
+#[napi]
+fn poisoned_mutex(env: Env) -> Result<()> {
+  let mutex = Arc::new(Mutex::new(0u32));
+  poison(&mutex, |mutex| {
+    let _guard = mutex.lock().unwrap();
+    panic!("Poison the lock");
+  });
+  unsafe { <&Mutex<u32>>::to_napi_value(env.raw(), &mutex)? };
+  Ok(())
+}
+
+#[napi]
+fn poisoned_rw_lock(env: Env) -> Result<()> {
+  let lock = Arc::new(RwLock::new(0u32));
+  poison(&lock, |lock| {
+    let _guard = lock.write().unwrap();
+    panic!("Poison the lock");
+  });
+  unsafe { <&RwLock<u32>>::to_napi_value(env.raw(), &lock)? };
+  Ok(())
+}
@@ -1,6 +1,10 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use napi::{
-  bindgen_prelude::{Buffer, ClassInstance, ObjectFinalize, This, Uint8Array, Unknown},
-  Env, Result,
+  bindgen_prelude::{
+    Buffer, ClassInstance, NapiCleanup, ObjectFinalize, This, Uint8Array, Unknown,
+  },
+  Env, Error, JsObject, Result, Status,
 };
 
 use crate::r#enum::Kind;
@@ -54,6 +58,15 @@ impl Animal {
     self.kind = kind;
   }
 
+  #[napi(getter, ts_return_type = "'Dog' | 'Cat' | 'Duck'")]
+  pub fn get_kind_name(&self) -> &str {
+    match self.kind {
+      Kind::Dog => "Dog",
+      Kind::Cat => "Cat",
+      Kind::Duck => "Duck",
+    }
+  }
+
   /// This is a
   /// multi-line comment
   /// with an emoji 🚀
@@ -359,6 +372,30 @@ pub fn receive_object_with_class_field(
   Ok(object.bird)
 }
 
+#[napi]
+pub fn create_bird(env: Env, name: String) -> Result<ClassInstance<Bird>> {
+  Bird { name }.into_instance(env)
+}
+
+#[napi]
+pub fn rename_bird(mut bird: ClassInstance<Bird>, suffix: String) -> ClassInstance<Bird> {
+  bird.name.push_str(&suffix);
+  bird
+}
+
+#[napi]
+pub fn create_birds(env: Env, names: Vec<String>) -> Result<Vec<ClassInstance<Bird>>> {
+  names
+    .into_iter()
+    .map(|name| Bird { name }.into_instance(env))
+    .collect()
+}
+
+#[napi]
+pub fn create_counters(counts: Vec<u32>) -> Vec<Counter> {
+  counts.into_iter().map(|count| Counter { count }).collect()
+}
+
 #[napi(constructor)]
 pub struct NotWritableClass {
   #[napi(writable = false)]
@@ -411,3 +448,204 @@ pub struct Width {
 pub fn plus_one(this: This<&Width>) -> i32 {
   this.value + 1
 }
+
+#[napi]
+pub struct Thermostat {
+  celsius: f64,
+}
+
+#[napi]
+impl Thermostat {
+  /// The constructor doesn't validate `celsius`, the `kelvin` getter does
+  #[napi(constructor)]
+  pub fn new(celsius: f64) -> Self {
+    Thermostat { celsius }
+  }
+
+  #[napi(getter)]
+  pub fn get_celsius(&self) -> f64 {
+    self.celsius
+  }
+
+  #[napi(setter)]
+  pub fn set_celsius(&mut self, celsius: f64) -> Result<()> {
+    if celsius < -273.15 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Temperature is below absolute zero".to_owned(),
+      ));
+    }
+    self.celsius = celsius;
+    Ok(())
+  }
+
+  #[napi(getter)]
+  pub fn get_kelvin(&self) -> Result<f64> {
+    if self.celsius < -273.15 {
+      return Err(Error::new(
+        Status::GenericFailure,
+        "Temperature is below absolute zero".to_owned(),
+      ));
+    }
+    Ok(self.celsius + 273.15)
+  }
+}
+
+#[napi]
+pub struct Counter {
+  count: u32,
+}
+
+#[napi]
+impl Counter {
+  #[napi(constructor)]
+  #[allow(clippy::new_without_default)]
+  pub fn new() -> Self {
+    Counter { count: 0 }
+  }
+
+  /// Mutates the counter in place through `&mut self`, no `RefCell` needed
+  #[napi]
+  pub fn increment(&mut self, step: u32) -> u32 {
+    self.count += step;
+    self.count
+  }
+
+  #[napi(getter)]
+  pub fn get_count(&self) -> u32 {
+    self.count
+  }
+}
+
+#[napi(ts_return_type = "Counter")]
+pub fn create_counter_instance(env: Env, count: u32) -> Result<JsObject> {
+  env.new_instance(Counter { count })
+}
+
+/// Only static methods, `new MathUtils()` throws
+#[napi]
+pub struct MathUtils;
+
+#[napi]
+impl MathUtils {
+  #[napi]
+  pub fn clamp(value: f64, min: f64, max: f64) -> f64 {
+    value.max(min).min(max)
+  }
+
+  #[napi]
+  pub fn lerp(from: f64, to: f64, t: f64) -> f64 {
+    from + (to - from) * t
+  }
+}
+
+#[napi]
+pub struct Vehicle {
+  wheels: u32,
+}
+
+#[napi]
+impl Vehicle {
+  #[napi(constructor)]
+  pub fn new(wheels: u32) -> Self {
+    Vehicle { wheels }
+  }
+
+  /// Inherited by `Car`, `Car.bicycle()` returns a `Vehicle` too
+  #[napi(factory)]
+  pub fn bicycle() -> Self {
+    Vehicle { wheels: 2 }
+  }
+
+  #[napi(getter)]
+  pub fn get_wheels(&self) -> u32 {
+    self.wheels
+  }
+
+  #[napi]
+  pub fn describe(&self) -> String {
+    format!("Vehicle with {} wheels", self.wheels)
+  }
+
+  #[napi]
+  pub fn honk(&self) -> String {
+    "Beep".to_owned()
+  }
+}
+
+/// `Car.prototype` inherits from `Vehicle.prototype`
+#[napi(extends = "Vehicle")]
+#[repr(C)]
+pub struct Car {
+  vehicle: Vehicle,
+  brand: String,
+}
+
+#[napi]
+impl Car {
+  #[napi(constructor)]
+  pub fn new(brand: String) -> Self {
+    Car {
+      vehicle: Vehicle::new(4),
+      brand,
+    }
+  }
+
+  /// Overrides `Vehicle#describe`
+  #[napi]
+  pub fn describe(&self) -> String {
+    format!("{} car with {} wheels", self.brand, self.vehicle.wheels)
+  }
+}
+
+#[napi]
+pub fn count_wheels(vehicle: &Vehicle) -> u32 {
+  vehicle.wheels
+}
+
+static THREAD_POOL_CLEANUP_COUNT: AtomicU32 = AtomicU32::new(0);
+
+/// Owns a global resource which is released when the env exits
+#[napi(cleanup)]
+pub struct ThreadPool {}
+
+impl NapiCleanup for ThreadPool {
+  fn on_env_exit() {
+    THREAD_POOL_CLEANUP_COUNT.fetch_add(1, Ordering::SeqCst);
+  }
+}
+
+#[napi]
+pub fn get_thread_pool_cleanup_count() -> u32 {
+  THREAD_POOL_CLEANUP_COUNT.load(Ordering::SeqCst)
+}
+
+#[napi]
+pub struct ByteChunk {
+  data: Vec<u8>,
+}
+
+#[napi]
+impl ByteChunk {
+  #[napi(constructor)]
+  pub fn new(data: Vec<u8>) -> Self {
+    ByteChunk { data }
+  }
+
+  #[napi]
+  pub fn byte_length(&self) -> u32 {
+    self.data.len() as u32
+  }
+
+  /// Consumes the chunk, it can't be used after the bytes are taken out
+  #[napi]
+  pub fn into_bytes(self) -> Buffer {
+    self.data.into()
+  }
+
+  #[napi]
+  pub fn concat(mut self, other: Vec<u8>) -> Buffer {
+    self.data.extend(other);
+    self.data.into()
+  }
+}
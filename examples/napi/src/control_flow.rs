@@ -0,0 +1,19 @@
+use std::ops::ControlFlow;
+
+#[napi]
+fn count_down(from: u32) -> ControlFlow<String, u32> {
+  match from {
+    0 => ControlFlow::Break("liftoff".to_owned()),
+    n => ControlFlow::Continue(n - 1),
+  }
+}
+
+#[napi]
+fn round_trip_control_flow(step: ControlFlow<String, u32>) -> ControlFlow<String, u32> {
+  step
+}
+
+#[napi]
+fn control_flow_is_break(step: ControlFlow<u32>) -> bool {
+  matches!(step, ControlFlow::Break(_))
+}
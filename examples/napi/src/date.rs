@@ -16,6 +16,13 @@ fn chrono_date_add_1_minute(input: chrono::DateTime<Utc>) -> chrono::DateTime<Ut
   input + Duration::minutes(1)
 }
 
+#[napi]
+fn elapsed_after_sleep(ms: u32) -> Elapsed {
+  let start = std::time::Instant::now();
+  std::thread::sleep(std::time::Duration::from_millis(ms as u64));
+  Elapsed(start)
+}
+
 #[napi(object)]
 pub struct Dates {
   pub start: chrono::DateTime<Utc>,
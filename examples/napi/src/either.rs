@@ -99,6 +99,18 @@ fn return_either_class(input: i32) -> Either<u32, JsClassForEither> {
   }
 }
 
+#[napi]
+fn return_either_class_instance(
+  env: Env,
+  another: bool,
+) -> Result<Either<ClassInstance<JsClassForEither>, ClassInstance<AnotherClassForEither>>> {
+  if another {
+    AnotherClassForEither {}.into_instance(env).map(Either::B)
+  } else {
+    JsClassForEither {}.into_instance(env).map(Either::A)
+  }
+}
+
 #[napi]
 fn either_from_option() -> Either<JsClassForEither, Undefined> {
   Some(JsClassForEither {}).into()
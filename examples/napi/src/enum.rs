@@ -2,6 +2,7 @@ use napi::bindgen_prelude::*;
 
 /// default enum values are continuos i32s start from 0
 #[napi]
+#[derive(PartialEq, Eq, Hash)]
 pub enum Kind {
   /// Barks
   Dog,
@@ -32,9 +33,54 @@ fn enum_to_i32(e: CustomNumEnum) -> i32 {
   e as i32
 }
 
+/// Shares its name with `std::cmp::Ordering`, which is typed as `-1 | 0 | 1`
+#[napi]
+pub enum Ordering {
+  Ascending,
+  Descending,
+}
+
+#[napi]
+fn reverse_sort_order(order: Ordering) -> Ordering {
+  match order {
+    Ordering::Ascending => Ordering::Descending,
+    Ordering::Descending => Ordering::Ascending,
+  }
+}
+
 #[napi(skip_typescript)]
 pub enum SkippedEnums {
   One = 1,
   Two,
   Tree,
 }
+
+#[napi(discriminant = "type")]
+pub enum Shape {
+  Circle {
+    radius: f64,
+  },
+  Rectangle {
+    width: f64,
+    height: f64,
+    label: Option<String>,
+  },
+}
+
+#[napi]
+fn scale_shape(shape: Shape, factor: f64) -> Shape {
+  match shape {
+    Shape::Circle { radius } => Shape::Circle {
+      radius: radius * factor,
+    },
+    Shape::Rectangle {
+      width,
+      height,
+      label,
+    } => Shape::Rectangle {
+      width: width * factor,
+      height: height * factor,
+      label,
+    },
+  }
+}
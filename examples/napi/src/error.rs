@@ -1,10 +1,54 @@
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, NapiRaw};
 
 #[napi]
 pub fn throw_error() -> Result<()> {
   Err(Error::new(Status::InvalidArg, "Manual Error".to_owned()))
 }
 
+#[napi]
+pub fn throw_error_with_context(path: String, line: u32) -> Result<()> {
+  Err(
+    Error::new(Status::InvalidArg, "Failed to read config".to_owned())
+      .context("path", path)
+      .context("line", line),
+  )
+}
+
+#[napi]
+pub fn throw_error_with_properties(status_code: u32) -> Result<()> {
+  Err(
+    Error::new(Status::GenericFailure, "Service unavailable".to_owned())
+      .with_property("retryable", status_code >= 500)
+      .with_property("statusCode", status_code),
+  )
+}
+
+#[napi]
+pub fn get_uint32_with_bare_status(env: Env, value: Unknown) -> Result<u32> {
+  let mut result = 0;
+  napi::check_status!(unsafe { sys::napi_get_value_uint32(env.raw(), value.raw(), &mut result) })?;
+  Ok(result)
+}
+
+#[napi]
+pub fn sum_integers(input: String) -> Result<i64> {
+  let mut sum = 0;
+  for part in input.split(',') {
+    sum += part.trim().parse::<i64>()?;
+  }
+  Ok(sum)
+}
+
+#[napi]
+pub fn parse_float_value(input: String) -> Result<f64> {
+  Ok(input.parse::<f64>()?)
+}
+
+#[napi]
+pub fn decode_utf8(bytes: Vec<u8>) -> Result<String> {
+  Ok(String::from_utf8(bytes)?)
+}
+
 #[napi(catch_unwind)]
 pub fn panic() {
   panic!("Don't panic");
@@ -14,3 +58,81 @@ pub fn panic() {
 pub fn receive_string(s: String) -> String {
   s
 }
+
+#[derive(Debug)]
+pub struct ParseConfigError {
+  line: u32,
+}
+
+impl std::fmt::Display for ParseConfigError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Invalid config at line {}", self.line)
+  }
+}
+
+impl std::error::Error for ParseConfigError {}
+
+#[napi(object)]
+pub struct AnyhowErrorInfo {
+  pub line: Option<u32>,
+  pub is_io_error: bool,
+  pub chain: Vec<String>,
+}
+
+#[napi]
+pub fn downcast_anyhow_error(line: u32) -> AnyhowErrorInfo {
+  let err: Error = anyhow::Error::new(ParseConfigError { line })
+    .context("Failed to load config")
+    .into();
+  AnyhowErrorInfo {
+    line: err.downcast_ref::<ParseConfigError>().map(|e| e.line),
+    is_io_error: err.downcast_ref::<std::io::Error>().is_some(),
+    chain: err.chain().map(|e| e.to_string()).collect(),
+  }
+}
+
+#[napi(return_result_object)]
+pub fn parse_port(input: String) -> Result<u32> {
+  input.parse::<u16>().map(u32::from).map_err(|e| {
+    Error::new(
+      Status::InvalidArg,
+      format!("Invalid port `{}`: {}", input, e),
+    )
+  })
+}
+
+#[napi(return_result_object)]
+pub fn validate_positive(n: i32) -> Result<()> {
+  if n > 0 {
+    Ok(())
+  } else {
+    Err(Error::from_reason(format!("{} is not positive", n)))
+  }
+}
+
+#[napi(return_result_object)]
+pub fn port_range(start: u32, end: u32) -> Result<impl Iterator<Item = Result<u32>>> {
+  if start > end {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!("Invalid port range {}-{}", start, end),
+    ));
+  }
+  Ok((start..=end).map(Ok))
+}
+
+#[napi]
+pub fn parse_ports(inputs: Vec<String>) -> SettledResults<u32> {
+  inputs
+    .into_iter()
+    .map(|input| {
+      input.parse::<u16>().map(u32::from).map_err(|e| {
+        Error::new(
+          Status::InvalidArg,
+          format!("Invalid port `{}`: {}", input, e),
+        )
+      })
+    })
+    .collect::<Vec<_>>()
+    .into()
+}
@@ -1,5 +1,5 @@
-use napi::bindgen_prelude::{Object, Result};
-use napi::JsFunction;
+use napi::bindgen_prelude::{Either, Object, Result};
+use napi::{JsFunction, JsUnknown};
 
 #[napi(ts_args_type = "a: { foo: number }", ts_return_type = "string[]")]
 fn ts_rename(a: Object) -> Result<Object> {
@@ -34,3 +34,32 @@ fn override_individual_arg_on_function_with_cb_arg<
 ) -> Result<Object> {
   callback(format!("World({})", not_overridden), None)
 }
+
+#[napi]
+pub struct Greeter {
+  greeting: String,
+}
+
+#[napi]
+impl Greeter {
+  #[napi(constructor)]
+  pub fn new(greeting: String) -> Self {
+    Greeter { greeting }
+  }
+
+  #[napi(ts_args_type = "name: string", ts_args_type = "names: Array<string>")]
+  pub fn greet(&self, target: Either<String, Vec<String>>) -> String {
+    let names = match target {
+      Either::A(name) => name,
+      Either::B(names) => names.join(" and "),
+    };
+    format!("{}, {}!", self.greeting, names)
+  }
+}
+
+#[napi(
+  ts_decl = "export function pickProperty<T extends object, K extends keyof T>(obj: T, key: K): T[K]"
+)]
+fn pick_property(obj: Object, key: String) -> Result<Option<JsUnknown>> {
+  obj.get(key)
+}
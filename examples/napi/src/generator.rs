@@ -1,3 +1,7 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use futures::{stream, Stream};
 use napi::bindgen_prelude::*;
 
 #[napi(iterator)]
@@ -109,3 +113,141 @@ impl Generator for Fib3 {
     Some(self.current)
   }
 }
+
+/// Every `for...of` loop iterates the collection from its first item
+#[napi(iterator)]
+pub struct NumberCollection {
+  items: Vec<u32>,
+  cursor: usize,
+}
+
+#[napi]
+impl Generator for NumberCollection {
+  type Yield = u32;
+  type Next = ();
+  type Return = ();
+
+  fn next(&mut self, _value: Option<Self::Next>) -> Option<Self::Yield> {
+    let item = self.items.get(self.cursor).copied();
+    // rewind once exhausted, so the next loop starts over
+    self.cursor = if item.is_some() { self.cursor + 1 } else { 0 };
+    item
+  }
+
+  fn complete(&mut self, _value: Option<Self::Return>) -> Option<Self::Yield> {
+    self.cursor = 0;
+    None
+  }
+}
+
+#[napi]
+impl NumberCollection {
+  #[napi(constructor)]
+  pub fn new(items: Vec<u32>) -> Self {
+    Self { items, cursor: 0 }
+  }
+
+  #[napi]
+  pub fn push(&mut self, item: u32) {
+    self.items.push(item);
+  }
+}
+
+static PULLED_ITEMS: AtomicU32 = AtomicU32::new(0);
+
+#[napi]
+fn count_to(n: u32) -> impl Iterator<Item = Result<u32>> {
+  (1..=n).map(|i| {
+    PULLED_ITEMS.fetch_add(1, Ordering::Relaxed);
+    Ok(i)
+  })
+}
+
+#[napi]
+fn pulled_items() -> u32 {
+  PULLED_ITEMS.load(Ordering::Relaxed)
+}
+
+#[napi]
+fn fail_at(n: u32) -> impl Iterator<Item = Result<u32>> {
+  (0..).map(move |i| {
+    if i == n {
+      Err(Error::from_reason(format!("Failed at {}", i)))
+    } else {
+      Ok(i)
+    }
+  })
+}
+
+static DROPPED_STREAMS: AtomicU32 = AtomicU32::new(0);
+
+struct StreamDropGuard;
+
+impl Drop for StreamDropGuard {
+  fn drop(&mut self) {
+    DROPPED_STREAMS.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+#[napi]
+fn count_to_async(n: u32, delay_ms: u32) -> impl Stream<Item = Result<u32>> {
+  stream::unfold((1, StreamDropGuard), move |(i, guard)| async move {
+    if i > n {
+      return None;
+    }
+    tokio::time::sleep(Duration::from_millis(delay_ms as u64)).await;
+    Some((Ok(i), (i + 1, guard)))
+  })
+}
+
+#[napi]
+fn dropped_streams() -> u32 {
+  DROPPED_STREAMS.load(Ordering::Relaxed)
+}
+
+#[napi]
+fn fail_at_async(n: u32) -> impl Stream<Item = Result<u32>> {
+  stream::iter((0..).map(move |i| {
+    if i == n {
+      Err(Error::from_reason(format!("Failed at {}", i)))
+    } else {
+      Ok(i)
+    }
+  }))
+}
+
+#[napi]
+async fn split_lines_async(input: String) -> Result<impl Stream<Item = Result<String>>> {
+  tokio::task::yield_now().await;
+  if input.is_empty() {
+    return Err(Error::from_reason("Nothing to split"));
+  }
+  let lines = input
+    .lines()
+    .map(|line| Ok(line.to_owned()))
+    .collect::<Vec<_>>();
+  Ok(stream::iter(lines))
+}
+
+#[napi]
+pub struct Playlist {
+  songs: Vec<String>,
+}
+
+#[napi]
+impl Playlist {
+  #[napi(constructor)]
+  pub fn new(songs: Vec<String>) -> Self {
+    Playlist { songs }
+  }
+
+  /// The stream borrows `self` until it's dropped
+  #[napi]
+  pub async fn play(&self, delay_ms: u32) -> impl Stream<Item = Result<String>> + '_ {
+    stream::unfold(0, move |i| async move {
+      let song = self.songs.get(i)?.clone();
+      tokio::time::sleep(Duration::from_millis(delay_ms as u64)).await;
+      Some((Ok(song), i + 1))
+    })
+  }
+}
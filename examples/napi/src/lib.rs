@@ -25,8 +25,10 @@ mod array;
 mod r#async;
 mod bigint;
 mod callback;
+mod cell;
 mod class;
 mod class_factory;
+mod control_flow;
 mod date;
 mod either;
 mod r#enum;
@@ -48,6 +50,7 @@ mod symbol;
 mod task;
 mod threadsafe_function;
 mod typed_array;
+mod url;
 
 #[napi]
 pub fn run_script(env: Env, script: String) -> napi::Result<JsUnknown> {
@@ -1,4 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{hash_map::RandomState, HashMap};
+
+use napi::bindgen_prelude::{JsMap, PropertyMap};
+
+use crate::r#enum::Kind;
 
 #[napi]
 fn get_mapping() -> HashMap<String, u32> {
@@ -12,3 +16,39 @@ fn get_mapping() -> HashMap<String, u32> {
 fn sum_mapping(nums: HashMap<String, u32>) -> u32 {
   nums.into_values().sum()
 }
+
+#[napi]
+fn double_kind_counts(counts: PropertyMap<Kind, u32>) -> PropertyMap<Kind, u32> {
+  counts
+    .into_inner()
+    .into_iter()
+    .map(|(kind, count)| (kind, count * 2))
+    .collect::<HashMap<_, _>>()
+    .into()
+}
+
+#[napi]
+fn get_nested_mapping() -> HashMap<String, HashMap<String, i32>> {
+  let mut inner = HashMap::new();
+  inner.insert("x".to_owned(), -1);
+  let mut map = HashMap::new();
+  map.insert("point".to_owned(), inner);
+  map
+}
+
+#[napi]
+fn get_mapping_with_hasher() -> HashMap<String, Vec<i32>, RandomState> {
+  let mut map = HashMap::with_hasher(RandomState::new());
+  map.insert("primes".to_owned(), vec![2, 3, 5]);
+  map
+}
+
+#[napi]
+fn square_map_values(map: JsMap<u32, u32>) -> JsMap<u32, u32> {
+  map
+    .into_inner()
+    .into_iter()
+    .map(|(k, v)| (k, v * v))
+    .collect::<HashMap<_, _>>()
+    .into()
+}
@@ -1,4 +1,4 @@
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, JsNull, JsUndefined, JsUnknown};
 
 #[napi]
 fn map_option(val: Option<u32>) -> Option<u32> {
@@ -12,3 +12,26 @@ fn return_null() -> Null {
 
 #[napi]
 fn return_undefined() -> Undefined {}
+
+#[napi]
+fn nil_values_are_singletons(
+  env: Env,
+  null_value: JsNull,
+  undefined_value: JsUndefined,
+) -> Result<bool> {
+  let converted_null =
+    unsafe { JsUnknown::from_napi_value(env.raw(), Null::to_napi_value(env.raw(), Null)?)? };
+  let converted_undefined =
+    unsafe { JsUnknown::from_napi_value(env.raw(), Undefined::to_napi_value(env.raw(), ())?)? };
+  Ok(
+    env.strict_equals(env.get_null()?, null_value)?
+      && env.strict_equals(converted_null, null_value)?
+      && env.strict_equals(env.get_undefined()?, undefined_value)?
+      && env.strict_equals(converted_undefined, undefined_value)?,
+  )
+}
+
+#[napi]
+fn optional_vec_len(val: Option<Vec<u32>>) -> Option<u32> {
+  val.map(|v| v.len() as u32)
+}
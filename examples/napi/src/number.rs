@@ -1,3 +1,7 @@
+use std::cmp::{self, Ordering};
+
+use napi::bindgen_prelude::CheckedF32;
+
 #[napi]
 fn add(a: u32, b: u32) -> u32 {
   a + b
@@ -10,3 +14,24 @@ fn fibonacci(n: u32) -> u32 {
     _ => fibonacci(n - 1) + fibonacci(n - 2),
   }
 }
+
+#[napi]
+fn half_f32(n: f32) -> f32 {
+  n / 2.0
+}
+
+#[napi]
+fn half_checked_f32(n: CheckedF32) -> f32 {
+  *n / 2.0
+}
+
+/// Comparator for `Array.prototype.sort`
+#[napi]
+fn compare_numbers(a: f64, b: f64) -> cmp::Ordering {
+  a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+#[napi]
+fn reverse_ordering(ordering: cmp::Ordering) -> cmp::Ordering {
+  ordering.reverse()
+}
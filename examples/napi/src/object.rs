@@ -1,11 +1,11 @@
 use napi::{
   bindgen_prelude::*, threadsafe_function::ThreadsafeFunction, JsGlobal, JsNull, JsObject,
-  JsUndefined, Property,
+  JsString, JsUndefined, JsUnknown, Property, JSON,
 };
 
 #[napi]
 fn list_obj_keys(obj: Object) -> Vec<String> {
-  Object::keys(&obj).unwrap()
+  obj.keys().unwrap()
 }
 
 #[napi]
@@ -21,6 +21,29 @@ fn get_global(env: Env) -> Result<JsGlobal> {
   env.get_global()
 }
 
+#[napi]
+fn stringify_with_injected_json(json: Global<JSON>, value: JsUnknown) -> Result<String> {
+  json.stringify(value)
+}
+
+mod settings {
+  /// Only `Global<T>` from `napi::bindgen_prelude` is injected
+  #[napi(object)]
+  pub struct Global {
+    pub name: String,
+  }
+
+  #[napi]
+  fn local_global_setting_name(setting: Global) -> String {
+    setting.name
+  }
+}
+
+#[napi]
+fn global_setting_name(setting: settings::Global) -> String {
+  setting.name
+}
+
 #[napi]
 fn get_undefined(env: Env) -> Result<JsUndefined> {
   env.get_undefined()
@@ -89,6 +112,16 @@ pub struct TsTypeChanged {
   pub type_override_optional: Option<String>,
 }
 
+#[napi]
+pub fn get_string_from_object_ref(obj: &JsObject, key: String) -> Result<Option<String>> {
+  obj.get_named_property(&key)
+}
+
+#[napi]
+pub fn js_string_ref_len(s: &JsString) -> Result<u32> {
+  Ok(s.utf8_len()? as u32)
+}
+
 #[napi(ts_return_type = "{ value: ArrayBuffer, get getter(): number }")]
 pub fn create_obj_with_property(env: Env) -> Result<JsObject> {
   let mut obj = env.create_object()?;
@@ -124,3 +157,113 @@ fn receive_object_only_from_js(
     );
   });
 }
+
+#[napi(object, debug)]
+pub struct DebuggableObject {
+  pub user_name: String,
+  pub login_count: u32,
+}
+
+#[napi]
+pub fn debug_object(obj: DebuggableObject) -> String {
+  format!("{:?}", obj)
+}
+
+#[napi(object, js_name_all = "PascalCase")]
+pub struct PascalCaseObject {
+  pub user_name: String,
+  pub login_count: u32,
+}
+
+#[napi]
+pub fn login_pascal_case_object(obj: PascalCaseObject) -> PascalCaseObject {
+  PascalCaseObject {
+    login_count: obj.login_count + 1,
+    ..obj
+  }
+}
+
+#[napi(object, js_name_all = "kebab-case")]
+pub struct KebabCaseObject {
+  pub user_name: String,
+  pub login_count: u32,
+  #[napi(js_name = "id")]
+  pub user_id: u32,
+}
+
+#[napi]
+pub fn login_kebab_case_object(obj: KebabCaseObject) -> KebabCaseObject {
+  KebabCaseObject {
+    login_count: obj.login_count + 1,
+    ..obj
+  }
+}
+
+/// Every field is `readonly` in TypeScript
+#[napi(object, readonly)]
+pub struct ImmutableUser {
+  pub name: String,
+  pub roles: Vec<String>,
+  pub nickname: Option<String>,
+}
+
+#[napi]
+pub fn rename_immutable_user(user: ImmutableUser, name: String) -> ImmutableUser {
+  ImmutableUser { name, ..user }
+}
+
+#[napi(object)]
+pub struct Named {
+  pub name: String,
+}
+
+#[napi]
+pub fn greet_this(this: This<Named>, greeting: String) -> String {
+  format!("{}, {}!", greeting, this.name)
+}
+
+#[napi(object)]
+pub struct TsElementTypeChanged {
+  #[napi(ts_element_type = "'read' | 'write'")]
+  pub permissions: Vec<String>,
+
+  #[napi(ts_element_type = "'read' | 'write'")]
+  pub permission_groups: Option<Vec<Vec<String>>>,
+}
+
+#[napi(object)]
+pub struct Attachment {
+  pub name: String,
+  #[napi(base64)]
+  pub content: Vec<u8>,
+  #[napi(base64)]
+  pub thumbnail: Option<Vec<u8>>,
+}
+
+#[napi]
+pub fn reverse_attachment(attachment: Attachment) -> Attachment {
+  let mut content = attachment.content;
+  content.reverse();
+  Attachment {
+    name: attachment.name,
+    content,
+    thumbnail: attachment.thumbnail,
+  }
+}
+
+#[napi(object)]
+/// RGB color channels
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+#[napi]
+pub fn invert_rgb(color: Rgb) -> Rgb {
+  Rgb(255 - color.0, 255 - color.1, 255 - color.2)
+}
+
+#[napi(object)]
+pub struct LabeledPoint(pub String, pub f64, pub Option<f64>);
+
+#[napi]
+pub fn scale_labeled_point(point: LabeledPoint, factor: f64) -> LabeledPoint {
+  LabeledPoint(point.0, point.1 * factor, point.2.map(|y| y * factor))
+}
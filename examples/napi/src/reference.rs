@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Rc, sync::Arc};
 
 use napi::bindgen_prelude::*;
 
@@ -153,3 +153,29 @@ impl CSSStyleSheet {
     })
   }
 }
+
+#[napi]
+pub struct SharedResource {
+  inner: Option<Arc<String>>,
+}
+
+#[napi]
+impl SharedResource {
+  #[napi(constructor)]
+  pub fn new(name: String) -> Self {
+    SharedResource {
+      inner: Some(Arc::new(name)),
+    }
+  }
+
+  #[napi]
+  pub fn weak_handle(&self) -> WeakHandle<String> {
+    WeakHandle(self.inner.as_ref().map(Arc::downgrade).unwrap_or_default())
+  }
+
+  /// Drops the only strong reference
+  #[napi]
+  pub fn release(&mut self) {
+    self.inner = None;
+  }
+}
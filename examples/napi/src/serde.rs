@@ -30,3 +30,63 @@ fn get_package_json_name(package_json: PackageJson) -> String {
 fn test_serde_roundtrip(data: Value) -> Value {
   data
 }
+
+#[napi]
+fn count_json_object_keys(data: Map<String, Value>) -> u32 {
+  fn count(value: &Value) -> u32 {
+    match value {
+      Value::Object(map) => map.values().map(|v| 1 + count(v)).sum(),
+      Value::Array(arr) => arr.iter().map(count).sum(),
+      _ => 0,
+    }
+  }
+  data.values().map(|v| 1 + count(v)).sum()
+}
+
+#[derive(Deserialize)]
+struct Owner {
+  login: String,
+}
+
+/// Deserialized from JavaScript by its `Deserialize` impl
+#[napi(
+  from_serde,
+  ts_type = "{ name: string, owner: { login: string }, stargazersCount?: number, topics?: string[] }"
+)]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repository {
+  name: String,
+  owner: Owner,
+  #[serde(default)]
+  stargazers_count: u32,
+  #[serde(default)]
+  topics: Vec<String>,
+}
+
+#[napi]
+fn describe_repository(repo: Repository) -> String {
+  format!(
+    "{}/{} ({} stars) [{}]",
+    repo.owner.login,
+    repo.name,
+    repo.stargazers_count,
+    repo.topics.join(", ")
+  )
+}
+
+#[napi(from_serde)]
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Shape {
+  Circle { radius: f64 },
+  Rect { width: f64, height: f64 },
+}
+
+#[napi]
+fn shape_area(shape: Shape) -> f64 {
+  match shape {
+    Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+    Shape::Rect { width, height } => width * height,
+  }
+}
@@ -1,3 +1,7 @@
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::sync::Arc;
+
 use napi::bindgen_prelude::*;
 
 #[napi]
@@ -25,3 +29,33 @@ fn concat_latin1(s: Latin1String) -> String {
 pub fn roundtrip_str(s: String) -> String {
   s
 }
+
+#[napi]
+fn cow_str_or_default(s: Option<Cow<'static, str>>) -> Cow<'static, str> {
+  s.unwrap_or(Cow::Borrowed("napi 🦀"))
+}
+
+#[napi]
+fn is_cow_str_owned(s: Cow<'static, str>) -> bool {
+  matches!(s, Cow::Owned(_))
+}
+
+#[napi]
+fn cstring_byte_length(s: CString) -> u32 {
+  s.as_bytes().len() as u32
+}
+
+#[napi]
+fn cstring_from_bytes(bytes: Vec<u8>) -> Result<CString> {
+  Ok(CString::new(bytes)?)
+}
+
+#[napi]
+fn static_cstr() -> &'static CStr {
+  CStr::from_bytes_with_nul(b"napi \xf0\x9f\xa6\x80\0").unwrap()
+}
+
+#[napi]
+fn shared_str_round_trip(s: Arc<str>) -> Arc<str> {
+  s
+}
@@ -19,6 +19,36 @@ pub fn call_threadsafe_function(callback: JsFunction) -> Result<()> {
   Ok(())
 }
 
+/// Values are queued before the JavaScript thread can deliver any of them,
+/// so they arrive strictly by priority: `2, 4, 3, 1`
+#[napi]
+pub fn call_threadsafe_function_with_priority(callback: JsFunction) -> Result<()> {
+  let tsfn: ThreadsafeFunction<u32, ErrorStrategy::Fatal> =
+    callback.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+  for (value, priority) in [(1, 0), (2, 10), (3, 5), (4, 10)] {
+    tsfn.call_with_priority(value, priority, ThreadsafeFunctionCallMode::NonBlocking);
+  }
+  Ok(())
+}
+
+/// Only the first value fits into the queue, the others are refused and never delivered
+#[napi]
+pub fn call_threadsafe_function_with_priority_queue_full(
+  callback: JsFunction,
+) -> Result<Vec<bool>> {
+  let tsfn: ThreadsafeFunction<u32, ErrorStrategy::Fatal> =
+    callback.create_threadsafe_function(1, |ctx| Ok(vec![ctx.value]))?;
+  Ok(
+    [(1, 0), (2, 10), (3, 5)]
+      .into_iter()
+      .map(|(value, priority)| {
+        tsfn.call_with_priority(value, priority, ThreadsafeFunctionCallMode::NonBlocking)
+          == Status::Ok
+      })
+      .collect(),
+  )
+}
+
 #[napi]
 pub fn threadsafe_function_throw_error(cb: JsFunction) -> Result<()> {
   let tsfn: ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled> =
@@ -116,3 +146,16 @@ pub fn accept_threadsafe_function_fatal(func: ThreadsafeFunction<u32, ErrorStrat
     func.call(1, ThreadsafeFunctionCallMode::NonBlocking);
   });
 }
+
+#[napi]
+pub fn tsfn_try_call(func: JsFunction) -> Result<Vec<u32>> {
+  let tsfn: ThreadsafeFunction<u32, ErrorStrategy::Fatal> =
+    func.create_threadsafe_function(1, |ctx| Ok(vec![ctx.value]))?;
+  // The queue can't be drained while the JavaScript thread is blocked here,
+  // so everything after the first value is handed back.
+  Ok(
+    (0..5)
+      .filter_map(|n| tsfn.try_call(n).err())
+      .collect::<Vec<u32>>(),
+  )
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use napi::bindgen_prelude::*;
 
 #[napi]
@@ -17,6 +19,19 @@ fn get_empty_buffer() -> Buffer {
   vec![].into()
 }
 
+#[napi]
+fn copy_buffer(env: Env, buf: Buffer) -> Result<Buffer> {
+  Buffer::copy_from(&env, &buf)
+}
+
+#[napi]
+fn copy_buffer_then_clear_source(env: Env) -> Result<Buffer> {
+  let mut source = b"copied".to_vec();
+  let copied = Buffer::copy_from(&env, &source)?;
+  source.fill(0);
+  Ok(copied)
+}
+
 #[napi]
 fn convert_u32_array(input: Uint32Array) -> Vec<u32> {
   input.to_vec()
@@ -71,3 +86,8 @@ impl Task for AsyncBuffer {
 fn async_reduce_buffer(buf: Buffer) -> Result<AsyncTask<AsyncBuffer>> {
   Ok(AsyncTask::new(AsyncBuffer { buf }))
 }
+
+#[napi]
+fn shared_bytes_round_trip(bytes: Arc<[u8]>) -> Arc<[u8]> {
+  bytes
+}
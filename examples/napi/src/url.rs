@@ -0,0 +1,11 @@
+use url::Url;
+
+#[napi]
+fn url_host(url: Url) -> Option<String> {
+  url.host_str().map(|host| host.to_owned())
+}
+
+#[napi]
+fn normalize_url(url: Url) -> Url {
+  url
+}
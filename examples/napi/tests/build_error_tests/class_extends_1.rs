@@ -0,0 +1,18 @@
+//! This is testing that `#[napi(extends = "...")]` requires the superclass
+//! to be the first field of a `#[repr(C)]` struct
+
+use napi_derive::napi;
+
+pub struct Shape {
+  sides: u32,
+}
+
+#[napi(extends = "Shape")]
+pub struct Square {
+  size: u32,
+  shape: Shape,
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
@@ -0,0 +1,13 @@
+//! This is testing that `#[napi(from_serde)]` can't be combined with `#[napi(object)]`
+
+use napi_derive::napi;
+
+#[napi(object, from_serde)]
+pub struct Point {
+  pub x: u32,
+  pub y: u32,
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
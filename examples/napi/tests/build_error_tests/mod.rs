@@ -1,6 +1,10 @@
 //! Include the test files here so they can be formatted properly with `cargo fmt`
 
+pub mod class_extends_1;
+pub mod from_serde_1;
+pub mod return_result_object_1;
 pub mod ts_arg_type_1;
 pub mod ts_arg_type_2;
 pub mod ts_arg_type_3;
 pub mod ts_arg_type_4;
+pub mod ts_decl_1;
@@ -0,0 +1,12 @@
+//! This is testing that `#[napi(return_result_object)]` requires a `Result` return type
+
+use napi_derive::napi;
+
+#[napi(return_result_object)]
+pub fn add(u: u32) -> u32 {
+  u + 1
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
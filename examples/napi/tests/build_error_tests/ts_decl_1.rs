@@ -0,0 +1,15 @@
+//! This is testing that `#[napi(ts_decl="...")]` can't be mixed with the other type overrides
+
+use napi_derive::napi;
+
+#[napi(
+  ts_decl = "export function add(u: number): number",
+  ts_return_type = "number"
+)]
+pub fn add(u: u32) -> u32 {
+  u + 1
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
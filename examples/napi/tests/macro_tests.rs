@@ -8,4 +8,8 @@ mod build_error_tests;
 fn run_build_error_tests() {
   let t = trybuild::TestCases::new();
   t.compile_fail("tests/build_error_tests/ts_arg_type_*.rs");
+  t.compile_fail("tests/build_error_tests/class_extends_*.rs");
+  t.compile_fail("tests/build_error_tests/ts_decl_*.rs");
+  t.compile_fail("tests/build_error_tests/return_result_object_*.rs");
+  t.compile_fail("tests/build_error_tests/from_serde_*.rs");
 }
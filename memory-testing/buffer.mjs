@@ -18,6 +18,7 @@ while (true) {
   api.bufferConvert(Buffer.from(FIXTURE))
   api.arrayBufferConvert(Uint8Array.from(FIXTURE))
   api.arrayBufferCreateFromVecWithSpareCapacity()
+  api.arrayBufferCreateFromBoxedSlice()
   api.bufferPassThrough(Buffer.from(FIXTURE))
   api.arrayBufferPassThrough(Uint8Array.from(FIXTURE))
   if (i % 10 === 0) {
@@ -164,6 +164,13 @@ pub fn array_buffer_create_from_vec_with_spare_capacity() -> Uint8Array {
   Uint8Array::new(v)
 }
 
+#[napi]
+pub fn array_buffer_create_from_boxed_slice(env: Env) -> Result<napi::JsArrayBuffer> {
+  env
+    .create_arraybuffer_from_boxed_slice(vec![1; 1024 * 10240].into_boxed_slice())
+    .map(|value| value.into_raw())
+}
+
 #[napi]
 pub fn array_buffer_len() -> u32 {
   Uint8Array::new(vec![1; 1024 * 10240]).len() as u32